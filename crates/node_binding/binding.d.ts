@@ -61,6 +61,14 @@ export class JsStats {
   getAssets(): JsStatsGetAssets
   getModules(reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean, usedExports: boolean, providedExports: boolean): Array<JsStatsModule>
   getChunks(chunkModules: boolean, chunksRelations: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean, usedExports: boolean, providedExports: boolean): Array<JsStatsChunk>
+  /** Serialized webpack-compatible stats JSON, rendered with the `stats` option of the compiler */
+  getJsonString(preset?: "none" | "errors-only" | "errors-warnings" | "normal" | "detailed" | "verbose"): string
+  /**
+   * JSON report explaining why the module is included, `module` is the identifier or the
+   * readable identifier of the module
+   */
+  getModuleExplanation(module: string, exportName?: string | undefined | null): string | null
+  getProfileSummary(limit: number): JsStatsProfileSummary
   getEntrypoints(): Array<JsStatsChunkGroup>
   getNamedChunkGroups(): Array<JsStatsChunkGroup>
   getErrors(): Array<JsStatsError>
@@ -76,6 +84,8 @@ export class Rspack {
   build(callback: (err: null | Error) => void): void
   /** Rebuild with the given option passed to the constructor */
   rebuild(changed_files: string[], removed_files: string[], callback: (err: null | Error) => void): void
+  /** Store the persistent cache, called when the compiler is closed */
+  close(callback: (err: null | Error) => void): void
 }
 
 export function __chunk_graph_inner_get_chunk_entry_dependent_chunks_iterable(jsChunkUkey: number, compilation: JsCompilation): Array<JsChunk>
@@ -144,6 +154,7 @@ export enum BuiltinPluginName {
   NaturalChunkIdsPlugin = 'NaturalChunkIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
+  RecordIdsPlugin = 'RecordIdsPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
@@ -154,6 +165,7 @@ export enum BuiltinPluginName {
   JsonModulesPlugin = 'JsonModulesPlugin',
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
@@ -173,8 +185,11 @@ export enum BuiltinPluginName {
   HtmlRspackPlugin = 'HtmlRspackPlugin',
   SwcJsMinimizerRspackPlugin = 'SwcJsMinimizerRspackPlugin',
   SwcCssMinimizerRspackPlugin = 'SwcCssMinimizerRspackPlugin',
+  LightningCssMinimizerRspackPlugin = 'LightningCssMinimizerRspackPlugin',
   BundlerInfoRspackPlugin = 'BundlerInfoRspackPlugin',
   CssExtractRspackPlugin = 'CssExtractRspackPlugin',
+  GraphExportRspackPlugin = 'GraphExportRspackPlugin',
+  SubresourceIntegrityRspackPlugin = 'SubresourceIntegrityRspackPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin'
 }
@@ -241,6 +256,7 @@ export interface JsAssetInfo {
 
 export interface JsAssetInfoRelated {
   sourceMap?: string
+  variants?: Array<string>
 }
 
 export interface JsBeforeResolveArgs {
@@ -533,6 +549,11 @@ export interface JsStatsGetAssets {
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
 }
 
+export interface JsStatsLoaderProfile {
+  loader: string
+  time: JsStatsMillisecond
+}
+
 export interface JsStatsLogging {
   name: string
   type: string
@@ -578,6 +599,11 @@ export interface JsStatsModuleProfile {
   factory: JsStatsMillisecond
   integration: JsStatsMillisecond
   building: JsStatsMillisecond
+  resolving: JsStatsMillisecond
+  parsing: JsStatsMillisecond
+  codeGeneration: JsStatsMillisecond
+  waiting: JsStatsMillisecond
+  loaders: Array<JsStatsLoaderProfile>
 }
 
 export interface JsStatsModuleReason {
@@ -592,6 +618,24 @@ export interface JsStatsOptimizationBailout {
   inner: string
 }
 
+export interface JsStatsProfileLoader {
+  loader: string
+  time: JsStatsMillisecond
+  count: number
+}
+
+export interface JsStatsProfileModule {
+  identifier: string
+  name: string
+  time: JsStatsMillisecond
+}
+
+export interface JsStatsProfileSummary {
+  slowestModules: Array<JsStatsProfileModule>
+  slowestLoaders: Array<JsStatsProfileLoader>
+  slowestResolves: Array<JsStatsProfileModule>
+}
+
 export interface JsStatsWarning {
   message: string
   moduleIdentifier?: string
@@ -627,24 +671,43 @@ export interface RawAssetGeneratorDataUrlFnArgs {
 }
 
 export interface RawAssetGeneratorDataUrlOptions {
-  encoding?: "base64" | "false" | undefined
+  encoding?: "base64" | "utf8" | "svg" | "false" | undefined
   mimetype?: string
 }
 
+export interface RawAssetGeneratorImageOptions {
+  optimize?: boolean
+  quality?: number
+}
+
 export interface RawAssetGeneratorOptions {
   emit?: boolean
   filename?: string
   publicPath?: string
   dataUrl?: RawAssetGeneratorDataUrlOptions | ((arg: RawAssetGeneratorDataUrlFnArgs) => string)
+  image?: RawAssetGeneratorImageOptions
+  transform?: (content: Buffer, context: RawAssetGeneratorTransformFnContext) => Buffer
+}
+
+export interface RawAssetGeneratorTransformFnContext {
+  filename: string
 }
 
 export interface RawAssetInlineGeneratorOptions {
   dataUrl?: RawAssetGeneratorDataUrlOptions | ((arg: RawAssetGeneratorDataUrlFnArgs) => string)
+  image?: RawAssetGeneratorImageOptions
+  transform?: (content: Buffer, context: RawAssetGeneratorTransformFnContext) => Buffer
 }
 
 export interface RawAssetParserDataUrl {
-  type: "options"
+  type: "options" | "function"
   options?: RawAssetParserDataUrlOptions
+  function?: (source: Buffer, context: RawAssetParserDataUrlFnContext) => boolean
+}
+
+export interface RawAssetParserDataUrlFnContext {
+  filename: string
+  issuer?: string
 }
 
 export interface RawAssetParserDataUrlOptions {
@@ -659,6 +722,8 @@ export interface RawAssetResourceGeneratorOptions {
   emit?: boolean
   filename?: string
   publicPath?: string
+  image?: RawAssetGeneratorImageOptions
+  transform?: (content: Buffer, context: RawAssetGeneratorTransformFnContext) => Buffer
 }
 
 export interface RawBannerContentFnCtx {
@@ -719,6 +784,11 @@ export interface RawCacheOptions {
   buildDependencies: Array<string>
   cacheDirectory: string
   cacheLocation: string
+  hashAlgorithm: string
+  compression: "gzip" | "brotli" | "none"
+  idleTimeout: number
+  idleTimeoutForInitialStore: number
+  idleTimeoutAfterLargeChanges: number
   name: string
   version: string
 }
@@ -938,6 +1008,18 @@ export interface RawGeneratorOptions {
   cssModule?: RawCssModuleGeneratorOptions
 }
 
+export interface RawGraphExportRspackPluginOptions {
+  filename: string
+  format: "dot" | "graphml"
+  entries: Array<string>
+  moduleFilter?: RegExp
+}
+
+export interface RawHtmlPageOptions {
+  entry?: string
+  templateParameters?: Record<string, string>
+}
+
 export interface RawHtmlRspackPluginOptions {
   /** emitted file name in output path */
   filename?: string
@@ -945,6 +1027,10 @@ export interface RawHtmlRspackPluginOptions {
   template?: string
   templateContent?: string
   templateParameters?: Record<string, string>
+  /** glob of template files, one page is generated for each of them */
+  templateGlob?: string
+  /** options of the pages generated from `template_glob`, keyed by the template file stem */
+  pages?: Record<string, RawHtmlPageOptions>
   /** "head", "body" or "false" */
   inject: "head" | "body" | "false"
   /** path or `auto` */
@@ -954,6 +1040,8 @@ export interface RawHtmlRspackPluginOptions {
   /** entry_chunk_name (only entry chunks are supported) */
   chunks?: Array<string>
   excludedChunks?: Array<string>
+  /** `auto`, `manual` or `dependency` */
+  chunksSortMode?: "auto" | "manual" | "dependency"
   sri?: "sha256" | "sha384" | "sha512"
   minify?: boolean
   title?: string
@@ -996,6 +1084,11 @@ export interface RawJavascriptParserOptions {
   strictExportPresence: boolean
 }
 
+export interface RawJsonParserOptions {
+  exportsDepth?: number
+  parse?: (source: string) => string
+}
+
 export interface RawLazyCompilationOption {
   module: (err: Error | null, arg: RawModuleArg) => any
   test?: RawLazyCompilationTest
@@ -1033,6 +1126,18 @@ export interface RawLibraryOptions {
   amdContainer?: string
 }
 
+export interface RawLightningCssDraftOptions {
+  customMedia: boolean
+}
+
+export interface RawLightningCssMinimizerRspackPluginOptions {
+  errorRecovery: boolean
+  targets?: Array<string>
+  include?: number
+  exclude?: number
+  draft?: RawLightningCssDraftOptions
+}
+
 export interface RawLimitChunkCountPluginOptions {
   chunkOverhead?: number
   entryChunkMultiplicator?: number
@@ -1157,6 +1262,9 @@ export interface RawOptions {
   experiments: RawExperiments
   node?: RawNodeOption
   profile: boolean
+  profileReport?: string
+  recordsInputPath?: string
+  recordsOutputPath?: string
   bail: boolean
 }
 
@@ -1203,12 +1311,13 @@ export interface RawOutputOptions {
 }
 
 export interface RawParserOptions {
-  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm"
+  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json" | "json5" | "yaml" | "toml"
   asset?: RawAssetParserOptions
   css?: RawCssParserOptions
   cssAuto?: RawCssAutoParserOptions
   cssModule?: RawCssModuleParserOptions
   javascript?: RawJavascriptParserOptions
+  json?: RawJsonParserOptions
 }
 
 export interface RawPathData {
@@ -1339,7 +1448,16 @@ export interface RawSizeLimitsPluginOptions {
 }
 
 export interface RawSnapshotOptions {
+  managedPaths?: (string | RegExp)[]
+  immutablePaths?: (string | RegExp)[]
+  module?: RawSnapshotStrategy
+  resolve?: RawSnapshotStrategy
+  buildDependencies?: RawSnapshotStrategy
+}
 
+export interface RawSnapshotStrategy {
+  timestamp?: boolean
+  hash?: boolean
 }
 
 export interface RawSourceMapDevToolPluginOptions {
@@ -1379,6 +1497,32 @@ export interface RawSplitChunksOptions {
 
 export interface RawStatsOptions {
   colors: boolean
+  preset?: "none" | "errors-only" | "errors-warnings" | "normal" | "detailed" | "verbose"
+  all?: boolean
+  hash?: boolean
+  publicPath?: boolean
+  outputPath?: boolean
+  assets?: boolean
+  chunks?: boolean
+  chunkModules?: boolean
+  chunkRelations?: boolean
+  chunkGroups?: boolean
+  entrypoints?: boolean
+  modules?: boolean
+  nestedModules?: boolean
+  orphanModules?: boolean
+  runtimeModules?: boolean
+  moduleAssets?: boolean
+  ids?: boolean
+  reasons?: boolean
+  source?: boolean
+  usedExports?: boolean
+  providedExports?: boolean
+  optimizationBailout?: boolean
+  errors?: boolean
+  errorsCount?: boolean
+  warnings?: boolean
+  warningsCount?: boolean
 }
 
 export interface RawStyleConfig {
@@ -1388,6 +1532,11 @@ export interface RawStyleConfig {
   bool?: boolean
 }
 
+export interface RawSubresourceIntegrityRspackPluginOptions {
+  hashFuncNames: Array<"sha256" | "sha384" | "sha512">
+  manifest?: string
+}
+
 export interface RawSwcJsMinimizerRspackPluginOptions {
   extractComments?: RawExtractComments
   compress: boolean | string
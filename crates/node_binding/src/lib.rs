@@ -134,6 +134,25 @@ impl Rspack {
       })
     }
   }
+
+  /// Store the persistent cache, called when the compiler is closed
+  #[napi(ts_args_type = "callback: (err: null | Error) => void")]
+  pub fn close(&mut self, env: Env, reference: Reference<Rspack>, f: JsFunction) -> Result<()> {
+    unsafe {
+      self.run(env, reference, |compiler, _guard| {
+        callbackify(env, f, async move {
+          compiler.close().await.map_err(|e| {
+            Error::new(
+              napi::Status::GenericFailure,
+              print_error_diagnostic(e, compiler.options.stats.colors),
+            )
+          })?;
+          drop(_guard);
+          Ok(())
+        })
+      })
+    }
+  }
 }
 
 impl Rspack {
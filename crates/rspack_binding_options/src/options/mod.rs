@@ -57,6 +57,9 @@ pub struct RawOptions {
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
   pub profile: bool,
+  pub profile_report: Option<String>,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   pub bail: bool,
 }
 
@@ -71,7 +74,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
     let mode = value.mode.unwrap_or_default().into();
     let module: ModuleOptions = value.module.try_into()?;
     let target = Target::new(&value.target)?;
-    let cache = value.cache.into();
+    let cache = value.cache.try_into()?;
     let experiments = Experiments {
       incremental_rebuild: IncrementalRebuild {
         make: if matches!(cache, CacheOptions::Disabled) {
@@ -85,7 +88,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       rspack_future: value.experiments.rspack_future.into(),
     };
     let optimization = value.optimization.try_into()?;
-    let stats = value.stats.into();
+    let stats = value.stats.try_into()?;
     let snapshot = value.snapshot.into();
     let node = value.node.map(|n| n.into());
 
@@ -105,6 +108,9 @@ impl TryFrom<RawOptions> for CompilerOptions {
       node,
       dev_server: Default::default(),
       profile: value.profile,
+      profile_report: value.profile_report,
+      records_input_path: value.records_input_path.map(Into::into),
+      records_output_path: value.records_output_path.map(Into::into),
       bail: value.bail,
       builtins: Default::default(),
     })
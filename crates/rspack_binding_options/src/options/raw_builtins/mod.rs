@@ -2,14 +2,17 @@ mod raw_banner;
 mod raw_bundle_info;
 mod raw_copy;
 mod raw_css_extract;
+mod raw_graph_export;
 mod raw_html;
 mod raw_ignore;
 mod raw_lazy_compilation;
+mod raw_lightning_css_minimizer;
 mod raw_limit_chunk_count;
 mod raw_mf;
 mod raw_progress;
 mod raw_runtime_chunk;
 mod raw_size_limits;
+mod raw_sri;
 mod raw_swc_js_minimizer;
 mod raw_to_be_deprecated;
 
@@ -19,7 +22,7 @@ use rspack_core::{BoxPlugin, Define, DefinePlugin, Plugin, PluginExt, Provide, P
 use rspack_error::Result;
 use rspack_ids::{
   DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin,
+  NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin, RecordIdsPlugin,
 };
 use rspack_napi::NapiResultExt;
 use rspack_plugin_asset::AssetPlugin;
@@ -37,6 +40,7 @@ use rspack_plugin_entry::EntryPlugin;
 use rspack_plugin_externals::{
   electron_target_plugin, http_externals_rspack_plugin, node_target_plugin, ExternalsPlugin,
 };
+use rspack_plugin_graph_export::GraphExportRspackPlugin;
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
 use rspack_plugin_ignore::IgnorePlugin;
@@ -47,6 +51,7 @@ use rspack_plugin_javascript::{
 };
 use rspack_plugin_json::JsonPlugin;
 use rspack_plugin_library::enable_library_plugin;
+use rspack_plugin_lightning_css_minimizer::LightningCssMinimizerRspackPlugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
@@ -63,10 +68,11 @@ use rspack_plugin_runtime::{
 use rspack_plugin_runtime_chunk::RuntimeChunkPlugin;
 use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
 use rspack_plugin_size_limits::SizeLimitsPlugin;
+use rspack_plugin_sri::SubresourceIntegrityRspackPlugin;
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
-use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin};
+use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin, WasmPlugin};
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
 
@@ -80,10 +86,13 @@ pub use self::{
 use self::{
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_css_extract::RawCssExtractPluginOption,
+  raw_graph_export::RawGraphExportRspackPluginOptions,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
+  raw_lightning_css_minimizer::RawLightningCssMinimizerRspackPluginOptions,
   raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
+  raw_sri::RawSubresourceIntegrityRspackPluginOptions,
 };
 use crate::{
   plugins::{CssExtractRspackAdditionalDataPlugin, JsLoaderResolverPlugin},
@@ -131,6 +140,7 @@ pub enum BuiltinPluginName {
   NaturalChunkIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
+  RecordIdsPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
   EnsureChunkConditionsPlugin,
@@ -141,6 +151,7 @@ pub enum BuiltinPluginName {
   JsonModulesPlugin,
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
+  WebAssemblyModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
@@ -163,8 +174,11 @@ pub enum BuiltinPluginName {
   HtmlRspackPlugin,
   SwcJsMinimizerRspackPlugin,
   SwcCssMinimizerRspackPlugin,
+  LightningCssMinimizerRspackPlugin,
   BundlerInfoRspackPlugin,
   CssExtractRspackPlugin,
+  GraphExportRspackPlugin,
+  SubresourceIntegrityRspackPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -248,7 +262,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
+        plugins.extend(enable_wasm_loading_plugin(
           wasm_loading_type.as_str().into(),
         ));
       }
@@ -340,6 +354,7 @@ impl BuiltinPlugin {
       BuiltinPluginName::DeterministicChunkIdsPlugin => {
         plugins.push(DeterministicChunkIdsPlugin::default().boxed())
       }
+      BuiltinPluginName::RecordIdsPlugin => plugins.push(RecordIdsPlugin::default().boxed()),
       BuiltinPluginName::RealContentHashPlugin => {
         plugins.push(RealContentHashPlugin::default().boxed())
       }
@@ -360,8 +375,9 @@ impl BuiltinPlugin {
         plugins.push(InferAsyncModulesPlugin::default().boxed())
       }
       BuiltinPluginName::JavascriptModulesPlugin => plugins.push(JsPlugin::default().boxed()),
+      BuiltinPluginName::WebAssemblyModulesPlugin => plugins.push(WasmPlugin::default().boxed()),
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
-        plugins.push(AsyncWasmPlugin::default().boxed())
+        plugins.push(AsyncWasmPlugin::new(downcast_into::<bool>(self.options)?).boxed())
       }
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin::default().boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
@@ -440,6 +456,12 @@ impl BuiltinPlugin {
       BuiltinPluginName::SwcCssMinimizerRspackPlugin => {
         plugins.push(SwcCssMinimizerRspackPlugin::default().boxed())
       }
+      BuiltinPluginName::LightningCssMinimizerRspackPlugin => plugins.push(
+        LightningCssMinimizerRspackPlugin::new(
+          downcast_into::<RawLightningCssMinimizerRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::CopyRspackPlugin => {
         let plugin = CopyRspackPlugin::new(
           CopyRspackPluginOptions::from(downcast_into::<RawCopyRspackPluginOptions>(self.options)?)
@@ -473,6 +495,20 @@ impl BuiltinPlugin {
         .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::GraphExportRspackPlugin => {
+        let plugin = GraphExportRspackPlugin::new(
+          downcast_into::<RawGraphExportRspackPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::SubresourceIntegrityRspackPlugin => {
+        let plugin = SubresourceIntegrityRspackPlugin::new(
+          downcast_into::<RawSubresourceIntegrityRspackPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       // rspack js adapter plugins
       BuiltinPluginName::JsLoaderRspackPlugin => {
         plugins.push(
@@ -0,0 +1,29 @@
+use napi_derive::napi;
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+use rspack_plugin_graph_export::{GraphExportFormat, GraphExportRspackPluginOptions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawGraphExportRspackPluginOptions {
+  pub filename: String,
+  #[napi(ts_type = "\"dot\" | \"graphml\"")]
+  pub format: String,
+  pub entries: Vec<String>,
+  #[napi(ts_type = "RegExp")]
+  pub module_filter: Option<JsRegExp>,
+}
+
+impl TryFrom<RawGraphExportRspackPluginOptions> for GraphExportRspackPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawGraphExportRspackPluginOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      filename: value.filename,
+      format: GraphExportFormat::try_from(value.format.as_str())?,
+      entries: value.entries,
+      module_filter: value
+        .module_filter
+        .map(|module_filter| module_filter.to_rspack_regex()),
+    })
+  }
+}
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use napi_derive::napi;
+use rspack_plugin_html::config::HtmlChunkSortMode;
 use rspack_plugin_html::config::HtmlInject;
+use rspack_plugin_html::config::HtmlPageOptions;
 use rspack_plugin_html::config::HtmlRspackPluginOptions;
 use rspack_plugin_html::config::HtmlScriptLoading;
 use rspack_plugin_html::sri::HtmlSriHashFunction;
@@ -11,6 +13,23 @@ pub type RawHtmlScriptLoading = String;
 pub type RawHtmlInject = String;
 pub type RawHtmlSriHashFunction = String;
 pub type RawHtmlFilename = String;
+pub type RawHtmlChunkSortMode = String;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawHtmlPageOptions {
+  pub entry: Option<String>,
+  pub template_parameters: Option<HashMap<String, String>>,
+}
+
+impl From<RawHtmlPageOptions> for HtmlPageOptions {
+  fn from(value: RawHtmlPageOptions) -> Self {
+    HtmlPageOptions {
+      entry: value.entry,
+      template_parameters: value.template_parameters,
+    }
+  }
+}
 
 #[derive(Debug)]
 #[napi(object)]
@@ -22,6 +41,10 @@ pub struct RawHtmlRspackPluginOptions {
   pub template: Option<String>,
   pub template_content: Option<String>,
   pub template_parameters: Option<HashMap<String, String>>,
+  /// glob of template files, one page is generated for each of them
+  pub template_glob: Option<String>,
+  /// options of the pages generated from `template_glob`, keyed by the template file stem
+  pub pages: Option<HashMap<String, RawHtmlPageOptions>>,
   /// "head", "body" or "false"
   #[napi(ts_type = "\"head\" | \"body\" | \"false\"")]
   pub inject: RawHtmlInject,
@@ -34,6 +57,9 @@ pub struct RawHtmlRspackPluginOptions {
   /// entry_chunk_name (only entry chunks are supported)
   pub chunks: Option<Vec<String>>,
   pub excluded_chunks: Option<Vec<String>>,
+  /// `auto`, `manual` or `dependency`
+  #[napi(ts_type = "\"auto\" | \"manual\" | \"dependency\"")]
+  pub chunks_sort_mode: Option<RawHtmlChunkSortMode>,
   #[napi(ts_type = "\"sha256\" | \"sha384\" | \"sha512\"")]
   pub sri: Option<RawHtmlSriHashFunction>,
   pub minify: Option<bool>,
@@ -53,16 +79,39 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       HtmlSriHashFunction::from_str(s).unwrap_or_else(|_| panic!("Invalid sri value: {s}"))
     });
 
+    let chunks_sort_mode = value
+      .chunks_sort_mode
+      .as_ref()
+      .map(|s| HtmlChunkSortMode::from_str(s).expect("Invalid chunks_sort_mode value"))
+      .unwrap_or_default();
+
+    // each page generated from the glob needs its own file
+    let default_filename = if value.template_glob.is_some() {
+      "[name].html"
+    } else {
+      "index.html"
+    };
+
     HtmlRspackPluginOptions {
-      filename: value.filename.unwrap_or_else(|| String::from("index.html")),
+      filename: value
+        .filename
+        .unwrap_or_else(|| String::from(default_filename)),
       template: value.template,
       template_content: value.template_content,
       template_parameters: value.template_parameters,
+      template_glob: value.template_glob,
+      pages: value.pages.map(|pages| {
+        pages
+          .into_iter()
+          .map(|(name, page)| (name, page.into()))
+          .collect()
+      }),
       inject,
       public_path: value.public_path,
       script_loading,
       chunks: value.chunks,
       excluded_chunks: value.excluded_chunks,
+      chunks_sort_mode,
       sri,
       minify: value.minify.unwrap_or_default(),
       title: value.title,
@@ -0,0 +1,30 @@
+use napi_derive::napi;
+use rspack_plugin_lightning_css_minimizer::LightningCssMinimizerOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawLightningCssMinimizerRspackPluginOptions {
+  pub error_recovery: bool,
+  pub targets: Option<Vec<String>>,
+  pub include: Option<u32>,
+  pub exclude: Option<u32>,
+  pub draft: Option<RawLightningCssDraftOptions>,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawLightningCssDraftOptions {
+  pub custom_media: bool,
+}
+
+impl From<RawLightningCssMinimizerRspackPluginOptions> for LightningCssMinimizerOptions {
+  fn from(value: RawLightningCssMinimizerRspackPluginOptions) -> Self {
+    Self {
+      error_recovery: value.error_recovery,
+      targets: value.targets,
+      include: value.include.unwrap_or_default(),
+      exclude: value.exclude.unwrap_or_default(),
+      draft_custom_media: value.draft.is_some_and(|draft| draft.custom_media),
+    }
+  }
+}
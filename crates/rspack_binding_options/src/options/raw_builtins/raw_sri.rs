@@ -0,0 +1,29 @@
+use napi_derive::napi;
+use rspack_plugin_sri::{
+  SubresourceIntegrityHashFunction, SubresourceIntegrityRspackPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSubresourceIntegrityRspackPluginOptions {
+  #[napi(ts_type = "Array<\"sha256\" | \"sha384\" | \"sha512\">")]
+  pub hash_func_names: Vec<String>,
+  pub manifest: Option<String>,
+}
+
+impl TryFrom<RawSubresourceIntegrityRspackPluginOptions>
+  for SubresourceIntegrityRspackPluginOptions
+{
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSubresourceIntegrityRspackPluginOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      hash_func_names: value
+        .hash_func_names
+        .iter()
+        .map(|name| SubresourceIntegrityHashFunction::try_from(name.as_str()))
+        .collect::<rspack_error::Result<_>>()?,
+      manifest: value.manifest,
+    })
+  }
+}
@@ -11,12 +11,20 @@ pub struct RawCacheOptions {
   pub build_dependencies: Vec<String>,
   pub cache_directory: String,
   pub cache_location: String,
+  pub hash_algorithm: String,
+  #[napi(ts_type = r#""gzip" | "brotli" | "none""#)]
+  pub compression: String,
+  pub idle_timeout: u32,
+  pub idle_timeout_for_initial_store: u32,
+  pub idle_timeout_after_large_changes: u32,
   pub name: String,
   pub version: String,
 }
 
-impl From<RawCacheOptions> for CacheOptions {
-  fn from(value: RawCacheOptions) -> CacheOptions {
+impl TryFrom<RawCacheOptions> for CacheOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCacheOptions) -> rspack_error::Result<CacheOptions> {
     let RawCacheOptions {
       r#type,
       max_generations,
@@ -25,11 +33,16 @@ impl From<RawCacheOptions> for CacheOptions {
       build_dependencies,
       cache_directory,
       cache_location,
+      hash_algorithm,
+      compression,
+      idle_timeout,
+      idle_timeout_for_initial_store,
+      idle_timeout_after_large_changes,
       name,
       version,
     } = value;
 
-    match r#type.as_str() {
+    Ok(match r#type.as_str() {
       "memory" => CacheOptions::Memory(MemoryCacheOptions { max_generations }),
       "filesystem" => CacheOptions::FileSystem(FileSystemCacheOptions {
         max_age,
@@ -37,10 +50,15 @@ impl From<RawCacheOptions> for CacheOptions {
         build_dependencies,
         cache_directory,
         cache_location,
+        hash_algorithm: hash_algorithm.as_str().try_into()?,
+        compression: compression.as_str().try_into()?,
+        idle_timeout,
+        idle_timeout_for_initial_store,
+        idle_timeout_after_large_changes,
         name,
         version,
       }),
       _ => CacheOptions::Disabled,
-    }
+    })
   }
 }
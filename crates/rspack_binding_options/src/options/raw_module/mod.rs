@@ -4,24 +4,27 @@ use std::fmt::Formatter;
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use derivative::Derivative;
-use napi::bindgen_prelude::Either3;
+use napi::bindgen_prelude::{Buffer, Either3};
 use napi::Either;
 use napi_derive::napi;
 use rspack_binding_values::RawRegex;
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetGeneratorDataUrlOptions,
-  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
-  AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions, BoxLoader,
-  CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions,
-  CssModuleParserOptions, CssParserOptions, DescriptionData, DynamicImportMode, ExportPresenceMode,
-  FuncUseCtx, GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions,
-  JavascriptParserOrder, JavascriptParserUrl, ModuleNoParseRule, ModuleNoParseRules,
+  AssetGeneratorImageOptions, AssetGeneratorOptions, AssetGeneratorTransform,
+  AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions,
+  AssetResourceGeneratorOptions, BoxLoader, CssAutoGeneratorOptions, CssAutoParserOptions,
+  CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions, CssParserOptions,
+  DescriptionData, DynamicImportMode, ExportPresenceMode, FuncUseCtx, GeneratorOptions,
+  GeneratorOptionsByModuleType, JavascriptParserOptions, JavascriptParserOrder,
+  JavascriptParserUrl, JsonParseFn, JsonParserOptions, ModuleNoParseRule, ModuleNoParseRules,
   ModuleNoParseTestFn, ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
   ModuleRuleUseLoader, ModuleType, ParserOptions, ParserOptionsByModuleType,
 };
 use rspack_error::error;
+use rspack_loader_lightningcss::LIGHTNINGCSS_LOADER_IDENTIFIER;
 use rspack_loader_react_refresh::REACT_REFRESH_LOADER_IDENTIFIER;
-use rspack_loader_swc::SWC_LOADER_IDENTIFIER;
+use rspack_loader_sass::SASS_LOADER_IDENTIFIER;
+use rspack_loader_swc::{SVGR_LOADER_IDENTIFIER, SWC_LOADER_IDENTIFIER};
 use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use tokio::runtime::Handle;
@@ -46,6 +49,36 @@ pub fn get_builtin_loader(builtin: &str, options: Option<&str>) -> BoxLoader {
       rspack_loader_react_refresh::ReactRefreshLoader::default().with_identifier(builtin.into()),
     );
   }
+  if builtin.starts_with(SASS_LOADER_IDENTIFIER) {
+    return Arc::new(
+      rspack_loader_sass::SassLoader::new(
+        serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
+          panic!("Could not parse builtin:sass-loader options:{options:?},error: {e:?}")
+        }),
+      )
+      .with_identifier(builtin.into()),
+    );
+  }
+  if builtin.starts_with(LIGHTNINGCSS_LOADER_IDENTIFIER) {
+    return Arc::new(
+      rspack_loader_lightningcss::LightningcssLoader::new(
+        serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
+          panic!("Could not parse builtin:lightningcss-loader options:{options:?},error: {e:?}")
+        }),
+      )
+      .with_identifier(builtin.into()),
+    );
+  }
+  if builtin.starts_with(SVGR_LOADER_IDENTIFIER) {
+    return Arc::new(
+      rspack_loader_swc::SvgrLoader::new(
+        serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
+          panic!("Could not parse builtin:svgr-loader options:{options:?},error: {e:?}")
+        }),
+      )
+      .with_identifier(builtin.into()),
+    );
+  }
 
   unreachable!("Unexpected builtin loader: {builtin}")
 }
@@ -253,10 +286,10 @@ pub struct RawModuleRule {
 }
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawParserOptions {
   #[napi(
-    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm""#
+    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json" | "json5" | "yaml" | "toml""#
   )]
   pub r#type: String,
   pub asset: Option<RawAssetParserOptions>,
@@ -264,6 +297,7 @@ pub struct RawParserOptions {
   pub css_auto: Option<RawCssAutoParserOptions>,
   pub css_module: Option<RawCssModuleParserOptions>,
   pub javascript: Option<RawJavascriptParserOptions>,
+  pub json: Option<RawJsonParserOptions>,
 }
 
 impl From<RawParserOptions> for ParserOptions {
@@ -301,6 +335,12 @@ impl From<RawParserOptions> for ParserOptions {
           .expect("should have an \"css_module\" when RawParserOptions.type is \"css/module\"")
           .into(),
       ),
+      "json" | "json5" | "yaml" | "toml" => Self::Json(
+        value
+          .json
+          .expect("should have an \"json\" when RawParserOptions.type is \"json\"")
+          .into(),
+      ),
       _ => panic!(
         "Failed to resolve the RawParserOptions.type {}.",
         value.r#type
@@ -347,8 +387,32 @@ impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
   }
 }
 
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawJsonParserOptions {
+  pub exports_depth: Option<u32>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(source: string) => string")]
+  pub parse: Option<ThreadsafeFunction<String, String>>,
+}
+
+impl From<RawJsonParserOptions> for JsonParserOptions {
+  fn from(value: RawJsonParserOptions) -> Self {
+    Self {
+      exports_depth: value.exports_depth,
+      parse: value.parse.map(|parse| {
+        JsonParseFn::new(move |source| {
+          let data = parse.blocking_call_with_sync(source)?;
+          json::parse(&data).map_err(|e| error!("failed to parse the result of `parse`: {e}"))
+        })
+      }),
+    }
+  }
+}
+
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetParserOptions {
   pub data_url_condition: Option<RawAssetParserDataUrl>,
 }
@@ -361,13 +425,23 @@ impl From<RawAssetParserOptions> for AssetParserOptions {
   }
 }
 
-#[derive(Debug, Default)]
-#[napi(object)]
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetParserDataUrl {
-  #[napi(ts_type = r#""options""#)]
+  #[napi(ts_type = r#""options" | "function""#)]
   pub r#type: String,
   pub options: Option<RawAssetParserDataUrlOptions>,
-  // TODO: pub function
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(source: Buffer, context: RawAssetParserDataUrlFnContext) => boolean")]
+  pub function: Option<ThreadsafeFunction<(Buffer, RawAssetParserDataUrlFnContext), bool>>,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawAssetParserDataUrlFnContext {
+  pub filename: String,
+  pub issuer: Option<String>,
 }
 
 impl From<RawAssetParserDataUrl> for AssetParserDataUrl {
@@ -379,8 +453,22 @@ impl From<RawAssetParserDataUrl> for AssetParserDataUrl {
           .expect("should have an \"options\" when RawAssetParserDataUrl.type is \"options\"")
           .into(),
       ),
+      "function" => {
+        let function = value
+          .function
+          .expect("should have a \"function\" when RawAssetParserDataUrl.type is \"function\"");
+        Self::Func(Arc::new(move |args| {
+          function.blocking_call_with_sync((
+            args.source.into(),
+            RawAssetParserDataUrlFnContext {
+              filename: args.filename,
+              issuer: args.issuer,
+            },
+          ))
+        }))
+      }
       _ => panic!(
-        "Failed to resolve the RawAssetParserDataUrl.type {}. Expected type is `options`.",
+        "Failed to resolve the RawAssetParserDataUrl.type {}. Expected type is `options` or `function`.",
         value.r#type
       ),
     }
@@ -521,6 +609,10 @@ pub struct RawAssetGeneratorOptions {
     ts_type = "RawAssetGeneratorDataUrlOptions | ((arg: RawAssetGeneratorDataUrlFnArgs) => string)"
   )]
   pub data_url: Option<RawAssetGeneratorDataUrl>,
+  pub image: Option<RawAssetGeneratorImageOptions>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(content: Buffer, context: RawAssetGeneratorTransformFnContext) => Buffer")]
+  pub transform: Option<ThreadsafeFunction<(Buffer, RawAssetGeneratorTransformFnContext), Buffer>>,
 }
 
 impl From<RawAssetGeneratorOptions> for AssetGeneratorOptions {
@@ -532,6 +624,8 @@ impl From<RawAssetGeneratorOptions> for AssetGeneratorOptions {
       data_url: value
         .data_url
         .map(|i| RawAssetGeneratorDataUrlWrapper(i).into()),
+      image: value.image.map(|i| i.into()),
+      transform: value.transform.map(into_asset_generator_transform),
     }
   }
 }
@@ -545,6 +639,10 @@ pub struct RawAssetInlineGeneratorOptions {
     ts_type = "RawAssetGeneratorDataUrlOptions | ((arg: RawAssetGeneratorDataUrlFnArgs) => string)"
   )]
   pub data_url: Option<RawAssetGeneratorDataUrl>,
+  pub image: Option<RawAssetGeneratorImageOptions>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(content: Buffer, context: RawAssetGeneratorTransformFnContext) => Buffer")]
+  pub transform: Option<ThreadsafeFunction<(Buffer, RawAssetGeneratorTransformFnContext), Buffer>>,
 }
 
 impl From<RawAssetInlineGeneratorOptions> for AssetInlineGeneratorOptions {
@@ -553,16 +651,23 @@ impl From<RawAssetInlineGeneratorOptions> for AssetInlineGeneratorOptions {
       data_url: value
         .data_url
         .map(|i| RawAssetGeneratorDataUrlWrapper(i).into()),
+      image: value.image.map(|i| i.into()),
+      transform: value.transform.map(into_asset_generator_transform),
     }
   }
 }
 
-#[derive(Debug, Default)]
-#[napi(object)]
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetResourceGeneratorOptions {
   pub emit: Option<bool>,
   pub filename: Option<String>,
   pub public_path: Option<String>,
+  pub image: Option<RawAssetGeneratorImageOptions>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(content: Buffer, context: RawAssetGeneratorTransformFnContext) => Buffer")]
+  pub transform: Option<ThreadsafeFunction<(Buffer, RawAssetGeneratorTransformFnContext), Buffer>>,
 }
 
 impl From<RawAssetResourceGeneratorOptions> for AssetResourceGeneratorOptions {
@@ -571,6 +676,45 @@ impl From<RawAssetResourceGeneratorOptions> for AssetResourceGeneratorOptions {
       emit: value.emit,
       filename: value.filename.map(|i| i.into()),
       public_path: value.public_path.map(|i| i.into()),
+      image: value.image.map(|i| i.into()),
+      transform: value.transform.map(into_asset_generator_transform),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawAssetGeneratorTransformFnContext {
+  pub filename: String,
+}
+
+fn into_asset_generator_transform(
+  function: ThreadsafeFunction<(Buffer, RawAssetGeneratorTransformFnContext), Buffer>,
+) -> AssetGeneratorTransform {
+  AssetGeneratorTransform(Arc::new(move |args| {
+    function
+      .blocking_call_with_sync((
+        args.content.into(),
+        RawAssetGeneratorTransformFnContext {
+          filename: args.filename,
+        },
+      ))
+      .map(|content| content.into())
+  }))
+}
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct RawAssetGeneratorImageOptions {
+  pub optimize: Option<bool>,
+  pub quality: Option<u8>,
+}
+
+impl From<RawAssetGeneratorImageOptions> for AssetGeneratorImageOptions {
+  fn from(value: RawAssetGeneratorImageOptions) -> Self {
+    Self {
+      optimize: value.optimize,
+      quality: value.quality,
     }
   }
 }
@@ -610,7 +754,7 @@ impl From<RawAssetGeneratorDataUrlWrapper> for AssetGeneratorDataUrl {
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawAssetGeneratorDataUrlOptions {
-  #[napi(ts_type = r#""base64" | "false" | undefined"#)]
+  #[napi(ts_type = r#""base64" | "utf8" | "svg" | "false" | undefined"#)]
   pub encoding: Option<String>,
   pub mimetype: Option<String>,
 }
@@ -1,12 +1,66 @@
+use napi::Either;
 use napi_derive::napi;
-use rspack_core::SnapshotOptions;
+use rspack_core::{SnapshotOptions, SnapshotPath, SnapshotStrategy};
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 
 #[derive(Debug, Default)]
 #[napi(object)]
-pub struct RawSnapshotOptions;
+pub struct RawSnapshotStrategy {
+  pub timestamp: Option<bool>,
+  pub hash: Option<bool>,
+}
+
+impl From<RawSnapshotStrategy> for SnapshotStrategy {
+  fn from(value: RawSnapshotStrategy) -> Self {
+    Self {
+      timestamp: value.timestamp.unwrap_or_default(),
+      hash: value.hash.unwrap_or_default(),
+    }
+  }
+}
+
+type RawSnapshotPath = Either<String, JsRegExp>;
+
+fn normalize_paths(paths: Vec<RawSnapshotPath>) -> Vec<SnapshotPath> {
+  paths
+    .into_iter()
+    .map(|path| match path {
+      Either::A(s) => SnapshotPath::String(s),
+      Either::B(r) => SnapshotPath::Regexp(r.to_rspack_regex()),
+    })
+    .collect()
+}
+
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawSnapshotOptions {
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub managed_paths: Option<Vec<RawSnapshotPath>>,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub immutable_paths: Option<Vec<RawSnapshotPath>>,
+  pub module: Option<RawSnapshotStrategy>,
+  pub resolve: Option<RawSnapshotStrategy>,
+  pub build_dependencies: Option<RawSnapshotStrategy>,
+}
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(_value: RawSnapshotOptions) -> Self {
-    SnapshotOptions
+  fn from(value: RawSnapshotOptions) -> Self {
+    let default = SnapshotOptions::default();
+    Self {
+      managed_paths: value
+        .managed_paths
+        .map(normalize_paths)
+        .unwrap_or(default.managed_paths),
+      immutable_paths: value
+        .immutable_paths
+        .map(normalize_paths)
+        .unwrap_or(default.immutable_paths),
+      module: value.module.map(Into::into).unwrap_or(default.module),
+      resolve: value.resolve.map(Into::into).unwrap_or(default.resolve),
+      build_dependencies: value
+        .build_dependencies
+        .map(Into::into)
+        .unwrap_or(default.build_dependencies),
+    }
   }
 }
@@ -1,16 +1,91 @@
 use napi_derive::napi;
-use rspack_core::StatsOptions;
+use rspack_core::{StatsOptions, StatsPreset};
 
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawStatsOptions {
   pub colors: bool,
+  #[napi(
+    ts_type = "\"none\" | \"errors-only\" | \"errors-warnings\" | \"normal\" | \"detailed\" | \"verbose\""
+  )]
+  pub preset: Option<String>,
+  pub all: Option<bool>,
+  pub hash: Option<bool>,
+  pub public_path: Option<bool>,
+  pub output_path: Option<bool>,
+  pub assets: Option<bool>,
+  pub chunks: Option<bool>,
+  pub chunk_modules: Option<bool>,
+  pub chunk_relations: Option<bool>,
+  pub chunk_groups: Option<bool>,
+  pub entrypoints: Option<bool>,
+  pub modules: Option<bool>,
+  pub nested_modules: Option<bool>,
+  pub orphan_modules: Option<bool>,
+  pub runtime_modules: Option<bool>,
+  pub module_assets: Option<bool>,
+  pub ids: Option<bool>,
+  pub reasons: Option<bool>,
+  pub source: Option<bool>,
+  pub used_exports: Option<bool>,
+  pub provided_exports: Option<bool>,
+  pub optimization_bailout: Option<bool>,
+  pub errors: Option<bool>,
+  pub errors_count: Option<bool>,
+  pub warnings: Option<bool>,
+  pub warnings_count: Option<bool>,
 }
 
-impl From<RawStatsOptions> for StatsOptions {
-  fn from(value: RawStatsOptions) -> Self {
-    Self {
-      colors: value.colors,
+impl TryFrom<RawStatsOptions> for StatsOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStatsOptions) -> rspack_error::Result<Self> {
+    let mut options = match value.all {
+      Some(all) => StatsOptions::all(all),
+      None => StatsOptions::from_preset(
+        value
+          .preset
+          .as_deref()
+          .map(StatsPreset::try_from)
+          .transpose()?
+          .unwrap_or_default(),
+      ),
+    };
+    options.colors = value.colors;
+
+    macro_rules! apply {
+      ($($field:ident),*) => {
+        $(if let Some(v) = value.$field {
+          options.$field = v;
+        })*
+      };
     }
+    apply!(
+      hash,
+      public_path,
+      output_path,
+      assets,
+      chunks,
+      chunk_modules,
+      chunk_relations,
+      chunk_groups,
+      entrypoints,
+      modules,
+      nested_modules,
+      orphan_modules,
+      runtime_modules,
+      module_assets,
+      ids,
+      reasons,
+      source,
+      used_exports,
+      provided_exports,
+      optimization_bailout,
+      errors,
+      errors_count,
+      warnings,
+      warnings_count
+    );
+    Ok(options)
   }
 }
@@ -3,12 +3,14 @@ use napi_derive::napi;
 #[napi(object)]
 pub struct JsAssetInfoRelated {
   pub source_map: Option<String>,
+  pub variants: Option<Vec<String>>,
 }
 
 impl From<JsAssetInfoRelated> for rspack_core::AssetInfoRelated {
   fn from(i: JsAssetInfoRelated) -> Self {
     Self {
       source_map: i.source_map,
+      variants: i.variants.unwrap_or_default(),
     }
   }
 }
@@ -67,6 +69,7 @@ impl From<rspack_core::AssetInfoRelated> for JsAssetInfoRelated {
   fn from(related: rspack_core::AssetInfoRelated) -> Self {
     Self {
       source_map: related.source_map,
+      variants: (!related.variants.is_empty()).then_some(related.variants),
     }
   }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use napi_derive::napi;
-use rspack_core::{Stats, StatsUsedExports};
+use rspack_core::{Stats, StatsOptions, StatsPreset, StatsUsedExports};
 use rspack_napi::napi::bindgen_prelude::Buffer;
 use rspack_napi::napi::{
   bindgen_prelude::{Result, SharedReference},
@@ -293,6 +293,11 @@ pub struct JsStatsModuleProfile {
   pub factory: JsStatsMillisecond,
   pub integration: JsStatsMillisecond,
   pub building: JsStatsMillisecond,
+  pub resolving: JsStatsMillisecond,
+  pub parsing: JsStatsMillisecond,
+  pub code_generation: JsStatsMillisecond,
+  pub waiting: JsStatsMillisecond,
+  pub loaders: Vec<JsStatsLoaderProfile>,
 }
 
 impl From<rspack_core::StatsModuleProfile> for JsStatsModuleProfile {
@@ -301,6 +306,77 @@ impl From<rspack_core::StatsModuleProfile> for JsStatsModuleProfile {
       factory: value.factory.into(),
       integration: value.integration.into(),
       building: value.building.into(),
+      resolving: value.resolving.into(),
+      parsing: value.parsing.into(),
+      code_generation: value.code_generation.into(),
+      waiting: value.waiting.into(),
+      loaders: value.loaders.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsLoaderProfile {
+  pub loader: String,
+  pub time: JsStatsMillisecond,
+}
+
+impl From<rspack_core::StatsLoaderProfile> for JsStatsLoaderProfile {
+  fn from(value: rspack_core::StatsLoaderProfile) -> Self {
+    Self {
+      loader: value.loader,
+      time: value.time.into(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsProfileSummary {
+  pub slowest_modules: Vec<JsStatsProfileModule>,
+  pub slowest_loaders: Vec<JsStatsProfileLoader>,
+  pub slowest_resolves: Vec<JsStatsProfileModule>,
+}
+
+impl From<rspack_core::StatsProfileSummary> for JsStatsProfileSummary {
+  fn from(value: rspack_core::StatsProfileSummary) -> Self {
+    Self {
+      slowest_modules: value.slowest_modules.into_iter().map(Into::into).collect(),
+      slowest_loaders: value.slowest_loaders.into_iter().map(Into::into).collect(),
+      slowest_resolves: value.slowest_resolves.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsProfileModule {
+  pub identifier: String,
+  pub name: String,
+  pub time: JsStatsMillisecond,
+}
+
+impl From<rspack_core::StatsProfileModule> for JsStatsProfileModule {
+  fn from(value: rspack_core::StatsProfileModule) -> Self {
+    Self {
+      identifier: value.identifier.to_string(),
+      name: value.name,
+      time: value.time.into(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsProfileLoader {
+  pub loader: String,
+  pub time: JsStatsMillisecond,
+  pub count: u32,
+}
+
+impl From<rspack_core::StatsProfileLoader> for JsStatsProfileLoader {
+  fn from(value: rspack_core::StatsProfileLoader) -> Self {
+    Self {
+      loader: value.loader,
+      time: value.time.into(),
+      count: value.count,
     }
   }
 }
@@ -540,6 +616,42 @@ impl JsStats {
       .map_err(|e| napi::Error::from_reason(e.to_string()))?
   }
 
+  /// Serialized webpack-compatible stats JSON, rendered with the given preset or the `stats`
+  /// option of the compiler
+  #[napi(
+    ts_args_type = r#"preset?: "none" | "errors-only" | "errors-warnings" | "normal" | "detailed" | "verbose""#
+  )]
+  pub fn get_json_string(&self, preset: Option<String>) -> Result<String> {
+    let to_napi_error = |e: rspack_error::Error| napi::Error::from_reason(e.to_string());
+    let json = match preset {
+      Some(preset) => {
+        let preset = StatsPreset::try_from(preset.as_str()).map_err(to_napi_error)?;
+        self.inner.to_json(&StatsOptions::from_preset(preset))
+      }
+      None => self.inner.to_json(&self.inner.compilation.options.stats),
+    };
+    json.map(|json| json.to_string()).map_err(to_napi_error)
+  }
+
+  /// JSON report explaining why the module is included, `module` is the identifier or the
+  /// readable identifier of the module
+  #[napi]
+  pub fn get_module_explanation(
+    &self,
+    module: String,
+    export_name: Option<String>,
+  ) -> Option<String> {
+    self
+      .inner
+      .get_module_explanation(&module, export_name.as_deref())
+      .map(|json| json.to_string())
+  }
+
+  #[napi]
+  pub fn get_profile_summary(&self, limit: u32) -> JsStatsProfileSummary {
+    self.inner.get_profile_summary(limit as usize).into()
+  }
+
   #[napi]
   pub fn get_entrypoints(&self) -> Vec<JsStatsChunkGroup> {
     self
@@ -1,11 +1,11 @@
 //!  There are methods whose verb is `ChunkGraphModule`
-use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::hash::Hasher;
 
 use rspack_identifier::Identifier;
 use rspack_util::ext::DynHash;
 use rustc_hash::FxHashSet as HashSet;
+use xxhash_rust::xxh3::Xxh3;
 
 use crate::update_hash::{UpdateHashContext, UpdateRspackHash};
 use crate::ChunkGraph;
@@ -166,6 +166,8 @@ impl ChunkGraph {
     self.block_to_chunk_group_ukey.insert(block, chunk_group);
   }
 
+  /// Hashed with xxh3, which is stable across processes, so the result can be used as the key
+  /// of the persistent code generation cache.
   pub fn get_module_graph_hash(
     &self,
     module: &BoxModule,
@@ -173,12 +175,12 @@ impl ChunkGraph {
     runtime: Option<&RuntimeSpec>,
     with_connections: bool,
   ) -> String {
-    let mut hasher = DefaultHasher::new();
+    let mut hasher = Xxh3::new();
     let mut connection_hash_cache: HashMap<Identifier, u64> = HashMap::new();
     let module_graph = &compilation.get_module_graph();
 
     let process_module_graph_module = |module: &BoxModule, strict: Option<bool>| -> u64 {
-      let mut hasher = DefaultHasher::new();
+      let mut hasher = Xxh3::new();
       module.identifier().dyn_hash(&mut hasher);
       module.source_types().dyn_hash(&mut hasher);
       module_graph
@@ -4,6 +4,7 @@ use std::{
   hash::{BuildHasherDefault, Hash},
   path::PathBuf,
   sync::{atomic::AtomicU32, Arc},
+  time::Instant,
 };
 
 use dashmap::{DashMap, DashSet};
@@ -23,6 +24,7 @@ use super::{
   hmr::CompilationRecords,
   make::{make_module_graph, update_module_graph, MakeArtifact, MakeParam},
   module_executor::ModuleExecutor,
+  records::Records,
 };
 use crate::{
   build_chunk_graph::build_chunk_graph,
@@ -132,6 +134,10 @@ pub struct Compilation {
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
+  /// Records read from `recordsInputPath` or carried over from the previous compilation
+  pub input_records: Option<Arc<Records>>,
+  /// Records of this compilation, written to `recordsOutputPath`
+  pub output_records: Records,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
   pub global_entry: EntryData,
@@ -160,6 +166,7 @@ pub struct Compilation {
   pub code_splitting_cache: CodeSplittingCache,
   pub hash: Option<RspackHashDigest>,
   pub used_chunk_ids: HashSet<String>,
+  pub used_module_ids: HashSet<String>,
 
   pub file_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub context_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
@@ -212,6 +219,8 @@ impl Compilation {
       id: CompilationId::new(),
       hot_index: 0,
       records,
+      input_records: None,
+      output_records: Default::default(),
       options,
       other_module_graph: None,
       dependency_factories: Default::default(),
@@ -240,6 +249,7 @@ impl Compilation {
       code_splitting_cache: Default::default(),
       hash: None,
       used_chunk_ids: Default::default(),
+      used_module_ids: Default::default(),
 
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
@@ -508,6 +518,9 @@ impl Compilation {
       if let Some(source_map) = asset.info.related.source_map {
         self.delete_asset(&source_map);
       }
+      for variant in asset.info.related.variants {
+        self.delete_asset(&variant);
+      }
       self.chunk_by_ukey.iter_mut().for_each(|(_, chunk)| {
         chunk.files.remove(filename);
         chunk.auxiliary_files.remove(filename);
@@ -759,6 +772,7 @@ impl Compilation {
         let module = module_graph
           .module_by_identifier(&module_identifier)
           .expect("module should exist");
+        let start = Instant::now();
         let res = self
           .old_cache
           .code_generate_occasion
@@ -775,13 +789,28 @@ impl Compilation {
             }
             Ok(codegen_list)
           })
-          .map(|(result, from_cache)| (module_identifier, result, from_cache));
+          .map(|(result, from_cache)| {
+            (
+              module_identifier,
+              result,
+              from_cache,
+              (start, Instant::now()),
+            )
+          });
         Some(res)
       })
       .collect::<Result<Vec<_>>>()?;
     let results = results
       .into_iter()
-      .map(|(module_identifier, item, from_cache)| {
+      .map(|(module_identifier, item, from_cache, (start, end))| {
+        if self.options.profile
+          && let Some(profile) = self
+            .get_module_graph_mut()
+            .module_graph_module_by_identifier_mut(&module_identifier)
+            .and_then(|mgm| mgm.profile.as_mut())
+        {
+          profile.set_code_generation(start, end);
+        }
         item.into_iter().for_each(|(result, runtime)| {
           if let Some(counter) = codegen_cache_counter {
             if from_cache {
@@ -843,12 +872,19 @@ impl Compilation {
       .chunk_by_ukey
       .values()
       .map(|chunk| async {
-        let mut manifest = Vec::new();
-        let mut diagnostics = Vec::new();
-        plugin_driver
-          .compilation_hooks
-          .render_manifest
-          .call(self, &chunk.ukey, &mut manifest, &mut diagnostics)
+        let (manifest, diagnostics) = self
+          .old_cache
+          .create_chunk_assets_occasion
+          .use_cache(chunk, || async {
+            let mut manifest = Vec::new();
+            let mut diagnostics = Vec::new();
+            plugin_driver
+              .compilation_hooks
+              .render_manifest
+              .call(self, &chunk.ukey, &mut manifest, &mut diagnostics)
+              .await?;
+            Ok((manifest, diagnostics))
+          })
           .await?;
 
         Ok((chunk.ukey, manifest, diagnostics))
@@ -1760,6 +1796,8 @@ impl AssetInfo {
 #[derive(Debug, Default, Clone)]
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
+  /// Resized or converted images of an asset module.
+  pub variants: Vec<String>,
 }
 
 /// level order, the impl is different from webpack, since we can't iterate a set and mutate it at
@@ -0,0 +1,537 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+use rspack_identifier::IdentifierMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde_json::{json, Value};
+use swc_core::ecma::atoms::Atom;
+
+use crate::{
+  AsyncDependenciesBlockIdentifier, ChunkGroupUkey, ChunkUkey, Compilation, ConnectionState,
+  DependenciesBlock, ExtendedReferencedExport, ModuleGraph, ModuleGraphConnection,
+  ModuleIdentifier, UsageState,
+};
+
+/// Answers why a module is included in the output and why its exports are (not) tree shaken.
+#[derive(Debug)]
+pub struct ModuleExplanation {
+  pub identifier: ModuleIdentifier,
+  pub name: String,
+  /// Shortest chain of active connections from an entry to the module
+  pub connection_chain: Vec<ExplanationConnection>,
+  pub chunks: Vec<ChunkExplanation>,
+  pub side_effects: SideEffectsExplanation,
+  pub exports: Vec<ExportExplanation>,
+  pub optimization_bailout: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ExplanationConnection {
+  /// `None` for the entry dependencies
+  pub origin_module: Option<ModuleIdentifier>,
+  pub origin_name: Option<String>,
+  pub module: ModuleIdentifier,
+  pub module_name: String,
+  pub dependency_type: String,
+  pub user_request: Option<String>,
+  /// The entry name for the entry dependencies
+  pub entry: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ChunkExplanation {
+  pub id: Option<String>,
+  pub name: Option<String>,
+  pub files: Vec<String>,
+  /// Chunk groups from an initial entrypoint to the chunk group containing the chunk
+  pub path: Vec<ChunkGroupExplanation>,
+}
+
+#[derive(Debug)]
+pub struct ChunkGroupExplanation {
+  pub name: Option<String>,
+  pub kind: &'static str,
+  /// The async block that created the chunk group, `None` for initial entrypoints
+  pub block: Option<BlockExplanation>,
+}
+
+#[derive(Debug)]
+pub struct BlockExplanation {
+  pub module: ModuleIdentifier,
+  pub module_name: String,
+  pub requests: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SideEffectsExplanation {
+  pub side_effect_free: Option<bool>,
+  /// Why the module can't be skipped when none of its exports are used
+  pub reasons: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ExportExplanation {
+  pub name: Atom,
+  pub provided: String,
+  pub used: String,
+  pub usage_state: UsageState,
+  /// Dependencies that reference the export, either directly or through the namespace object
+  pub used_by: Vec<ExplanationConnection>,
+}
+
+impl Compilation {
+  /// Explains why the module is included, `module` is either the identifier or the readable
+  /// identifier of the module.
+  pub fn explain_module(&self, module: &str) -> Option<ModuleExplanation> {
+    let module_graph = self.get_module_graph();
+    let identifier = self.find_module_for_explanation(&module_graph, module)?;
+    let connection_chain = self.explain_connection_chain(&module_graph, identifier);
+    let chunks = self
+      .chunk_graph
+      .get_module_chunks(identifier)
+      .iter()
+      .copied()
+      .sorted_unstable()
+      .map(|chunk| self.explain_chunk(&module_graph, chunk))
+      .collect();
+    let exports_info = module_graph.get_exports_info(&identifier);
+    let exports = exports_info
+      .exports
+      .keys()
+      .cloned()
+      .collect_vec()
+      .into_iter()
+      .filter_map(|name| self.explain_export_inner(&module_graph, identifier, name))
+      .collect();
+    Some(ModuleExplanation {
+      identifier,
+      name: self.module_name_for_explanation(&module_graph, &identifier),
+      connection_chain,
+      chunks,
+      side_effects: self.explain_side_effects(&module_graph, identifier),
+      exports,
+      optimization_bailout: module_graph
+        .module_graph_module_by_identifier(&identifier)
+        .map(|mgm| mgm.optimization_bailout.clone())
+        .unwrap_or_default(),
+    })
+  }
+
+  /// Explains the usage of an export of the module.
+  pub fn explain_export(&self, module: &str, export_name: &str) -> Option<ExportExplanation> {
+    let module_graph = self.get_module_graph();
+    let identifier = self.find_module_for_explanation(&module_graph, module)?;
+    self.explain_export_inner(&module_graph, identifier, export_name.into())
+  }
+
+  fn find_module_for_explanation(
+    &self,
+    module_graph: &ModuleGraph,
+    module: &str,
+  ) -> Option<ModuleIdentifier> {
+    let identifier = ModuleIdentifier::from(module);
+    if module_graph.module_by_identifier(&identifier).is_some() {
+      return Some(identifier);
+    }
+    module_graph
+      .modules()
+      .into_iter()
+      .filter(|(_, m)| m.readable_identifier(&self.options.context) == module)
+      .map(|(identifier, _)| identifier)
+      .min()
+  }
+
+  fn module_name_for_explanation(
+    &self,
+    module_graph: &ModuleGraph,
+    identifier: &ModuleIdentifier,
+  ) -> String {
+    module_graph
+      .module_by_identifier(identifier)
+      .map(|module| {
+        module
+          .readable_identifier(&self.options.context)
+          .to_string()
+      })
+      .unwrap_or_else(|| identifier.to_string())
+  }
+
+  fn explanation_connection(
+    &self,
+    module_graph: &ModuleGraph,
+    connection: &ModuleGraphConnection,
+  ) -> ExplanationConnection {
+    let dependency = module_graph.dependency_by_id(&connection.dependency_id);
+    ExplanationConnection {
+      origin_module: connection.original_module_identifier,
+      origin_name: connection
+        .original_module_identifier
+        .map(|origin| self.module_name_for_explanation(module_graph, &origin)),
+      module: *connection.module_identifier(),
+      module_name: self.module_name_for_explanation(module_graph, connection.module_identifier()),
+      dependency_type: dependency
+        .map(|dependency| dependency.dependency_type().to_string())
+        .unwrap_or_default(),
+      user_request: dependency
+        .and_then(|dependency| dependency.as_module_dependency())
+        .map(|dependency| dependency.user_request().to_string()),
+      entry: None,
+    }
+  }
+
+  fn explain_connection_chain(
+    &self,
+    module_graph: &ModuleGraph,
+    identifier: ModuleIdentifier,
+  ) -> Vec<ExplanationConnection> {
+    let mut entry_modules: IdentifierMap<(&str, &ModuleGraphConnection)> = Default::default();
+    for (name, entry) in self.entries.iter() {
+      for dependency_id in entry.all_dependencies() {
+        if let Some(connection) = module_graph.connection_by_dependency(dependency_id) {
+          entry_modules
+            .entry(*connection.module_identifier())
+            .or_insert((name.as_str(), connection));
+        }
+      }
+    }
+
+    // walk the incoming connections backwards until reaching an entry module
+    let mut previous: IdentifierMap<&ModuleGraphConnection> = Default::default();
+    let mut queue = VecDeque::from([identifier]);
+    let mut visited = HashSet::from_iter([identifier]);
+    let mut entry = None;
+    while let Some(module) = queue.pop_front() {
+      if let Some(item) = entry_modules.get(&module) {
+        entry = Some((module, *item));
+        break;
+      }
+      let connections = module_graph
+        .get_incoming_connections(&module)
+        .into_iter()
+        .filter(|connection| connection.is_active(module_graph, None))
+        .sorted_unstable_by_key(|connection| connection.original_module_identifier);
+      for connection in connections {
+        if let Some(origin) = connection.original_module_identifier
+          && visited.insert(origin)
+        {
+          previous.insert(origin, connection);
+          queue.push_back(origin);
+        }
+      }
+    }
+
+    let Some((mut module, (entry_name, entry_connection))) = entry else {
+      return vec![];
+    };
+    let mut chain = vec![ExplanationConnection {
+      entry: Some(entry_name.to_string()),
+      ..self.explanation_connection(module_graph, entry_connection)
+    }];
+    while let Some(connection) = previous.get(&module) {
+      chain.push(self.explanation_connection(module_graph, connection));
+      module = *connection.module_identifier();
+    }
+    chain
+  }
+
+  fn explain_chunk(&self, module_graph: &ModuleGraph, chunk_ukey: ChunkUkey) -> ChunkExplanation {
+    let chunk = self.chunk_by_ukey.expect_get(&chunk_ukey);
+
+    let mut async_entrypoint_parents: HashMap<ChunkGroupUkey, Vec<ChunkGroupUkey>> =
+      Default::default();
+    for (ukey, chunk_group) in self.chunk_group_by_ukey.iter() {
+      for async_entrypoint in chunk_group.async_entrypoints_iterable() {
+        async_entrypoint_parents
+          .entry(*async_entrypoint)
+          .or_default()
+          .push(*ukey);
+      }
+    }
+
+    // walk the parents backwards until reaching an initial entrypoint
+    let mut previous: HashMap<ChunkGroupUkey, ChunkGroupUkey> = Default::default();
+    let mut queue: VecDeque<ChunkGroupUkey> = chunk.groups.iter().copied().sorted().collect();
+    let mut visited: HashSet<ChunkGroupUkey> = queue.iter().copied().collect();
+    let mut root = None;
+    while let Some(ukey) = queue.pop_front() {
+      let chunk_group = self.chunk_group_by_ukey.expect_get(&ukey);
+      if chunk_group.is_initial() {
+        root = Some(ukey);
+        break;
+      }
+      let parents = chunk_group
+        .parents_iterable()
+        .chain(async_entrypoint_parents.get(&ukey).into_iter().flatten())
+        .copied()
+        .sorted();
+      for parent in parents {
+        if visited.insert(parent) {
+          previous.insert(parent, ukey);
+          queue.push_back(parent);
+        }
+      }
+    }
+
+    let mut path = vec![];
+    let mut current = root;
+    let mut parent = None;
+    while let Some(ukey) = current {
+      path.push(self.explain_chunk_group(module_graph, ukey, parent));
+      parent = Some(ukey);
+      current = previous.get(&ukey).copied();
+    }
+
+    ChunkExplanation {
+      id: chunk.id.clone(),
+      name: chunk.name.clone(),
+      files: chunk.files.iter().cloned().sorted().collect(),
+      path,
+    }
+  }
+
+  fn explain_chunk_group(
+    &self,
+    module_graph: &ModuleGraph,
+    ukey: ChunkGroupUkey,
+    parent: Option<ChunkGroupUkey>,
+  ) -> ChunkGroupExplanation {
+    let chunk_group = self.chunk_group_by_ukey.expect_get(&ukey);
+    let kind = if chunk_group.is_initial() {
+      "entrypoint"
+    } else if chunk_group.kind.is_entrypoint() {
+      "async entrypoint"
+    } else {
+      "async"
+    };
+
+    // prefer the block located in the parent chunk group, a named chunk group may be created by
+    // several blocks
+    let block = parent.and_then(|parent| {
+      let parent = self.chunk_group_by_ukey.expect_get(&parent);
+      let blocks: Vec<&AsyncDependenciesBlockIdentifier> = self
+        .chunk_graph
+        .block_to_chunk_group_ukey
+        .iter()
+        .filter(|(_, chunk_group)| **chunk_group == ukey)
+        .map(|(block, _)| block)
+        .sorted()
+        .collect();
+      blocks
+        .iter()
+        .find(|block| {
+          module_graph.block_by_id(block).is_some_and(|block| {
+            parent
+              .chunks
+              .iter()
+              .any(|chunk| self.chunk_graph.is_module_in_chunk(block.parent(), *chunk))
+          })
+        })
+        .or(blocks.first())
+        .and_then(|block| module_graph.block_by_id(block))
+        .map(|block| BlockExplanation {
+          module: *block.parent(),
+          module_name: self.module_name_for_explanation(module_graph, block.parent()),
+          requests: block
+            .get_dependencies()
+            .iter()
+            .filter_map(|dependency| module_graph.dependency_by_id(dependency))
+            .filter_map(|dependency| dependency.as_module_dependency())
+            .map(|dependency| dependency.user_request().to_string())
+            .collect(),
+        })
+    });
+
+    ChunkGroupExplanation {
+      name: chunk_group.name().map(ToString::to_string),
+      kind,
+      block,
+    }
+  }
+
+  fn explain_side_effects(
+    &self,
+    module_graph: &ModuleGraph,
+    identifier: ModuleIdentifier,
+  ) -> SideEffectsExplanation {
+    let Some(module) = module_graph.module_by_identifier(&identifier) else {
+      return SideEffectsExplanation {
+        side_effect_free: None,
+        reasons: vec![],
+      };
+    };
+    let mut reasons = vec![];
+    if self.options.optimization.side_effects.is_false() {
+      reasons.push("optimization.sideEffects is disabled".to_string());
+    }
+
+    let factory_side_effect_free = module.factory_meta().and_then(|m| m.side_effect_free);
+    let build_side_effect_free = module.build_meta().and_then(|m| m.side_effect_free);
+    let side_effect_free = factory_side_effect_free.or(build_side_effect_free);
+    match (factory_side_effect_free, build_side_effect_free) {
+      (Some(false), _) => reasons.push(
+        "marked to have side effects by the \"sideEffects\" field of package.json or module rules"
+          .to_string(),
+      ),
+      (Some(true), _) => {}
+      (None, Some(true)) => {
+        let mut module_chain = HashSet::from_iter([identifier]);
+        for dependency in module
+          .get_dependencies()
+          .iter()
+          .filter_map(|dependency| module_graph.dependency_by_id(dependency))
+        {
+          if matches!(
+            dependency.get_module_evaluation_side_effects_state(module_graph, &mut module_chain),
+            ConnectionState::Bool(true)
+          ) {
+            let request = dependency
+              .as_module_dependency()
+              .map(|dependency| dependency.user_request().to_string())
+              .unwrap_or_else(|| dependency.dependency_type().to_string());
+            reasons.push(format!("dependency \"{request}\" has side effects"));
+          }
+        }
+      }
+      (None, _) => {
+        let bailouts = module_graph
+          .module_graph_module_by_identifier(&identifier)
+          .map(|mgm| {
+            mgm
+              .optimization_bailout
+              .iter()
+              .filter(|bailout| bailout.contains("with side_effects in source code"))
+              .cloned()
+              .collect_vec()
+          })
+          .unwrap_or_default();
+        if bailouts.is_empty() {
+          reasons.push("the source code of the module has side effects".to_string());
+        } else {
+          reasons.extend(bailouts);
+        }
+      }
+    }
+    SideEffectsExplanation {
+      side_effect_free,
+      reasons,
+    }
+  }
+
+  fn explain_export_inner(
+    &self,
+    module_graph: &ModuleGraph,
+    identifier: ModuleIdentifier,
+    name: Atom,
+  ) -> Option<ExportExplanation> {
+    let exports_info = module_graph.get_exports_info(&identifier);
+    let export_info_id = exports_info.exports.get(&name)?;
+    let export_info = export_info_id.get_export_info(module_graph);
+
+    let used_by = module_graph
+      .get_incoming_connections(&identifier)
+      .into_iter()
+      .filter(|connection| connection.is_active(module_graph, None))
+      .filter(|connection| {
+        let Some(dependency) = module_graph.dependency_by_id(&connection.dependency_id) else {
+          return false;
+        };
+        let referenced_exports = if let Some(dependency) = dependency.as_module_dependency() {
+          dependency.get_referenced_exports(module_graph, None)
+        } else if dependency.as_context_dependency().is_some() {
+          vec![ExtendedReferencedExport::Array(vec![])]
+        } else {
+          return false;
+        };
+        referenced_exports.iter().any(|referenced| {
+          let names = match referenced {
+            ExtendedReferencedExport::Array(names) => names,
+            ExtendedReferencedExport::Export(referenced) => &referenced.name,
+          };
+          names.first().map_or(true, |first| first == &name)
+        })
+      })
+      .sorted_unstable_by_key(|connection| {
+        (
+          connection.original_module_identifier,
+          connection.dependency_id,
+        )
+      })
+      .map(|connection| self.explanation_connection(module_graph, connection))
+      .collect();
+
+    Some(ExportExplanation {
+      provided: export_info_id.get_provided_info(module_graph),
+      used: export_info_id.get_used_info(module_graph),
+      usage_state: export_info.get_used(None),
+      used_by,
+      name,
+    })
+  }
+}
+
+fn usage_state_to_str(usage_state: UsageState) -> &'static str {
+  match usage_state {
+    UsageState::Unused => "unused",
+    UsageState::OnlyPropertiesUsed => "onlyPropertiesUsed",
+    UsageState::NoInfo => "noInfo",
+    UsageState::Unknown => "unknown",
+    UsageState::Used => "used",
+  }
+}
+
+impl ExplanationConnection {
+  pub fn to_json(&self) -> Value {
+    json!({
+      "originModule": self.origin_module.map(|m| m.to_string()),
+      "originName": self.origin_name,
+      "module": self.module.as_str(),
+      "moduleName": self.module_name,
+      "type": self.dependency_type,
+      "userRequest": self.user_request,
+      "entry": self.entry,
+    })
+  }
+}
+
+impl ExportExplanation {
+  pub fn to_json(&self) -> Value {
+    json!({
+      "name": self.name.as_str(),
+      "provided": self.provided,
+      "used": self.used,
+      "usageState": usage_state_to_str(self.usage_state),
+      "usedBy": self.used_by.iter().map(ExplanationConnection::to_json).collect_vec(),
+    })
+  }
+}
+
+impl ModuleExplanation {
+  /// The JSON report of the explanation.
+  pub fn to_json(&self) -> Value {
+    json!({
+      "identifier": self.identifier.as_str(),
+      "name": self.name,
+      "connectionChain": self.connection_chain.iter().map(ExplanationConnection::to_json).collect_vec(),
+      "chunks": self.chunks.iter().map(|chunk| json!({
+        "id": chunk.id,
+        "name": chunk.name,
+        "files": chunk.files,
+        "path": chunk.path.iter().map(|chunk_group| json!({
+          "name": chunk_group.name,
+          "kind": chunk_group.kind,
+          "block": chunk_group.block.as_ref().map(|block| json!({
+            "module": block.module.as_str(),
+            "moduleName": block.module_name,
+            "requests": block.requests,
+          })),
+        })).collect_vec(),
+      })).collect_vec(),
+      "sideEffects": {
+        "sideEffectFree": self.side_effects.side_effect_free,
+        "reasons": self.side_effects.reasons,
+      },
+      "exports": self.exports.iter().map(ExportExplanation::to_json).collect_vec(),
+      "optimizationBailout": self.optimization_bailout,
+    })
+  }
+}
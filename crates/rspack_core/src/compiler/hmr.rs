@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rspack_error::Result;
@@ -6,10 +7,12 @@ use rspack_fs::AsyncWritableFileSystem;
 use rspack_hash::RspackHashDigest;
 use rspack_identifier::{Identifier, IdentifierMap};
 use rspack_sources::Source;
-use rustc_hash::FxHashSet as HashSet;
+use rspack_util::identifier::make_paths_relative;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-  fast_set, get_chunk_from_ukey, ChunkKind, Compilation, Compiler, ModuleExecutor, RuntimeSpec,
+  fast_set, get_chunk_from_ukey, ChunkKind, Compilation, Compiler, HmrRecords, ModuleExecutor,
+  RuntimeSpec,
 };
 
 impl<T> Compiler<T>
@@ -21,35 +24,7 @@ where
     changed_files: std::collections::HashSet<String>,
     deleted_files: std::collections::HashSet<String>,
   ) -> Result<()> {
-    let old = self.compilation.get_stats();
-    let old_hash = self.compilation.hash.clone();
-
-    let (old_all_modules, old_runtime_modules) = collect_changed_modules(old.compilation)?;
-    // TODO: should use `records`
-
-    let mut all_old_runtime: RuntimeSpec = Default::default();
-    for entry_ukey in old.compilation.get_chunk_graph_entries() {
-      if let Some(runtime) = get_chunk_from_ukey(&entry_ukey, &old.compilation.chunk_by_ukey)
-        .map(|entry_chunk| entry_chunk.runtime.clone())
-      {
-        all_old_runtime.extend(runtime);
-      }
-    }
-
-    let mut old_chunks: Vec<(String, RuntimeSpec)> = vec![];
-    for (_, chunk) in old.compilation.chunk_by_ukey.iter() {
-      if chunk.kind != ChunkKind::HotUpdate {
-        old_chunks.push((chunk.expect_id().to_string(), chunk.runtime.clone()));
-      }
-    }
-
-    let records = CompilationRecords {
-      old_chunks,
-      all_old_runtime,
-      old_all_modules,
-      old_runtime_modules,
-      old_hash,
-    };
+    let records = CompilationRecords::record(&self.compilation)?;
 
     // build without stats
     {
@@ -65,7 +40,10 @@ where
       self
         .old_cache
         .set_modified_files(all_files.into_iter().collect());
-      self.plugin_driver.resolver_factory.clear_cache();
+      self
+        .plugin_driver
+        .resolver_factory
+        .clear_outdated_cache(&self.options);
 
       let mut new_compilation = Compilation::new(
         self.options.clone(),
@@ -84,6 +62,7 @@ where
       }
 
       new_compilation.hot_index = self.compilation.hot_index + 1;
+      new_compilation.input_records = self.records.clone();
 
       let is_incremental_rebuild_make = self.options.is_incremental_rebuild_make_enabled();
       if is_incremental_rebuild_make {
@@ -125,6 +104,90 @@ pub struct CompilationRecords {
   pub old_hash: Option<RspackHashDigest>,
 }
 
+impl CompilationRecords {
+  pub fn record(compilation: &Compilation) -> Result<Self> {
+    let (old_all_modules, old_runtime_modules) = collect_changed_modules(compilation)?;
+
+    let mut all_old_runtime: RuntimeSpec = Default::default();
+    for entry_ukey in compilation.get_chunk_graph_entries() {
+      if let Some(runtime) = get_chunk_from_ukey(&entry_ukey, &compilation.chunk_by_ukey)
+        .map(|entry_chunk| entry_chunk.runtime.clone())
+      {
+        all_old_runtime.extend(runtime);
+      }
+    }
+
+    let mut old_chunks: Vec<(String, RuntimeSpec)> = vec![];
+    for (_, chunk) in compilation.chunk_by_ukey.iter() {
+      if chunk.kind != ChunkKind::HotUpdate {
+        old_chunks.push((chunk.expect_id().to_string(), chunk.runtime.clone()));
+      }
+    }
+
+    Ok(Self {
+      old_chunks,
+      all_old_runtime,
+      old_all_modules,
+      old_runtime_modules,
+      old_hash: compilation.hash.clone(),
+    })
+  }
+
+  /// Restore the hmr state persisted in records by a previous process, module identifiers
+  /// are matched against the modules of `compilation`.
+  pub fn revive(records: &HmrRecords, old_hash: Option<&str>, compilation: &Compilation) -> Self {
+    let context = compilation.options.context.as_str();
+    let identifiers = compilation
+      .get_module_graph()
+      .modules()
+      .into_keys()
+      .map(|identifier| (make_paths_relative(context, &identifier), identifier))
+      .collect::<HashMap<_, _>>();
+
+    Self {
+      old_chunks: records
+        .chunks
+        .iter()
+        .map(|(id, runtime)| {
+          (
+            id.clone(),
+            runtime.iter().map(|r| Arc::from(r.as_str())).collect(),
+          )
+        })
+        .collect(),
+      all_old_runtime: records
+        .all_runtime
+        .iter()
+        .map(|r| Arc::from(r.as_str()))
+        .collect(),
+      old_all_modules: records
+        .modules
+        .iter()
+        .map(|(name, module)| {
+          // modules that no longer exist keep their relative identifier and are reported as removed
+          let identifier = identifiers
+            .get(name)
+            .copied()
+            .unwrap_or_else(|| Identifier::from(name.as_str()));
+          (
+            identifier,
+            (
+              RspackHashDigest::from(module.hash.as_str()),
+              module.id.clone(),
+            ),
+          )
+        })
+        .collect(),
+      old_runtime_modules: records
+        .runtime_modules
+        .iter()
+        .map(|(identifier, content)| (Identifier::from(identifier.as_str()), content.clone()))
+        .collect(),
+      old_hash: old_hash.map(RspackHashDigest::from),
+    }
+  }
+}
+
 pub type ChangedModules = (
   IdentifierMap<(RspackHashDigest, String)>,
   IdentifierMap<String>,
@@ -89,6 +89,7 @@ impl Task<MakeTaskContext> for AddTask {
       resolver_factory: context.resolver_factory.clone(),
       compiler_options: context.compiler_options.clone(),
       plugin_driver: context.plugin_driver.clone(),
+      old_cache: context.old_cache.clone(),
     })])
   }
 }
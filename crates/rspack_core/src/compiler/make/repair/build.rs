@@ -4,10 +4,11 @@ use rspack_error::{Diagnostic, IntoTWithDiagnosticArray};
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
+  old_cache::Cache as OldCache,
   utils::task_loop::{Task, TaskResult, TaskType},
   AsyncDependenciesBlock, BoxDependency, BuildContext, BuildResult, CompilerContext,
   CompilerModuleContext, CompilerOptions, DependencyParents, Module, ModuleProfile,
-  ResolverFactory, SharedPluginDriver,
+  ResolverFactory, SharedPluginDriver, Snapshot,
 };
 
 #[derive(Debug)]
@@ -17,6 +18,7 @@ pub struct BuildTask {
   pub resolver_factory: Arc<ResolverFactory>,
   pub compiler_options: Arc<CompilerOptions>,
   pub plugin_driver: SharedPluginDriver,
+  pub old_cache: Arc<OldCache>,
 }
 
 #[async_trait::async_trait]
@@ -30,6 +32,7 @@ impl Task<MakeTaskContext> for BuildTask {
       resolver_factory,
       plugin_driver,
       current_profile,
+      old_cache,
       mut module,
     } = *self;
     if let Some(current_profile) = &current_profile {
@@ -54,6 +57,8 @@ impl Task<MakeTaskContext> for BuildTask {
           },
           plugin_driver: plugin_driver.clone(),
           compiler_options: &compiler_options,
+          old_cache: &old_cache,
+          profile: current_profile.as_deref(),
         },
         None,
       )
@@ -65,6 +70,27 @@ impl Task<MakeTaskContext> for BuildTask {
       .call(&mut module)
       .await?;
 
+    let result = result.map(|mut build_result| {
+      let snapshot_options = &compiler_options.snapshot;
+      if compiler_options.cache.is_enabled() && snapshot_options.module.is_enabled() {
+        let build_info = &mut build_result.build_info;
+        let files = build_info
+          .file_dependencies
+          .iter()
+          .chain(build_info.context_dependencies.iter())
+          .chain(build_info.build_dependencies.iter())
+          .map(|p| p.as_path());
+        let missing = build_info.missing_dependencies.iter().map(|p| p.as_path());
+        build_info.snapshot = Some(Snapshot::new(
+          files,
+          missing,
+          &snapshot_options.module,
+          snapshot_options,
+        ));
+      }
+      build_result
+    });
+
     let build_result = result.map(|t| {
       let diagnostics = module
         .clone_diagnostics()
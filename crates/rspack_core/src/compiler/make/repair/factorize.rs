@@ -88,11 +88,13 @@ impl Task<MakeTaskContext> for FactorizeTask {
       missing_dependencies: Default::default(),
       context_dependencies: Default::default(),
       diagnostics: Default::default(),
+      resolve_duration: Default::default(),
     };
     match self.module_factory.create(&mut create_data).await {
       Ok(result) => {
         if let Some(current_profile) = &factorize_result_task.current_profile {
           current_profile.mark_factory_end();
+          current_profile.set_resolving(create_data.resolve_duration);
         }
         let diagnostics = create_data.diagnostics.drain(..).collect();
         Ok(vec![Box::new(
@@ -1,13 +1,15 @@
 mod compilation;
+mod explain;
 mod hmr;
 mod make;
 mod module_executor;
+mod records;
 
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use rspack_error::Result;
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_fs::{AsyncNativeFileSystem, AsyncWritableFileSystem};
 use rspack_futures::FuturesResults;
 use rspack_hook::define_hook;
 use rspack_sources::BoxSource;
@@ -15,13 +17,18 @@ use rustc_hash::FxHashMap as HashMap;
 use tracing::instrument;
 
 pub use self::compilation::*;
+pub use self::explain::*;
 pub use self::hmr::{collect_changed_modules, CompilationRecords};
 pub use self::module_executor::{ExecuteModuleId, ModuleExecutor};
+pub use self::records::*;
 use crate::old_cache::Cache as OldCache;
 use crate::{
   fast_set, BoxPlugin, CompilerOptions, Logger, PluginDriver, ResolverFactory, SharedPluginDriver,
 };
-use crate::{ContextModuleFactory, NormalModuleFactory};
+use crate::{ContextModuleFactory, NormalModuleFactory, Stats};
+
+/// Number of items in each list of the profile report summary
+const PROFILE_REPORT_LIMIT: usize = 20;
 
 // should be SyncHook, but rspack need call js hook
 define_hook!(CompilerThisCompilation: AsyncSeries(compilation: &mut Compilation, params: &mut CompilationParams));
@@ -55,6 +62,8 @@ where
 {
   pub options: Arc<CompilerOptions>,
   pub output_filesystem: T,
+  /// Reads the files of the compiler itself, like records.
+  pub input_filesystem: AsyncNativeFileSystem,
   pub compilation: Compilation,
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
@@ -63,6 +72,8 @@ where
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
+  /// records of the last compilation, passed to the next one
+  pub records: Option<Arc<Records>>,
 }
 
 impl<T> Compiler<T>
@@ -96,11 +107,13 @@ where
         Default::default(),
       ),
       output_filesystem,
+      input_filesystem: AsyncNativeFileSystem,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
       old_cache,
       emitted_asset_versions: Default::default(),
+      records: None,
     }
   }
 
@@ -109,12 +122,19 @@ where
     Ok(())
   }
 
+  /// Store the persistent cache before the compiler is dropped.
+  pub async fn close(&mut self) -> Result<()> {
+    self.old_cache.shutdown().await;
+    Ok(())
+  }
+
   #[instrument(name = "build", skip_all)]
   pub async fn build(&mut self) -> Result<()> {
     self.old_cache.end_idle();
-    // TODO: clear the outdated cache entries in resolver,
-    // TODO: maybe it's better to use external entries.
-    self.plugin_driver.resolver_factory.clear_cache();
+    self
+      .plugin_driver
+      .resolver_factory
+      .clear_outdated_cache(&self.options);
 
     let module_executor = ModuleExecutor::default();
     fast_set(
@@ -131,6 +151,7 @@ where
         Default::default(),
       ),
     );
+    self.read_records().await?;
 
     self.compile().await?;
     self.old_cache.begin_idle();
@@ -174,6 +195,10 @@ where
     }
     logger.time_end(make_hook_start);
     self.compilation.make().await?;
+    self
+      .plugin_driver
+      .resolver_factory
+      .take_snapshot(&self.options);
     logger.time_end(make_start);
 
     let start = logger.time("finish make hook");
@@ -205,6 +230,8 @@ where
   async fn compile_done(&mut self) -> Result<()> {
     let logger = self.compilation.get_logger("rspack.Compiler");
 
+    self.record_compilation()?;
+
     if matches!(
       self
         .plugin_driver
@@ -221,6 +248,75 @@ where
     self.emit_assets().await?;
     logger.time_end(start);
 
+    if self.options.profile
+      && let Some(filename) = &self.options.profile_report
+    {
+      self.emit_profile_report(filename).await?;
+    }
+
+    self.emit_records().await?;
+
+    Ok(())
+  }
+
+  async fn read_records(&mut self) -> Result<()> {
+    if let Some(path) = &self.options.records_input_path {
+      self.records = Records::read(&self.input_filesystem, path)
+        .await?
+        .map(Arc::new);
+    }
+    if let Some(records) = &self.records {
+      if self.options.dev_server.hot && records.hmr.is_some() {
+        self.compilation.hot_index = records.hot_index + 1;
+      }
+      self.compilation.input_records = Some(records.clone());
+    }
+    Ok(())
+  }
+
+  /// Complete the records collected by plugins during seal with hashes and hmr state.
+  fn record_compilation(&mut self) -> Result<()> {
+    if self.options.records_input_path.is_none() && self.options.records_output_path.is_none() {
+      return Ok(());
+    }
+    let hmr = if self.options.dev_server.hot {
+      Some(HmrRecords::new(
+        &CompilationRecords::record(&self.compilation)?,
+        self.options.context.as_str(),
+      ))
+    } else {
+      None
+    };
+    let compilation = &mut self.compilation;
+    let mut records = std::mem::take(&mut compilation.output_records);
+    records.chunk_hashes = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| {
+        Some((
+          chunk.id.clone()?,
+          chunk.hash.as_ref()?.encoded().to_string(),
+        ))
+      })
+      .collect();
+    records.hash = compilation.get_hash().map(ToString::to_string);
+    records.hot_index = compilation.hot_index;
+    records.hmr = hmr;
+    self.records = Some(Arc::new(records));
+    Ok(())
+  }
+
+  async fn emit_records(&self) -> Result<()> {
+    let (Some(path), Some(records)) = (&self.options.records_output_path, &self.records) else {
+      return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+      self.output_filesystem.create_dir_all(parent).await?;
+    }
+    self
+      .output_filesystem
+      .write(path, records.to_json_string()?.as_bytes())
+      .await?;
     Ok(())
   }
 
@@ -334,6 +430,19 @@ where
     Ok(())
   }
 
+  async fn emit_profile_report(&self, filename: &str) -> Result<()> {
+    let report = Stats::new(&self.compilation).get_profile_report(PROFILE_REPORT_LIMIT);
+    let file_path = self.options.output.path.join(filename);
+    if let Some(parent) = file_path.parent() {
+      self.output_filesystem.create_dir_all(parent).await?;
+    }
+    self
+      .output_filesystem
+      .write(&file_path, report.to_string().as_bytes())
+      .await?;
+    Ok(())
+  }
+
   fn new_compilation_params(&self) -> CompilationParams {
     CompilationParams {
       normal_module_factory: Arc::new(NormalModuleFactory::new(
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rspack_error::{error, Result};
+use rspack_fs::AsyncReadableFileSystem;
+use rspack_util::identifier::make_paths_relative;
+use serde::{Deserialize, Serialize};
+
+use super::hmr::CompilationRecords;
+
+/// Ids, hashes and hmr state of a compilation, persisted to `recordsOutputPath`
+/// and read back from `recordsInputPath` so that they survive restarts.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Records {
+  pub modules: ModuleRecords,
+  pub chunks: ChunkRecords,
+  pub chunk_hashes: BTreeMap<String, String>,
+  pub hash: Option<String>,
+  pub hot_index: u32,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hmr: Option<HmrRecords>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModuleRecords {
+  /// Module identifier relative to the context -> module id
+  pub by_identifier: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChunkRecords {
+  /// Chunk name -> chunk id
+  pub by_name: BTreeMap<String, String>,
+  /// Root modules of an unnamed chunk -> chunk id
+  pub by_source: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HmrRecords {
+  /// Chunk id -> runtimes of the chunk
+  pub chunks: BTreeMap<String, Vec<String>>,
+  pub all_runtime: Vec<String>,
+  /// Module identifier relative to the context -> module hash and id
+  pub modules: BTreeMap<String, HmrModuleRecord>,
+  /// Runtime module identifier -> generated content
+  pub runtime_modules: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HmrModuleRecord {
+  pub hash: String,
+  pub id: String,
+}
+
+impl Records {
+  /// Read records from `path` with the input filesystem, a missing file yields `None`.
+  pub async fn read(fs: &impl AsyncReadableFileSystem, path: &Path) -> Result<Option<Self>> {
+    let content = match fs.read(path).await {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+      Err(e) => return Err(error!("Failed to read records {}: {e}", path.display())),
+    };
+    serde_json::from_slice(&content)
+      .map(Some)
+      .map_err(|e| error!("Failed to parse records {}: {e}", path.display()))
+  }
+
+  pub fn to_json_string(&self) -> Result<String> {
+    serde_json::to_string_pretty(self).map_err(|e| error!(e.to_string()))
+  }
+}
+
+impl HmrRecords {
+  pub fn new(records: &CompilationRecords, context: &str) -> Self {
+    let mut all_runtime = records
+      .all_old_runtime
+      .iter()
+      .map(|runtime| runtime.to_string())
+      .collect::<Vec<_>>();
+    all_runtime.sort_unstable();
+    Self {
+      chunks: records
+        .old_chunks
+        .iter()
+        .map(|(id, runtime)| {
+          let mut runtime = runtime.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+          runtime.sort_unstable();
+          (id.clone(), runtime)
+        })
+        .collect(),
+      all_runtime,
+      modules: records
+        .old_all_modules
+        .iter()
+        .map(|(identifier, (hash, id))| {
+          (
+            make_paths_relative(context, identifier),
+            HmrModuleRecord {
+              hash: hash.encoded().to_string(),
+              id: id.clone(),
+            },
+          )
+        })
+        .collect(),
+      runtime_modules: records
+        .old_runtime_modules
+        .iter()
+        .map(|(identifier, content)| (identifier.to_string(), content.clone()))
+        .collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn records_json_round_trip() {
+    let mut records = Records {
+      hash: Some("abc".to_string()),
+      hot_index: 2,
+      ..Default::default()
+    };
+    records.modules.by_identifier.insert(
+      "javascript/auto|./src/index.js".to_string(),
+      "42".to_string(),
+    );
+    records
+      .chunks
+      .by_name
+      .insert("main".to_string(), "main".to_string());
+    records
+      .chunks
+      .by_source
+      .insert("./src/async.js".to_string(), "7".to_string());
+    records
+      .chunk_hashes
+      .insert("main".to_string(), "0123abcd".to_string());
+
+    let json = records.to_json_string().expect("should serialize records");
+    assert!(json.contains("\"byIdentifier\""));
+    assert!(json.contains("\"chunkHashes\""));
+    assert!(!json.contains("\"hmr\""));
+    let parsed: Records = serde_json::from_str(&json).expect("should parse records");
+    assert_eq!(parsed, records);
+  }
+
+  #[test]
+  fn records_tolerate_missing_fields() {
+    let parsed: Records =
+      serde_json::from_str(r#"{ "modules": { "byIdentifier": { "./a.js": "1" } } }"#)
+        .expect("should parse partial records");
+    assert_eq!(
+      parsed.modules.by_identifier.get("./a.js"),
+      Some(&"1".to_string())
+    );
+    assert!(parsed.chunks.by_name.is_empty());
+    assert_eq!(parsed.hmr, None);
+  }
+}
@@ -552,6 +552,7 @@ impl Module for ConcatenatedModule {
       json_data: Default::default(),
       top_level_declarations: Some(Default::default()),
       module_concatenation_bailout: Default::default(),
+      snapshot: None,
     };
     self.clear_diagnostics();
 
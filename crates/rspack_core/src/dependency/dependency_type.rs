@@ -14,6 +14,8 @@ pub enum DependencyType {
   // Harmony import
   EsmImport(/* HarmonyImportSideEffectDependency.span */ ErrorSpan), /* TODO: remove span after old tree shaking is removed */
   EsmImportSpecifier,
+  // import source x from "./x.wasm"
+  EsmImportSource,
   // Harmony export
   EsmExport(ErrorSpan),
   EsmExportImportedSpecifier,
@@ -108,6 +110,7 @@ impl DependencyType {
       DependencyType::EsmExportSpecifier => Cow::Borrowed("esm export specifier"),
       DependencyType::EsmExportImportedSpecifier => Cow::Borrowed("esm export import specifier"),
       DependencyType::EsmImportSpecifier => Cow::Borrowed("esm import specifier"),
+      DependencyType::EsmImportSource => Cow::Borrowed("esm import source"),
       DependencyType::EsmExportExpression => Cow::Borrowed("esm export expression"),
       DependencyType::EsmExportHeader => Cow::Borrowed("esm export header"),
       DependencyType::DynamicImport => Cow::Borrowed("import()"),
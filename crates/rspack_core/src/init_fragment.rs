@@ -33,6 +33,7 @@ pub enum InitFragmentKey {
   HarmonyExports,
   CommonJsExports(String),
   ExternalModule(String),
+  WasmSourceImport(String),
   AwaitDependencies,
   HarmonyCompatibility,
   ModuleDecorator(String /* module_id */),
@@ -113,6 +114,7 @@ impl InitFragmentKey {
       InitFragmentKey::HarmonyFakeNamespaceObjectFragment(_)
       | InitFragmentKey::HarmonyExportStar(_)
       | InitFragmentKey::ExternalModule(_)
+      | InitFragmentKey::WasmSourceImport(_)
       | InitFragmentKey::ModuleDecorator(_)
       | InitFragmentKey::CommonJsExports(_)
       | InitFragmentKey::Const(_) => first(fragments),
@@ -248,7 +250,10 @@ impl InitFragmentRenderContext for GenerateContext<'_> {
   }
 }
 
-pub struct ChunkRenderContext;
+pub struct ChunkRenderContext {
+  /// Output path of the chunk being rendered
+  pub output_path: String,
+}
 
 impl InitFragmentRenderContext for ChunkRenderContext {
   fn add_runtime_requirements(&mut self, _requirement: RuntimeGlobals) {
@@ -75,8 +75,10 @@ mod stats;
 pub use stats::*;
 mod runtime;
 mod runtime_module;
+mod snapshot;
 pub use runtime::*;
 pub use runtime_module::*;
+pub use snapshot::*;
 mod code_generation_results;
 pub use code_generation_results::*;
 mod entrypoint;
@@ -157,6 +159,9 @@ impl From<&str> for SourceType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
+  Json5,
+  Yaml,
+  Toml,
   Css,
   CssModule,
   CssAuto,
@@ -229,6 +234,9 @@ impl ModuleType {
       ModuleType::CssAuto => "css/auto",
 
       ModuleType::Json => "json",
+      ModuleType::Json5 => "json5",
+      ModuleType::Yaml => "yaml",
+      ModuleType::Toml => "toml",
 
       ModuleType::WasmSync => "webassembly/sync",
       ModuleType::WasmAsync => "webassembly/async",
@@ -269,6 +277,9 @@ impl From<&str> for ModuleType {
       "css/auto" => Self::CssAuto,
 
       "json" => Self::Json,
+      "json5" => Self::Json5,
+      "yaml" => Self::Yaml,
+      "toml" => Self::Toml,
 
       "webassembly/sync" => Self::WasmSync,
       "webassembly/async" => Self::WasmAsync,
@@ -14,19 +14,23 @@ use rspack_util::source_map::ModuleSourceMapConfig;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::ecma::atoms::Atom;
 
+use crate::old_cache::Cache as OldCache;
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkGraph, ChunkUkey, CodeGenerationResult, Compilation,
   CompilerContext, CompilerOptions, ConcatenationScope, ConnectionState, Context, ContextModule,
   DependenciesBlock, DependencyId, DependencyTemplate, ExportInfoProvided, ExternalModule,
-  ImmutableModuleGraph, ModuleDependency, ModuleGraph, ModuleGraphAccessor, ModuleType,
-  MutableModuleGraph, NormalModule, RawModule, Resolve, RuntimeSpec, SelfModule,
-  SharedPluginDriver, SourceType,
+  ImmutableModuleGraph, ModuleDependency, ModuleGraph, ModuleGraphAccessor, ModuleProfile,
+  ModuleType, MutableModuleGraph, NormalModule, RawModule, Resolve, RuntimeSpec, SelfModule,
+  SharedPluginDriver, Snapshot, SourceType,
 };
 pub struct BuildContext<'a> {
   pub compiler_context: CompilerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  pub old_cache: &'a OldCache,
+  /// Profile of the module, only available when `profile` is enabled.
+  pub profile: Option<&'a ModuleProfile>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -53,6 +57,8 @@ pub struct BuildInfo {
   pub json_data: Option<JsonValue>,
   pub top_level_declarations: Option<HashSet<String>>,
   pub module_concatenation_bailout: Option<String>,
+  /// Snapshot of the dependencies of the module, used to check whether the module is outdated.
+  pub snapshot: Option<Snapshot>,
 }
 
 impl Default for BuildInfo {
@@ -72,6 +78,7 @@ impl Default for BuildInfo {
       json_data: None,
       top_level_declarations: None,
       module_concatenation_bailout: None,
+      snapshot: None,
     }
   }
 }
@@ -355,6 +362,11 @@ pub trait Module:
       if !build_info.cacheable {
         return true;
       }
+      if let Some(snapshot) = &build_info.snapshot
+        && !snapshot.is_valid()
+      {
+        return true;
+      }
     }
     false
   }
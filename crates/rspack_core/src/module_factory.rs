@@ -1,4 +1,4 @@
-use std::{fmt::Debug, path::PathBuf};
+use std::{fmt::Debug, path::PathBuf, time::Duration};
 
 use rspack_error::{Diagnostic, Result};
 use rustc_hash::FxHashSet as HashSet;
@@ -18,6 +18,8 @@ pub struct ModuleFactoryCreateData {
   pub context_dependencies: HashSet<PathBuf>,
   pub missing_dependencies: HashSet<PathBuf>,
   pub diagnostics: Vec<Diagnostic>,
+  /// Time spent in resolving the request and the loaders
+  pub resolve_duration: Duration,
 }
 
 impl ModuleFactoryCreateData {
@@ -1,6 +1,7 @@
 use std::time::{Duration, Instant};
 
 use once_cell::sync::OnceCell;
+use rspack_loader_runner::LoaderTiming;
 
 #[derive(Debug, Default, Clone)]
 pub struct TimeRange {
@@ -16,6 +17,14 @@ impl TimeRange {
     }
   }
 
+  pub fn start(&self) -> Option<Instant> {
+    self.start.get().copied()
+  }
+
+  pub fn end(&self) -> Option<Instant> {
+    self.end.get().copied()
+  }
+
   pub fn duration(&self) -> Option<Duration> {
     if let Some(end) = self.end.get()
       && let Some(start) = self.start.get()
@@ -38,6 +47,10 @@ impl ModulePhaseProfile {
     self.range.duration()
   }
 
+  pub fn range(&self) -> &TimeRange {
+    &self.range
+  }
+
   pub fn set_parallelism_factor(&self, factor: u16) {
     self
       .parallelism_factor
@@ -52,6 +65,14 @@ pub struct ModuleProfile {
   // pub restoring: ModulePhaseProfile,
   pub integration: ModulePhaseProfile,
   pub building: ModulePhaseProfile,
+  /// Time spent in resolving the request and the loaders, it's a part of the factory phase
+  pub resolving: OnceCell<Duration>,
+  /// Time spent in each loader, it's a part of the building phase
+  pub loaders: OnceCell<Vec<LoaderTiming>>,
+  /// Time spent in parsing, it's a part of the building phase
+  pub parsing: ModulePhaseProfile,
+  /// Time spent in code generation of all runtimes
+  pub code_generation: ModulePhaseProfile,
   // pub storing: ModulePhaseProfile,
 
   // pub additional_factory_times: Vec<TimeRange>,
@@ -133,6 +154,57 @@ impl ModuleProfile {
       .expect("should only call once");
   }
 
+  pub fn set_resolving(&self, duration: Duration) {
+    self.resolving.set(duration).expect("should only call once");
+  }
+
+  pub fn set_loaders(&self, loaders: Vec<LoaderTiming>) {
+    self.loaders.set(loaders).expect("should only call once");
+  }
+
+  pub fn mark_parsing_start(&self) {
+    self
+      .parsing
+      .range
+      .start
+      .set(Instant::now())
+      .expect("should only call once");
+  }
+
+  pub fn mark_parsing_end(&self) {
+    self
+      .parsing
+      .range
+      .end
+      .set(Instant::now())
+      .expect("should only call once");
+  }
+
+  /// Code generation runs again for modules that are not rebuilt, so the range is replaced.
+  pub fn set_code_generation(&mut self, start: Instant, end: Instant) {
+    self.code_generation.range = TimeRange::with_value(start, end);
+  }
+
+  /// Time the module stayed in the task queue between integration and building.
+  pub fn waiting(&self) -> Option<Duration> {
+    let integrated = self.integration.range.end()?;
+    let building = self.building.range.start()?;
+    Some(building.saturating_duration_since(integrated))
+  }
+
+  /// Total time spent on the module, waiting time excluded.
+  pub fn total(&self) -> Duration {
+    [
+      &self.factory,
+      &self.integration,
+      &self.building,
+      &self.code_generation,
+    ]
+    .into_iter()
+    .filter_map(|phase| phase.duration())
+    .sum()
+  }
+
   // TODO: store module to cache is not implemented yet
   // pub fn mark_storing_start(&self) {
   //   self
@@ -1,5 +1,6 @@
 use std::{
   borrow::Cow,
+  collections::HashMap,
   fmt::Debug,
   hash::{BuildHasherDefault, Hash},
   sync::{
@@ -11,7 +12,10 @@ use std::{
 use bitflags::bitflags;
 use dashmap::DashMap;
 use derivative::Derivative;
-use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, MietteExt, Result, Severity};
+use rspack_error::{
+  error, Diagnosable, Diagnostic, DiagnosticExt, IntoTWithDiagnosticArray, MietteExt, Result,
+  Severity,
+};
 use rspack_hash::RspackHash;
 use rspack_hook::define_hook;
 use rspack_identifier::Identifiable;
@@ -28,13 +32,13 @@ use serde_json::json;
 
 use crate::{
   add_connection_states, contextify, diagnostics::ModuleBuildError, get_context,
-  impl_module_meta_info, AsyncDependenciesBlockIdentifier, BoxLoader, BoxModule, BuildContext,
-  BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation,
-  CompilerContext, ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
-  DependencyTemplate, FactoryMeta, GenerateContext, GeneratorOptions, LibIdentOptions, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin, RuntimeGlobals,
-  RuntimeSpec, SourceType,
+  impl_module_meta_info, old_cache::BuildModuleCacheEntry, AsyncDependenciesBlockIdentifier,
+  BoxLoader, BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult, ChunkGraph,
+  ChunkInitFragments, CodeGenerationResult, Compilation, CompilerContext, ConcatenationScope,
+  ConnectionState, Context, DependenciesBlock, DependencyId, DependencyTemplate, FactoryMeta,
+  GenerateContext, GeneratorOptions, LibIdentOptions, Module, ModuleDependency, ModuleGraph,
+  ModuleIdentifier, ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions,
+  Resolve, RspackLoaderRunnerPlugin, RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
 bitflags! {
@@ -120,6 +124,8 @@ pub struct NormalModule {
   loaders: Vec<BoxLoader>,
   /// Whether loaders list contains inline loader
   contains_inline_loader: bool,
+  /// Resource of the module which requested to create this module
+  issuer: Option<Box<str>>,
 
   /// Original content of this module, will be available after module build
   original_source: Option<BoxSource>,
@@ -192,6 +198,7 @@ impl NormalModule {
     resolve_options: Option<Box<Resolve>>,
     loaders: Vec<BoxLoader>,
     contains_inline_loader: bool,
+    issuer: Option<Box<str>>,
   ) -> Self {
     let module_type = module_type.into();
     let id = Self::create_id(&module_type, &request);
@@ -212,6 +219,7 @@ impl NormalModule {
       resolve_options,
       loaders,
       contains_inline_loader,
+      issuer,
       original_source: None,
       source: NormalModuleSource::Unbuild,
       debug_id: DEBUG_ID.fetch_add(1, Ordering::Relaxed),
@@ -405,14 +413,24 @@ impl Module for NormalModule {
       additional_data.insert(&LoadersShouldAlwaysGiveContent {});
     }
 
-    let loader_result = run_loaders(
-      &self.loaders,
-      &mut self.resource_data,
-      &[&plugin],
-      build_context.compiler_context,
-      additional_data,
-    )
-    .await;
+    let build_module_occasion = &build_context.old_cache.build_module_occasion;
+    let cached_entry = build_module_occasion.get(&self.id);
+    let loader_result = match cached_entry
+      .as_ref()
+      .and_then(|entry| entry.to_loader_result())
+    {
+      Some(loader_result) => Ok(loader_result.with_empty_diagnostic()),
+      None => {
+        run_loaders(
+          &self.loaders,
+          &mut self.resource_data,
+          &[&plugin],
+          build_context.compiler_context,
+          additional_data,
+        )
+        .await
+      }
+    };
     let (mut loader_result, ds) = match loader_result {
       Ok(r) => r.split_into_parts(),
       Err(e) => {
@@ -449,7 +467,20 @@ impl Module for NormalModule {
       .additional_data
       .call(&mut loader_result.additional_data)
       .await?;
+    // loader results with diagnostics are not persisted, so the diagnostics can be reported again
+    let mut cache_entry =
+      if cached_entry.is_none() && ds.is_empty() && build_module_occasion.is_enabled() {
+        BuildModuleCacheEntry::from_loader_result(
+          &loader_result,
+          &build_context.compiler_options.snapshot,
+        )
+      } else {
+        None
+      };
     self.add_diagnostics(ds);
+    if let Some(profile) = build_context.profile {
+      profile.set_loaders(std::mem::take(&mut loader_result.loader_timings));
+    }
 
     let content = if self.module_type().is_binary() {
       Content::Buffer(loader_result.content.into_bytes())
@@ -477,6 +508,10 @@ impl Module for NormalModule {
       build_info.build_dependencies = loader_result.build_dependencies;
       build_info.asset_filenames = loader_result.asset_filenames;
 
+      if let Some(cache_entry) = cache_entry {
+        build_module_occasion.set(self.id, cache_entry);
+      }
+
       return Ok(BuildResult {
         build_info,
         build_meta,
@@ -494,6 +529,9 @@ impl Module for NormalModule {
     build_info.build_dependencies = loader_result.build_dependencies;
     build_info.asset_filenames = loader_result.asset_filenames;
 
+    if let Some(profile) = build_context.profile {
+      profile.mark_parsing_start();
+    }
     let (
       ParseResult {
         source,
@@ -514,6 +552,7 @@ impl Module for NormalModule {
         module_parser_options: self.parser_options.as_ref(),
         module_type: &self.module_type,
         module_user_request: &self.user_request,
+        module_issuer: self.issuer.as_deref(),
         module_source_map_kind: *self.get_source_map_kind(),
         loaders: &self.loaders,
         resource_data: &self.resource_data,
@@ -523,6 +562,9 @@ impl Module for NormalModule {
         build_meta: &mut build_meta,
       })?
       .split_into_parts();
+    if let Some(profile) = build_context.profile {
+      profile.mark_parsing_end();
+    }
     self.add_diagnostics(ds);
     let optimization_bailouts = if let Some(side_effects_bailout) = side_effects_bailout {
       let short_id = self.readable_identifier(&build_context.compiler_options.context);
@@ -540,6 +582,18 @@ impl Module for NormalModule {
     self.code_generation_dependencies = Some(code_generation_dependencies);
     self.presentational_dependencies = Some(presentational_dependencies);
 
+    if let Some(cached_entry) = &cached_entry {
+      self.parser_and_generator.resume(&cached_entry.extra_data());
+    }
+    if let Some(cache_entry) = &mut cache_entry {
+      let mut extra_data = HashMap::new();
+      self.parser_and_generator.store(&mut extra_data);
+      cache_entry.set_extra_data(extra_data);
+    }
+    if let Some(cache_entry) = cache_entry {
+      build_module_occasion.set(self.id, cache_entry);
+    }
+
     let mut hasher = RspackHash::from(&build_context.compiler_options.output);
     self.update_hash(&mut hasher);
     build_meta.hash(&mut hasher);
@@ -591,6 +645,13 @@ impl Module for NormalModule {
         )?;
         code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
       }
+      // Generators can't return chunk init fragments directly, so they are passed by data.
+      if let Some(chunk_init_fragments) = code_generation_result.data.remove::<ChunkInitFragments>()
+      {
+        code_generation_result
+          .chunk_init_fragments
+          .extend(chunk_init_fragments);
+      }
       code_generation_result.set_hash(
         &compilation.options.output.hash_function,
         &compilation.options.output.hash_digest,
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, path::Path, sync::Arc};
+use std::{borrow::Cow, path::Path, sync::Arc, time::Instant};
 
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -290,7 +290,9 @@ impl NormalModuleFactory {
           };
 
           // default resolve
+          let start = Instant::now();
           let resource_data = resolve(resolve_args, plugin_driver).await;
+          data.resolve_duration += start.elapsed();
 
           match resource_data {
             Ok(ResolveResult::Resource(resource)) => {
@@ -401,6 +403,7 @@ impl NormalModuleFactory {
         }
       }
 
+      let start = Instant::now();
       let mut all_loaders = Vec::with_capacity(
         pre_loaders.len() + post_loaders.len() + normal_loaders.len() + inline_loaders.len(),
       );
@@ -435,6 +438,7 @@ impl NormalModuleFactory {
         all_loaders
           .push(resolve_each(plugin_driver, &self.options.context, &loader_resolver, &l).await?)
       }
+      data.resolve_duration += start.elapsed();
 
       async fn resolve_each(
         plugin_driver: &SharedPluginDriver,
@@ -541,6 +545,7 @@ impl NormalModuleFactory {
         resolved_resolve_options,
         loaders,
         contains_inline,
+        data.issuer.clone(),
       )
       .boxed()
     };
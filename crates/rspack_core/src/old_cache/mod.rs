@@ -1,33 +1,125 @@
 use std::{
   path::PathBuf,
   sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+    atomic::{AtomicBool, AtomicU32, Ordering},
+    Arc, Mutex,
   },
+  time::{Duration, Instant},
 };
 
-use crate::CompilerOptions;
+use crate::{CacheOptions, CompilerOptions, FileSystemCacheOptions};
 
 mod local;
 mod occasion;
 mod storage;
 pub use local::*;
-use occasion::{CodeGenerateOccasion, CreateChunkAssetsOccasion};
+pub use occasion::BuildModuleCacheEntry;
+use occasion::{BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion};
 use storage::new_storage;
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
+  /// Bumped whenever the compiler stops being idle, so a scheduled or running store of an
+  /// older generation gives up.
+  generation: AtomicU32,
+  idle_store: Option<IdleStore>,
+  pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
 }
 
+/// Timing of the deferred stores of the filesystem cache, same as the `IdleFileCachePlugin`
+/// of webpack.
+#[derive(Debug)]
+struct IdleStore {
+  idle_timeout: Duration,
+  idle_timeout_for_initial_store: Duration,
+  idle_timeout_after_large_changes: Duration,
+  stats: Mutex<IdleStoreStats>,
+}
+
+#[derive(Debug, Default)]
+struct IdleStoreStats {
+  is_initial_store: bool,
+  build_start: Option<Instant>,
+  /// Time spent in builds since the last store, with 10% decay per build
+  time_spent_in_build: Duration,
+  avg_time_spent_in_store: Duration,
+}
+
+impl IdleStore {
+  fn new(options: &FileSystemCacheOptions) -> Self {
+    Self {
+      idle_timeout: Duration::from_millis(options.idle_timeout as u64),
+      idle_timeout_for_initial_store: Duration::from_millis(
+        options.idle_timeout_for_initial_store as u64,
+      ),
+      idle_timeout_after_large_changes: Duration::from_millis(
+        options.idle_timeout_after_large_changes as u64,
+      ),
+      stats: Mutex::new(IdleStoreStats {
+        is_initial_store: true,
+        ..Default::default()
+      }),
+    }
+  }
+
+  fn build_started(&self) {
+    let mut stats = self.stats.lock().expect("should lock idle store stats");
+    stats.build_start = Some(Instant::now());
+  }
+
+  /// Returns the timeout of the next store. A build taking more than half of the average
+  /// store time is considered as a large change, which should be persisted sooner.
+  fn build_finished(&self) -> Duration {
+    let mut stats = self.stats.lock().expect("should lock idle store stats");
+    if let Some(build_start) = stats.build_start.take() {
+      stats.time_spent_in_build = stats.time_spent_in_build.mul_f32(0.9) + build_start.elapsed();
+    }
+    if stats.is_initial_store {
+      self.idle_timeout.min(self.idle_timeout_for_initial_store)
+    } else if stats.time_spent_in_build * 2 > stats.avg_time_spent_in_store {
+      self.idle_timeout.min(self.idle_timeout_after_large_changes)
+    } else {
+      self.idle_timeout
+    }
+  }
+
+  fn stored(&self, time_spent: Duration) {
+    let mut stats = self.stats.lock().expect("should lock idle store stats");
+    stats.avg_time_spent_in_store = if stats.is_initial_store {
+      time_spent
+    } else {
+      (stats.avg_time_spent_in_store + time_spent) / 2
+    };
+    stats.is_initial_store = false;
+    stats.time_spent_in_build = Duration::ZERO;
+  }
+}
+
 impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
+    // built modules are kept in the module graph between rebuilds, so loader results only
+    // need to be stored when they can be restored by another process
+    let build_module_storage = match &options.cache {
+      CacheOptions::FileSystem(_) => new_storage(&options, "build-module"),
+      _ => None,
+    };
+    let idle_store = match &options.cache {
+      CacheOptions::FileSystem(fs_options) => Some(IdleStore::new(fs_options)),
+      _ => None,
+    };
     Self {
       is_idle: true.into(),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      generation: Default::default(),
+      idle_store,
+      build_module_occasion: BuildModuleOccasion::new(build_module_storage),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options, "code-generate")),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(
+        &options,
+        "create-chunk-assets",
+      )),
     }
   }
 
@@ -35,13 +127,56 @@ impl Cache {
     // TODO
   }
 
-  pub fn begin_idle(&self) {
-    if self.is_idle.load(Ordering::Relaxed) {
-      // TODO clean cache
-    }
+  /// Schedule the store of the persistent cache, the store happens after the idle timeout in
+  /// the background and is cancelled once the compiler is busy again.
+  pub fn begin_idle(self: &Arc<Self>) {
+    self.is_idle.store(true, Ordering::Relaxed);
+    let Some(idle_store) = &self.idle_store else {
+      return;
+    };
+    let timeout = idle_store.build_finished();
+    let generation = self.generation.load(Ordering::Relaxed);
+    let Ok(handle) = tokio::runtime::Handle::try_current() else {
+      self.store(generation);
+      return;
+    };
+    let cache = self.clone();
+    handle.spawn(async move {
+      tokio::time::sleep(timeout).await;
+      if cache.generation.load(Ordering::Relaxed) == generation {
+        let _ = tokio::task::spawn_blocking(move || cache.store(generation)).await;
+      }
+    });
   }
 
   pub fn end_idle(&self) {
     self.is_idle.store(false, Ordering::Relaxed);
+    self.generation.fetch_add(1, Ordering::Relaxed);
+    if let Some(idle_store) = &self.idle_store {
+      idle_store.build_started();
+    }
+  }
+
+  /// Store the persistent cache right away, used when the compiler is closed.
+  pub async fn shutdown(self: &Arc<Self>) {
+    if self.idle_store.is_none() {
+      return;
+    }
+    let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+    let cache = self.clone();
+    let _ = tokio::task::spawn_blocking(move || cache.store(generation)).await;
+  }
+
+  fn store(&self, generation: u32) {
+    let start = Instant::now();
+    let is_aborted = || self.generation.load(Ordering::Relaxed) != generation;
+    self.build_module_occasion.store(&is_aborted);
+    self.code_generate_occasion.store(&is_aborted);
+    self.create_chunk_assets_occasion.store(&is_aborted);
+    if !is_aborted()
+      && let Some(idle_store) = &self.idle_store
+    {
+      idle_store.stored(start.elapsed());
+    }
   }
 }
@@ -0,0 +1,194 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use rkyv::{AlignedVec, Archive, Deserialize, Serialize};
+use rspack_identifier::Identifier;
+use rspack_loader_runner::{Content, LoaderResult};
+use rspack_sources::SourceMap;
+use rustc_hash::FxHashSet as HashSet;
+
+use crate::old_cache::storage::{
+  self, aligned_bytes, deserialize_item, serialize_item, StorageItem,
+};
+use crate::{BuildExtraDataType, Snapshot, SnapshotOptions};
+
+type Storage = dyn storage::Storage<BuildModuleCacheEntry>;
+
+fn extra_data_type_to_u8(ty: &BuildExtraDataType) -> u8 {
+  match ty {
+    BuildExtraDataType::CssParserAndGenerator => 0,
+    BuildExtraDataType::AssetParserAndGenerator => 1,
+    BuildExtraDataType::JavaScriptParserAndGenerator => 2,
+  }
+}
+
+fn extra_data_type_from_u8(ty: u8) -> Option<BuildExtraDataType> {
+  match ty {
+    0 => Some(BuildExtraDataType::CssParserAndGenerator),
+    1 => Some(BuildExtraDataType::AssetParserAndGenerator),
+    2 => Some(BuildExtraDataType::JavaScriptParserAndGenerator),
+    _ => None,
+  }
+}
+
+/// The loader result of a normal module together with the snapshot of the files it depends
+/// on, a warm build reuses it instead of running the loaders again.
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct BuildModuleCacheEntry {
+  content: Vec<u8>,
+  is_buffer: bool,
+  source_map: Option<String>,
+  file_dependencies: Vec<String>,
+  context_dependencies: Vec<String>,
+  missing_dependencies: Vec<String>,
+  build_dependencies: Vec<String>,
+  asset_filenames: Vec<String>,
+  extra_data: Vec<(u8, Vec<u8>)>,
+  snapshot: Snapshot,
+}
+
+impl BuildModuleCacheEntry {
+  /// Create an entry from a loader result, only results without additional data can be
+  /// persisted, and only when the module snapshot is enabled.
+  pub fn from_loader_result(
+    loader_result: &LoaderResult,
+    snapshot_options: &SnapshotOptions,
+  ) -> Option<Self> {
+    if !loader_result.cacheable
+      || !loader_result.additional_data.is_empty()
+      || !snapshot_options.module.is_enabled()
+    {
+      return None;
+    }
+    let to_strings = |paths: &HashSet<PathBuf>| {
+      paths
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+    };
+    let source_map = match &loader_result.source_map {
+      Some(map) => Some(map.clone().to_json().ok()?),
+      None => None,
+    };
+    let snapshot = Snapshot::new(
+      loader_result
+        .file_dependencies
+        .iter()
+        .chain(loader_result.context_dependencies.iter())
+        .map(|p| p.as_path()),
+      loader_result
+        .missing_dependencies
+        .iter()
+        .map(|p| p.as_path()),
+      &snapshot_options.module,
+      snapshot_options,
+    );
+    Some(Self {
+      content: loader_result.content.as_bytes().to_vec(),
+      is_buffer: matches!(loader_result.content, Content::Buffer(_)),
+      source_map,
+      file_dependencies: to_strings(&loader_result.file_dependencies),
+      context_dependencies: to_strings(&loader_result.context_dependencies),
+      missing_dependencies: to_strings(&loader_result.missing_dependencies),
+      build_dependencies: to_strings(&loader_result.build_dependencies),
+      asset_filenames: loader_result.asset_filenames.iter().cloned().collect(),
+      extra_data: vec![],
+      snapshot,
+    })
+  }
+
+  pub fn set_extra_data(&mut self, extra_data: HashMap<BuildExtraDataType, AlignedVec>) {
+    self.extra_data = extra_data
+      .into_iter()
+      .map(|(ty, data)| (extra_data_type_to_u8(&ty), data.into_vec()))
+      .collect();
+  }
+
+  pub fn extra_data(&self) -> HashMap<BuildExtraDataType, AlignedVec> {
+    self
+      .extra_data
+      .iter()
+      .filter_map(|(ty, data)| Some((extra_data_type_from_u8(*ty)?, aligned_bytes(data))))
+      .collect()
+  }
+
+  fn is_valid(&self) -> bool {
+    self.snapshot.is_valid()
+  }
+
+  pub fn to_loader_result(&self) -> Option<LoaderResult> {
+    let to_paths = |paths: &[String]| paths.iter().map(PathBuf::from).collect::<HashSet<_>>();
+    let content = if self.is_buffer {
+      Content::Buffer(self.content.clone())
+    } else {
+      Content::String(String::from_utf8(self.content.clone()).ok()?)
+    };
+    let source_map = match &self.source_map {
+      Some(map) => Some(SourceMap::from_json(map).ok()?),
+      None => None,
+    };
+    Some(LoaderResult {
+      cacheable: true,
+      file_dependencies: to_paths(&self.file_dependencies),
+      context_dependencies: to_paths(&self.context_dependencies),
+      missing_dependencies: to_paths(&self.missing_dependencies),
+      build_dependencies: to_paths(&self.build_dependencies),
+      asset_filenames: self.asset_filenames.iter().cloned().collect(),
+      content,
+      source_map,
+      additional_data: Default::default(),
+      loader_timings: vec![],
+    })
+  }
+}
+
+impl StorageItem for BuildModuleCacheEntry {
+  fn to_bytes(&self) -> Option<AlignedVec> {
+    serialize_item(self)
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    deserialize_item(bytes)
+  }
+}
+
+#[derive(Debug)]
+pub struct BuildModuleOccasion {
+  storage: Option<Box<Storage>>,
+}
+
+impl BuildModuleOccasion {
+  pub fn new(storage: Option<Box<Storage>>) -> Self {
+    Self { storage }
+  }
+
+  /// Only the filesystem cache needs to restore loader results, the memory cache keeps the
+  /// built modules in the module graph.
+  pub fn is_enabled(&self) -> bool {
+    self.storage.is_some()
+  }
+
+  /// Get the cached entry of a module, entries with changed dependencies are dropped.
+  pub fn get(&self, id: &Identifier) -> Option<BuildModuleCacheEntry> {
+    let storage = self.storage.as_ref()?;
+    let entry = storage.get(id)?;
+    if entry.is_valid() {
+      Some(entry)
+    } else {
+      storage.remove(id);
+      None
+    }
+  }
+
+  pub fn set(&self, id: Identifier, entry: BuildModuleCacheEntry) {
+    if let Some(storage) = &self.storage {
+      storage.set(id, entry);
+    }
+  }
+
+  pub fn store(&self, is_aborted: &dyn Fn() -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.store(is_aborted);
+    }
+  }
+}
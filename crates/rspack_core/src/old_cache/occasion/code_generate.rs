@@ -1,11 +1,78 @@
+use rkyv::{AlignedVec, Archive, Deserialize, Serialize};
 use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
 use rspack_identifier::Identifier;
 
-use crate::{get_runtime_key, RuntimeSpec, RuntimeSpecSet};
+use crate::old_cache::storage::{
+  deserialize_item, serialize_item, SerializableSource, StorageItem,
+};
+use crate::{get_runtime_key, RuntimeGlobals, RuntimeSpec, RuntimeSpecSet, SourceType};
 use crate::{old_cache::storage, BoxModule, CodeGenerationResult, Compilation, NormalModuleSource};
 
 type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
 
+#[derive(Debug, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct SerializableCodeGenerationResult {
+  source_types: Vec<String>,
+  sources: Vec<SerializableSource>,
+  runtime_requirements: u64,
+  hash: Option<String>,
+  runtime: Vec<String>,
+}
+
+impl StorageItem for Vec<(CodeGenerationResult, RuntimeSpec)> {
+  fn to_bytes(&self) -> Option<AlignedVec> {
+    let results = self
+      .iter()
+      .map(|(result, runtime)| {
+        // data and init fragments are trait objects, results with them are kept in memory only
+        if !result.data.is_empty()
+          || !result.chunk_init_fragments.is_empty()
+          || result.concatenation_scope.is_some()
+        {
+          return None;
+        }
+        let mut source_types = vec![];
+        let mut sources = vec![];
+        for (source_type, source) in result.inner() {
+          source_types.push(source_type.to_string());
+          sources.push(SerializableSource::from_source(source.as_ref())?);
+        }
+        Some(SerializableCodeGenerationResult {
+          source_types,
+          sources,
+          runtime_requirements: result.runtime_requirements.bits(),
+          hash: result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+          runtime: runtime.iter().map(|r| r.to_string()).collect(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serialize_item(&results)
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let results = deserialize_item::<Vec<SerializableCodeGenerationResult>>(bytes)?;
+    results
+      .into_iter()
+      .map(|result| {
+        let mut code_generation_result = CodeGenerationResult::default();
+        for (source_type, source) in result.source_types.iter().zip(result.sources) {
+          code_generation_result.add(
+            SourceType::from(source_type.as_str()),
+            source.into_source()?,
+          );
+        }
+        code_generation_result.runtime_requirements =
+          RuntimeGlobals::from_bits(result.runtime_requirements)?;
+        code_generation_result.hash = result.hash.as_deref().map(RspackHashDigest::from);
+        let runtime = RuntimeSpec::from_iter(result.runtime.into_iter().map(Into::into));
+        Some((code_generation_result, runtime))
+      })
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +83,12 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
+  pub fn store(&self, is_aborted: &dyn Fn() -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.store(is_aborted);
+    }
+  }
+
   pub fn use_cache<'a, G>(
     &self,
     module: &'a BoxModule,
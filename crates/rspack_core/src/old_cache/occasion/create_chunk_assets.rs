@@ -1,11 +1,96 @@
 use futures::Future;
-use rspack_error::Result;
+use itertools::Itertools;
+use rkyv::{AlignedVec, Archive, Deserialize, Serialize};
+use rspack_error::{Diagnostic, Result};
 use rspack_identifier::Identifier;
 
-use crate::{old_cache::storage, Chunk, Compilation, NormalModuleSource, RenderManifestEntry};
+use crate::old_cache::storage::{
+  deserialize_item, serialize_item, SerializableSource, StorageItem,
+};
+use crate::{old_cache::storage, AssetInfo, AssetInfoRelated, Chunk, RenderManifestEntry};
 
 type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
 
+#[derive(Debug, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct SerializableRenderManifestEntry {
+  source: SerializableSource,
+  filename: String,
+  immutable: bool,
+  minimized: bool,
+  chunk_hash: Vec<String>,
+  content_hash: Vec<String>,
+  development: bool,
+  hot_module_replacement: bool,
+  javascript_module: Option<bool>,
+  related_source_map: Option<String>,
+  related_variants: Vec<String>,
+  version: String,
+  source_filename: Option<String>,
+  auxiliary: bool,
+  has_filename: bool,
+}
+
+impl StorageItem for Vec<RenderManifestEntry> {
+  fn to_bytes(&self) -> Option<AlignedVec> {
+    let entries = self
+      .iter()
+      .map(|entry| {
+        let info = &entry.info;
+        Some(SerializableRenderManifestEntry {
+          source: SerializableSource::from_source(entry.source.as_ref())?,
+          filename: entry.filename().to_string(),
+          immutable: info.immutable,
+          minimized: info.minimized,
+          chunk_hash: info.chunk_hash.iter().cloned().collect(),
+          content_hash: info.content_hash.iter().cloned().collect(),
+          development: info.development,
+          hot_module_replacement: info.hot_module_replacement,
+          javascript_module: info.javascript_module,
+          related_source_map: info.related.source_map.clone(),
+          related_variants: info.related.variants.clone(),
+          version: info.version.clone(),
+          source_filename: info.source_filename.clone(),
+          auxiliary: entry.auxiliary,
+          has_filename: entry.has_filename(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serialize_item(&entries)
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let entries = deserialize_item::<Vec<SerializableRenderManifestEntry>>(bytes)?;
+    entries
+      .into_iter()
+      .map(|entry| {
+        let info = AssetInfo {
+          immutable: entry.immutable,
+          minimized: entry.minimized,
+          chunk_hash: entry.chunk_hash.into_iter().collect(),
+          content_hash: entry.content_hash.into_iter().collect(),
+          development: entry.development,
+          hot_module_replacement: entry.hot_module_replacement,
+          javascript_module: entry.javascript_module,
+          related: AssetInfoRelated {
+            source_map: entry.related_source_map,
+            variants: entry.related_variants,
+          },
+          version: entry.version,
+          source_filename: entry.source_filename,
+        };
+        Some(RenderManifestEntry::new(
+          entry.source.into_source()?,
+          entry.filename,
+          info,
+          entry.auxiliary,
+          entry.has_filename,
+        ))
+      })
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
   storage: Option<Box<Storage>>,
@@ -16,48 +101,43 @@ impl CreateChunkAssetsOccasion {
     Self { storage }
   }
 
-  pub async fn use_cache<'a, G, F>(
+  pub fn store(&self, is_aborted: &dyn Fn() -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.store(is_aborted);
+    }
+  }
+
+  /// Reuse the rendered assets of a chunk whose id and hashes are unchanged. The hashes cover
+  /// the code generation results and ids of the modules in the chunk, so the cache is also
+  /// valid in another process. Assets rendered with diagnostics are not cached.
+  pub async fn use_cache<G, F>(
     &self,
-    compilation: &Compilation,
     chunk: &Chunk,
     generator: G,
-  ) -> Result<Vec<RenderManifestEntry>>
+  ) -> Result<(Vec<RenderManifestEntry>, Vec<Diagnostic>)>
   where
-    G: Fn() -> F,
-    F: Future<Output = Result<Vec<RenderManifestEntry>>>,
+    G: FnOnce() -> F,
+    F: Future<Output = Result<(Vec<RenderManifestEntry>, Vec<Diagnostic>)>>,
   {
-    let storage = match &self.storage {
-      Some(s) => s,
-      // no cache return directly
-      None => return generator().await,
+    let (Some(storage), Some(id), Some(hash)) = (&self.storage, &chunk.id, &chunk.hash) else {
+      return generator().await;
     };
 
-    let chunk_id = Identifier::from(chunk.expect_id());
-    let modules = &compilation
-      .chunk_graph
-      .get_chunk_graph_chunk(&chunk.ukey)
-      .modules;
-    let is_cache_valid = modules.iter().all(|module_id| {
-      matches!(
-        compilation
-          .get_module_graph()
-          .module_by_identifier(module_id)
-          .and_then(|m| m.as_normal_module())
-          .map(|m| matches!(m.source(), NormalModuleSource::Unbuild)),
-        Some(true)
-      )
-    });
-
-    if is_cache_valid {
-      // read
-      if let Some(data) = storage.get(&chunk_id) {
-        return Ok(data);
-      }
+    let content_hash = chunk
+      .content_hash
+      .iter()
+      .map(|(source_type, hash)| format!("{source_type}:{}", hash.encoded()))
+      .sorted_unstable()
+      .join(",");
+    let cache_id = Identifier::from(format!("{id}|{}|{content_hash}", hash.encoded()));
+    if let Some(manifest) = storage.get(&cache_id) {
+      return Ok((manifest, vec![]));
+    }
+
+    let (manifest, diagnostics) = generator().await?;
+    if diagnostics.is_empty() {
+      storage.set(cache_id, manifest.clone());
     }
-    // run generator and save to cache
-    let data = generator().await?;
-    // TODO sometime may not run save
-    storage.set(chunk_id, data.clone());
-    Ok(data)
+    Ok((manifest, diagnostics))
   }
 }
@@ -1,3 +1,5 @@
+mod build_module;
+pub use build_module::*;
 mod code_generate;
 pub use code_generate::*;
 mod create_chunk_assets;
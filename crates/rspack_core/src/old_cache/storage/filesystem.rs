@@ -0,0 +1,360 @@
+use std::{
+  hash::{BuildHasherDefault, Hash},
+  io::{Read, Write},
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicBool, Ordering},
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use flate2::{read::GzDecoder, write::GzEncoder};
+use rkyv::{Archive, Deserialize, Serialize};
+use rspack_hash::{HashDigest, RspackHash};
+use rspack_identifier::{Identifier, IdentifierHasher};
+
+use super::{deserialize_item, serialize_item, Storage, StorageItem};
+use crate::{CacheCompression, Context, FileSystemCacheOptions, SnapshotStrategy};
+
+/// Bump it when the layout of the pack or any persisted item changes.
+const PACK_FORMAT_VERSION: &str = "1";
+
+#[derive(Debug, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct Pack {
+  identity: String,
+  entries: Vec<PackEntry>,
+}
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct PackEntry {
+  id: String,
+  /// Last access time in milliseconds since unix epoch
+  last_used: u64,
+  data: Vec<u8>,
+}
+
+/// A [Storage] that keeps items in memory and writes them to a pack file in the cache
+/// location when the compiler becomes idle, the pack will be restored by the next process.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  pack_path: PathBuf,
+  compression: CacheCompression,
+  identity: String,
+  max_age: Option<Duration>,
+  data: DashMap<Identifier, Item, BuildHasherDefault<IdentifierHasher>>,
+  /// Entries restored from disk which haven't been accessed in this process yet
+  restored: DashMap<Identifier, PackEntry, BuildHasherDefault<IdentifierHasher>>,
+  dirty: AtomicBool,
+}
+
+impl<Item> FileSystemStorage<Item> {
+  pub fn new(
+    options: &FileSystemCacheOptions,
+    context: &Context,
+    build_dependencies_snapshot: &SnapshotStrategy,
+    name: &str,
+  ) -> Self {
+    let extension = match options.compression {
+      CacheCompression::None => "pack",
+      CacheCompression::Gzip => "pack.gz",
+      CacheCompression::Brotli => "pack.br",
+    };
+    let pack_path = cache_location(options, context).join(format!("{name}.{extension}"));
+    let identity = cache_identity(options, context, build_dependencies_snapshot);
+    let max_age = (options.max_age > 0).then(|| Duration::from_millis(options.max_age as u64));
+    let restored = DashMap::default();
+    if let Some(pack) = read_pack(&pack_path, options.compression) {
+      if pack.identity == identity {
+        let now = now();
+        for entry in pack.entries {
+          if is_expired(entry.last_used, now, max_age) {
+            continue;
+          }
+          restored.insert(Identifier::from(entry.id.as_str()), entry);
+        }
+      }
+    }
+    Self {
+      pack_path,
+      compression: options.compression,
+      identity,
+      max_age,
+      data: DashMap::default(),
+      restored,
+      dirty: AtomicBool::new(false),
+    }
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: StorageItem + Clone + std::fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    if let Some(item) = self.data.get(id) {
+      return Some(item.clone());
+    }
+    let (_, entry) = self.restored.remove(id)?;
+    let item = Item::from_bytes(&entry.data)?;
+    self.data.insert(*id, item.clone());
+    self.dirty.store(true, Ordering::Relaxed);
+    Some(item)
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.restored.remove(&id);
+    self.data.insert(id, data);
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    self.data.remove(id);
+    self.restored.remove(id);
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  fn store(&self, is_aborted: &dyn Fn() -> bool) {
+    if !self.dirty.swap(false, Ordering::Relaxed) {
+      return;
+    }
+    let now = now();
+    let mut entries = Vec::with_capacity(self.data.len() + self.restored.len());
+    for item in self.data.iter() {
+      if is_aborted() {
+        self.dirty.store(true, Ordering::Relaxed);
+        return;
+      }
+      if let Some(data) = item.value().to_bytes() {
+        entries.push(PackEntry {
+          id: item.key().to_string(),
+          last_used: now,
+          data: data.into_vec(),
+        });
+      }
+    }
+    entries.extend(
+      self
+        .restored
+        .iter()
+        .filter(|entry| !is_expired(entry.last_used, now, self.max_age))
+        .map(|entry| entry.value().clone()),
+    );
+    if is_aborted() {
+      self.dirty.store(true, Ordering::Relaxed);
+      return;
+    }
+    let pack = Pack {
+      identity: self.identity.clone(),
+      entries,
+    };
+    if let Err(e) = write_pack(&self.pack_path, &pack, self.compression) {
+      tracing::warn!(
+        "Failed to store cache pack {}: {e}",
+        self.pack_path.display()
+      );
+    }
+  }
+}
+
+/// Resolve the directory to store packs, same as `path.resolve(cacheDirectory, name)` in webpack
+/// when `cache_location` is not specified.
+fn cache_location(options: &FileSystemCacheOptions, context: &Context) -> PathBuf {
+  if !options.cache_location.is_empty() {
+    return resolve(context, &options.cache_location);
+  }
+  let cache_directory = if options.cache_directory.is_empty() {
+    Path::new(context.as_str()).join("node_modules/.cache/rspack")
+  } else {
+    resolve(context, &options.cache_directory)
+  };
+  let name = if options.name.is_empty() {
+    "default"
+  } else {
+    &options.name
+  };
+  cache_directory.join(name)
+}
+
+fn resolve(context: &Context, path: &str) -> PathBuf {
+  Path::new(context.as_str()).join(path)
+}
+
+/// Packs written with a different version or changed build dependencies are discarded, build
+/// dependencies are compared by the `snapshot.buildDependencies` strategy.
+fn cache_identity(
+  options: &FileSystemCacheOptions,
+  context: &Context,
+  snapshot: &SnapshotStrategy,
+) -> String {
+  let mut hasher = RspackHash::new(&options.hash_algorithm);
+  PACK_FORMAT_VERSION.hash(&mut hasher);
+  options.version.hash(&mut hasher);
+  options.name.hash(&mut hasher);
+  let mut build_dependencies = options.build_dependencies.clone();
+  build_dependencies.sort();
+  for dependency in build_dependencies {
+    let path = resolve(context, &dependency);
+    dependency.hash(&mut hasher);
+    if snapshot.hash {
+      std::fs::read(&path).ok().hash(&mut hasher);
+    } else if snapshot.timestamp {
+      std::fs::metadata(&path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .hash(&mut hasher);
+    }
+  }
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+fn read_pack(path: &Path, compression: CacheCompression) -> Option<Pack> {
+  let bytes = std::fs::read(path).ok()?;
+  let bytes = decompress(bytes, compression).ok()?;
+  deserialize_item::<Pack>(&bytes)
+}
+
+fn write_pack(path: &Path, pack: &Pack, compression: CacheCompression) -> std::io::Result<()> {
+  let bytes = serialize_item(pack).ok_or_else(|| {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, "failed to serialize pack")
+  })?;
+  let bytes = compress(&bytes, compression)?;
+  if let Some(dir) = path.parent() {
+    std::fs::create_dir_all(dir)?;
+  }
+  // write to a temporary file first, so a crashed process never leaves a broken pack
+  let temp_path = path.with_extension("pack_");
+  std::fs::write(&temp_path, bytes)?;
+  std::fs::rename(temp_path, path)
+}
+
+fn compress(bytes: &[u8], compression: CacheCompression) -> std::io::Result<Vec<u8>> {
+  match compression {
+    CacheCompression::None => Ok(bytes.to_vec()),
+    CacheCompression::Gzip => {
+      let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::fast());
+      encoder.write_all(bytes)?;
+      encoder.finish()
+    }
+    CacheCompression::Brotli => {
+      let mut output = Vec::new();
+      {
+        // a low quality keeps storing large caches fast, packs are mostly rkyv padding and
+        // source text which compress well anyway
+        let mut encoder = brotli::CompressorWriter::new(&mut output, 4096, 4, 22);
+        encoder.write_all(bytes)?;
+      }
+      Ok(output)
+    }
+  }
+}
+
+fn decompress(bytes: Vec<u8>, compression: CacheCompression) -> std::io::Result<Vec<u8>> {
+  let mut output = Vec::new();
+  match compression {
+    CacheCompression::None => return Ok(bytes),
+    CacheCompression::Gzip => {
+      GzDecoder::new(bytes.as_slice()).read_to_end(&mut output)?;
+    }
+    CacheCompression::Brotli => {
+      brotli::Decompressor::new(bytes.as_slice(), 4096).read_to_end(&mut output)?;
+    }
+  }
+  Ok(output)
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+fn is_expired(last_used: u64, now: u64, max_age: Option<Duration>) -> bool {
+  max_age.is_some_and(|max_age| now.saturating_sub(last_used) > max_age.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod test {
+  use rkyv::AlignedVec;
+  use rspack_identifier::Identifier;
+
+  use super::{FileSystemStorage, Storage, StorageItem};
+  use crate::{CacheCompression, Context, FileSystemCacheOptions, SnapshotStrategy};
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct Item(String);
+
+  impl StorageItem for Item {
+    fn to_bytes(&self) -> Option<AlignedVec> {
+      let mut bytes = AlignedVec::new();
+      bytes.extend_from_slice(self.0.as_bytes());
+      Some(bytes)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+      String::from_utf8(bytes.to_vec()).ok().map(Self)
+    }
+  }
+
+  #[test]
+  fn filesystem_storage_restore_pack() {
+    let dir =
+      std::env::temp_dir().join(format!("rspack-filesystem-storage-{}", std::process::id()));
+    let context = Context::new(dir.to_string_lossy().to_string());
+    let options = FileSystemCacheOptions {
+      cache_directory: "cache".into(),
+      name: "test".into(),
+      version: "1".into(),
+      ..Default::default()
+    };
+    let snapshot = SnapshotStrategy {
+      timestamp: false,
+      hash: true,
+    };
+
+    let storage = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+    storage.set(Identifier::from("a"), Item("a".into()));
+    storage.store(&|| false);
+    assert!(dir.join("cache/test/item.pack").exists());
+
+    let storage = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+    assert_eq!(storage.get(&Identifier::from("a")), Some(Item("a".into())));
+    assert_eq!(storage.get(&Identifier::from("b")), None);
+
+    // packs of another version are discarded
+    let options = FileSystemCacheOptions {
+      version: "2".into(),
+      ..options
+    };
+    let storage = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+    assert_eq!(storage.get(&Identifier::from("a")), None);
+
+    // compressed packs are stored next to the uncompressed ones
+    for compression in [CacheCompression::Gzip, CacheCompression::Brotli] {
+      let options = FileSystemCacheOptions {
+        compression,
+        ..options.clone()
+      };
+      let storage = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+      storage.set(Identifier::from("c"), Item("c".into()));
+      storage.store(&|| false);
+      let storage = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+      assert_eq!(storage.get(&Identifier::from("c")), Some(Item("c".into())));
+    }
+    assert!(dir.join("cache/test/item.pack.gz").exists());
+    assert!(dir.join("cache/test/item.pack.br").exists());
+
+    // aborted stores keep the pending items
+    let storage = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+    storage.set(Identifier::from("d"), Item("d".into()));
+    storage.store(&|| true);
+    let restored = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+    assert_eq!(restored.get(&Identifier::from("d")), None);
+    storage.store(&|| false);
+    let restored = FileSystemStorage::<Item>::new(&options, &context, &snapshot, "item");
+    assert_eq!(restored.get(&Identifier::from("d")), Some(Item("d".into())));
+
+    std::fs::remove_dir_all(dir).expect("should remove cache dir");
+  }
+}
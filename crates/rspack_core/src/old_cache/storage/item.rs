@@ -0,0 +1,92 @@
+use rkyv::{from_bytes, to_bytes, AlignedVec, Archive, Deserialize, Serialize};
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource,
+  WithoutOriginalOptions,
+};
+
+/// Items which can be written to a persistent [super::Storage].
+///
+/// `to_bytes` returns `None` for items that can't be persisted (e.g. they contain
+/// trait objects), these items only live in memory.
+pub trait StorageItem: Sized {
+  fn to_bytes(&self) -> Option<AlignedVec>;
+  fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Copy bytes into an aligned buffer, rkyv requires the archived root to be aligned.
+pub fn aligned_bytes(bytes: &[u8]) -> AlignedVec {
+  let mut aligned = AlignedVec::with_capacity(bytes.len());
+  aligned.extend_from_slice(bytes);
+  aligned
+}
+
+pub fn serialize_item<T>(item: &T) -> Option<AlignedVec>
+where
+  T: Serialize<rkyv::ser::serializers::AllocSerializer<1024>>,
+{
+  to_bytes::<_, 1024>(item).ok()
+}
+
+pub fn deserialize_item<T>(bytes: &[u8]) -> Option<T>
+where
+  T: Archive,
+  T::Archived: for<'a> rkyv::CheckBytes<rkyv::validation::validators::DefaultValidator<'a>>
+    + Deserialize<T, rkyv::de::deserializers::SharedDeserializeMap>,
+{
+  from_bytes::<T>(&aligned_bytes(bytes)).ok()
+}
+
+/// Owned form of a [BoxSource], the structure of the source is flattened to its content
+/// and source map.
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct SerializableSource {
+  content: Vec<u8>,
+  is_buffer: bool,
+  source_map: Option<String>,
+}
+
+impl SerializableSource {
+  pub fn from_source(source: &dyn Source) -> Option<Self> {
+    let content = source.buffer().into_owned();
+    let is_buffer = std::str::from_utf8(&content).is_err()
+      || source
+        .as_any()
+        .downcast_ref::<RawSource>()
+        .map(|s| s.is_buffer())
+        .unwrap_or_default();
+    let source_map = if is_buffer {
+      None
+    } else {
+      match source.map(&MapOptions::default()) {
+        Some(map) => Some(map.to_json().ok()?),
+        None => None,
+      }
+    };
+    Some(Self {
+      content,
+      is_buffer,
+      source_map,
+    })
+  }
+
+  pub fn into_source(self) -> Option<BoxSource> {
+    if self.is_buffer {
+      return Some(RawSource::from(self.content).boxed());
+    }
+    let content = String::from_utf8(self.content).ok()?;
+    Some(match self.source_map {
+      Some(map) => {
+        let source_map = SourceMap::from_json(&map).ok()?;
+        let name = source_map.file().unwrap_or_default().to_string();
+        SourceMapSource::new(WithoutOriginalOptions {
+          value: content,
+          name,
+          source_map,
+        })
+        .boxed()
+      }
+      None => RawSource::from(content).boxed(),
+    })
+  }
+}
@@ -2,26 +2,39 @@ use std::fmt::Debug;
 
 use rspack_identifier::Identifier;
 
-use crate::CacheOptions;
+use crate::{CacheOptions, CompilerOptions};
 
+mod filesystem;
+mod item;
 mod memory;
+use filesystem::FileSystemStorage;
+pub use item::*;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
-  // fn end_idle(&self);
+  /// Write pending items to the persistent storage, it should stop early once `is_aborted`
+  /// returns true and keep the pending items for the next store.
+  fn store(&self, _is_aborted: &dyn Fn() -> bool) {}
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+/// Create the storage of an occasion, `name` is used to separate the persisted data of
+/// different occasions in the same cache location.
+pub fn new_storage<Item>(options: &CompilerOptions, name: &str) -> Option<Box<dyn Storage<Item>>>
 where
-  Item: Debug + Clone + Send + Sync + 'static,
+  Item: StorageItem + Debug + Clone + Send + Sync + 'static,
 {
-  match options {
+  match &options.cache {
     CacheOptions::Disabled => None,
-    _ => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::Memory(_) => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::FileSystem(fs_options) => Some(Box::new(FileSystemStorage::new(
+      fs_options,
+      &options.context,
+      &options.snapshot.build_dependencies,
+      name,
+    ))),
   }
 }
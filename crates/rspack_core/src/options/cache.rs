@@ -1,10 +1,37 @@
+use rspack_error::{error, Error};
+
+use crate::HashFunction;
+
 #[derive(Debug, Clone, Default)]
 pub struct MemoryCacheOptions {
   /// Define the lifespan of unused cache entries in the memory cache.
   pub max_generations: u32,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheCompression {
+  #[default]
+  None,
+  Gzip,
+  Brotli,
+}
+
+impl TryFrom<&str> for CacheCompression {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "none" => Ok(Self::None),
+      "gzip" => Ok(Self::Gzip),
+      "brotli" => Ok(Self::Brotli),
+      _ => Err(error!(
+        "Unsupported cache compression \"{value}\", expected one of \"gzip\", \"brotli\" or \"none\""
+      )),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
 pub struct FileSystemCacheOptions {
   // Collect unused memory allocated during deserialization
   // allow_collecting_memory: bool,
@@ -20,14 +47,16 @@ pub struct FileSystemCacheOptions {
   pub cache_directory: String,
   /// Locations for the cache. Defaults to path.resolve(cache.cacheDirectory, cache.name)
   pub cache_location: String,
-  // Algorithm used the hash generation.
-  // hash_algorithm: "md4" | "md5" | ...,
-  // Compression type used for the cache files.
-  // compression: false | "gzip" | "brotli",
-  // Dnotes the time period after which the cache storing should happen.
-  // idle_timeout: number,
-  // idle_timeout_for_initial_store: number,
-  // idle_timeout_after_large_changes": number,
+  /// Algorithm used the hash generation.
+  pub hash_algorithm: HashFunction,
+  /// Compression type used for the cache files.
+  pub compression: CacheCompression,
+  /// Time in milliseconds after which idle period the cache storing should happen.
+  pub idle_timeout: u32,
+  /// Time in milliseconds after which idle period the initial cache storing should happen.
+  pub idle_timeout_for_initial_store: u32,
+  /// Time in milliseconds after which idle period the cache storing should happen when larger changes have been detected.
+  pub idle_timeout_after_large_changes: u32,
   /// Name for the cache
   pub name: String,
   // Store strategy
@@ -36,6 +65,25 @@ pub struct FileSystemCacheOptions {
   pub version: String,
 }
 
+impl Default for FileSystemCacheOptions {
+  fn default() -> Self {
+    Self {
+      max_age: Default::default(),
+      profile: Default::default(),
+      build_dependencies: Default::default(),
+      cache_directory: Default::default(),
+      cache_location: Default::default(),
+      hash_algorithm: HashFunction::Xxhash64,
+      compression: Default::default(),
+      idle_timeout: Default::default(),
+      idle_timeout_for_initial_store: Default::default(),
+      idle_timeout_after_large_changes: Default::default(),
+      name: Default::default(),
+      version: Default::default(),
+    }
+  }
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum CacheOptions {
   #[default]
@@ -43,3 +91,30 @@ pub enum CacheOptions {
   Memory(MemoryCacheOptions),
   FileSystem(FileSystemCacheOptions),
 }
+
+impl CacheOptions {
+  pub fn is_enabled(&self) -> bool {
+    !matches!(self, Self::Disabled)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn compression() {
+    assert_eq!(
+      CacheCompression::try_from("brotli").expect("should parse"),
+      CacheCompression::Brotli
+    );
+    assert_eq!(
+      CacheCompression::try_from("none").expect("should parse"),
+      CacheCompression::None
+    );
+    let error = CacheCompression::try_from("zstd").expect_err("should fail");
+    assert!(error
+      .to_string()
+      .contains("\"gzip\", \"brotli\" or \"none\""));
+  }
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{
   Builtins, CacheOptions, Context, DevServerOptions, Experiments, IncrementalRebuildMakeState,
   Mode, ModuleOptions, NodeOption, Optimization, OutputOptions, Resolve, SnapshotOptions,
@@ -22,6 +24,12 @@ pub struct CompilerOptions {
   pub node: Option<NodeOption>,
   pub optimization: Optimization,
   pub profile: bool,
+  /// Filename of the JSON profile report, relative to the output path
+  pub profile_report: Option<String>,
+  /// Path of the JSON file records are read from
+  pub records_input_path: Option<PathBuf>,
+  /// Path of the JSON file records are written to
+  pub records_output_path: Option<PathBuf>,
   pub bail: bool,
   pub builtins: Builtins,
 }
@@ -7,6 +7,7 @@ use async_recursion::async_recursion;
 use bitflags::bitflags;
 use derivative::Derivative;
 use futures::future::BoxFuture;
+use json::JsonValue;
 use rspack_error::Result;
 use rspack_macros::MergeFrom;
 use rspack_regex::RspackRegex;
@@ -37,6 +38,7 @@ pub enum ParserOptions {
   CssAuto(CssAutoParserOptions),
   CssModule(CssModuleParserOptions),
   Javascript(JavascriptParserOptions),
+  Json(JsonParserOptions),
   Unknown,
 }
 
@@ -57,6 +59,7 @@ impl ParserOptions {
   get_variant!(get_css_auto, CssAuto, CssAutoParserOptions);
   get_variant!(get_css_module, CssModule, CssModuleParserOptions);
   get_variant!(get_javascript, Javascript, JavascriptParserOptions);
+  get_variant!(get_json, Json, JsonParserOptions);
 }
 
 #[derive(Debug, Clone, Copy, MergeFrom)]
@@ -179,17 +182,79 @@ pub struct JavascriptParserOptions {
   pub strict_export_presence: bool,
 }
 
+#[derive(Debug, Clone, MergeFrom)]
+pub struct JsonParserOptions {
+  /// How deep nested exports of the json data are tracked, unlimited if not set.
+  pub exports_depth: Option<u32>,
+  pub parse: Option<JsonParseFn>,
+}
+
+/// Replaces the default parser of json modules, receives the source and returns the data.
+#[derive(Clone)]
+pub struct JsonParseFn(Arc<dyn Fn(String) -> Result<JsonValue> + Sync + Send>);
+
+impl JsonParseFn {
+  pub fn new(f: impl Fn(String) -> Result<JsonValue> + Sync + Send + 'static) -> Self {
+    Self(Arc::new(f))
+  }
+
+  pub fn call(&self, source: String) -> Result<JsonValue> {
+    (self.0)(source)
+  }
+}
+
+impl fmt::Debug for JsonParseFn {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    "JsonParseFn(...)".fmt(f)
+  }
+}
+
+impl MergeFrom for JsonParseFn {
+  fn merge_from(self, other: &Self) -> Self {
+    other.clone()
+  }
+}
+
 #[derive(Debug, Clone, MergeFrom)]
 pub struct AssetParserOptions {
   pub data_url_condition: Option<AssetParserDataUrl>,
 }
 
-#[derive(Debug, Clone, MergeFrom)]
+#[derive(Clone)]
 pub enum AssetParserDataUrl {
   Options(AssetParserDataUrlOptions),
-  // TODO: Function
+  Func(AssetParserDataUrlFn),
+}
+
+impl fmt::Debug for AssetParserDataUrl {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Options(i) => i.fmt(f),
+      Self::Func(_) => "Func(...)".fmt(f),
+    }
+  }
 }
 
+impl MergeFrom for AssetParserDataUrl {
+  fn merge_from(self, other: &Self) -> Self {
+    match (self, other) {
+      (Self::Options(a), Self::Options(b)) => Self::Options(a.merge_from(b)),
+      (_, other) => other.clone(),
+    }
+  }
+}
+
+pub struct AssetParserDataUrlFnArgs {
+  pub source: Vec<u8>,
+  pub filename: String,
+  /// Resource of the module which imports the asset first, depends on the build order
+  /// when the asset has several importers.
+  pub issuer: Option<String>,
+}
+
+/// Decides whether the asset is inlined as a data url.
+pub type AssetParserDataUrlFn = Arc<dyn Fn(AssetParserDataUrlFnArgs) -> Result<bool> + Sync + Send>;
+
 #[derive(Debug, Clone, MergeFrom)]
 pub struct AssetParserDataUrlOptions {
   pub max_size: Option<u32>,
@@ -284,6 +349,8 @@ impl GeneratorOptions {
 #[derive(Debug, Clone, MergeFrom)]
 pub struct AssetInlineGeneratorOptions {
   pub data_url: Option<AssetGeneratorDataUrl>,
+  pub image: Option<AssetGeneratorImageOptions>,
+  pub transform: Option<AssetGeneratorTransform>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -291,6 +358,8 @@ pub struct AssetResourceGeneratorOptions {
   pub emit: Option<bool>,
   pub filename: Option<Filename>,
   pub public_path: Option<PublicPath>,
+  pub image: Option<AssetGeneratorImageOptions>,
+  pub transform: Option<AssetGeneratorTransform>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -299,6 +368,42 @@ pub struct AssetGeneratorOptions {
   pub filename: Option<Filename>,
   pub public_path: Option<PublicPath>,
   pub data_url: Option<AssetGeneratorDataUrl>,
+  pub image: Option<AssetGeneratorImageOptions>,
+  pub transform: Option<AssetGeneratorTransform>,
+}
+
+pub struct AssetGeneratorTransformFnArgs {
+  pub content: Vec<u8>,
+  pub filename: String,
+}
+
+pub type AssetGeneratorTransformFn =
+  Arc<dyn Fn(AssetGeneratorTransformFnArgs) -> Result<Vec<u8>> + Sync + Send>;
+
+/// Transforms the content of an asset module before it is inlined or emitted.
+#[derive(Clone)]
+pub struct AssetGeneratorTransform(pub AssetGeneratorTransformFn);
+
+impl fmt::Debug for AssetGeneratorTransform {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    "AssetGeneratorTransform(...)".fmt(f)
+  }
+}
+
+impl MergeFrom for AssetGeneratorTransform {
+  fn merge_from(self, other: &Self) -> Self {
+    other.clone()
+  }
+}
+
+/// Enables the image pipeline of asset modules, which optimizes the images losslessly,
+/// and converts or resizes them by the `format` and `width` of the resource query.
+#[derive(Debug, Clone, Default, MergeFrom)]
+pub struct AssetGeneratorImageOptions {
+  /// Lossless optimizations of the PNG, JPEG and GIF images without query, defaults to `true`.
+  pub optimize: Option<bool>,
+  /// Quality of the lossy JPEG and AVIF encoders, from 1 to 100, defaults to 80.
+  pub quality: Option<u8>,
 }
 
 pub struct AssetGeneratorDataUrlFnArgs {
@@ -348,6 +453,10 @@ pub struct AssetGeneratorDataUrlOptions {
 pub enum DataUrlEncoding {
   None,
   Base64,
+  /// Percent-encoded UTF-8 text with `charset=utf-8`, for plain text mimetypes.
+  Utf8,
+  /// Minified and percent-encoded svg, which is smaller than base64.
+  Svg,
 }
 
 impl fmt::Display for DataUrlEncoding {
@@ -355,6 +464,8 @@ impl fmt::Display for DataUrlEncoding {
     match self {
       DataUrlEncoding::None => write!(f, ""),
       DataUrlEncoding::Base64 => write!(f, "base64"),
+      DataUrlEncoding::Utf8 => write!(f, "utf8"),
+      DataUrlEncoding::Svg => write!(f, "svg"),
     }
   }
 }
@@ -364,7 +475,9 @@ impl From<String> for DataUrlEncoding {
     match value.as_str() {
       "base64" => Self::Base64,
       "false" => Self::None,
-      _ => unreachable!("DataUrlEncoding should be base64 or false"),
+      "utf8" => Self::Utf8,
+      "svg" => Self::Svg,
+      _ => unreachable!("DataUrlEncoding should be base64, utf8, svg or false"),
     }
   }
 }
@@ -1,2 +1,86 @@
-#[derive(Debug, Clone, Default)]
-pub struct SnapshotOptions;
+use once_cell::sync::Lazy;
+use rspack_regex::RspackRegex;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SnapshotStrategy {
+  /// Compare timestamps of the files to determine invalidation.
+  pub timestamp: bool,
+  /// Compare content hashes of the files to determine invalidation.
+  pub hash: bool,
+}
+
+impl SnapshotStrategy {
+  pub fn is_enabled(&self) -> bool {
+    self.timestamp || self.hash
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum SnapshotPath {
+  /// Absolute path prefix
+  String(String),
+  /// The first capture group (or the whole match) is used as the root directory
+  Regexp(RspackRegex),
+}
+
+impl SnapshotPath {
+  /// Return the matched root directory of the path.
+  pub fn match_root<'a>(&self, path: &'a str) -> Option<&'a str> {
+    match self {
+      Self::String(prefix) => {
+        if path.starts_with(prefix.as_str()) {
+          Some(&path[..prefix.len()])
+        } else {
+          None
+        }
+      }
+      Self::Regexp(regexp) => {
+        let matched = regexp
+          .find_group(path, 1)
+          .or_else(|| regexp.find_group(path, 0))?;
+        Some(&path[..matched.end])
+      }
+    }
+  }
+}
+
+static NODE_MODULES_REGEXP: Lazy<RspackRegex> = Lazy::new(|| {
+  RspackRegex::new(r"^(.+?[\\/]node_modules[\\/])").expect("should be a valid regexp")
+});
+
+#[derive(Debug, Clone)]
+pub struct SnapshotOptions {
+  /// Paths that are managed by a package manager, packages in these paths are validated by
+  /// the name and version in their `package.json` instead of their files.
+  pub managed_paths: Vec<SnapshotPath>,
+  /// Paths that are managed by a package manager and contain a version or hash in their
+  /// path, files in these paths are assumed to be immutable.
+  pub immutable_paths: Vec<SnapshotPath>,
+  /// Snapshots for building modules.
+  pub module: SnapshotStrategy,
+  /// Snapshots for resolving requests.
+  pub resolve: SnapshotStrategy,
+  /// Snapshots for the build dependencies when using the persistent cache.
+  pub build_dependencies: SnapshotStrategy,
+}
+
+impl Default for SnapshotOptions {
+  fn default() -> Self {
+    Self {
+      managed_paths: vec![SnapshotPath::Regexp(NODE_MODULES_REGEXP.clone())],
+      immutable_paths: vec![],
+      module: SnapshotStrategy {
+        timestamp: true,
+        hash: false,
+      },
+      resolve: SnapshotStrategy {
+        timestamp: true,
+        hash: false,
+      },
+      build_dependencies: SnapshotStrategy {
+        timestamp: false,
+        hash: true,
+      },
+    }
+  }
+}
@@ -1,4 +1,149 @@
-#[derive(Debug, Default)]
+use rspack_error::{error, Error};
+
+/// Named presets of the stats options, same as webpack.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPreset {
+  None,
+  ErrorsOnly,
+  ErrorsWarnings,
+  #[default]
+  Normal,
+  Detailed,
+  Verbose,
+}
+
+impl TryFrom<&str> for StatsPreset {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "none" => Ok(Self::None),
+      "errors-only" => Ok(Self::ErrorsOnly),
+      "errors-warnings" => Ok(Self::ErrorsWarnings),
+      "normal" => Ok(Self::Normal),
+      "detailed" => Ok(Self::Detailed),
+      "verbose" => Ok(Self::Verbose),
+      _ => Err(error!("Unsupported stats preset: {value}")),
+    }
+  }
+}
+
+/// Controls which information is included in the stats JSON, the defaults follow
+/// `stats.toJson()` of webpack.
+#[derive(Debug, Clone)]
 pub struct StatsOptions {
   pub colors: bool,
+  pub hash: bool,
+  pub public_path: bool,
+  pub output_path: bool,
+  pub assets: bool,
+  pub chunks: bool,
+  pub chunk_modules: bool,
+  pub chunk_relations: bool,
+  pub chunk_groups: bool,
+  pub entrypoints: bool,
+  pub modules: bool,
+  pub nested_modules: bool,
+  pub orphan_modules: bool,
+  pub runtime_modules: bool,
+  pub module_assets: bool,
+  pub ids: bool,
+  pub reasons: bool,
+  pub source: bool,
+  pub used_exports: bool,
+  pub provided_exports: bool,
+  pub optimization_bailout: bool,
+  pub errors: bool,
+  pub errors_count: bool,
+  pub warnings: bool,
+  pub warnings_count: bool,
+}
+
+impl Default for StatsOptions {
+  fn default() -> Self {
+    Self::from_preset(StatsPreset::Normal)
+  }
+}
+
+impl StatsOptions {
+  /// Turns every toggle on or off, same as the `all` option.
+  pub fn all(value: bool) -> Self {
+    Self {
+      colors: false,
+      hash: value,
+      public_path: value,
+      output_path: value,
+      assets: value,
+      chunks: value,
+      chunk_modules: value,
+      chunk_relations: value,
+      chunk_groups: value,
+      entrypoints: value,
+      modules: value,
+      nested_modules: value,
+      orphan_modules: value,
+      runtime_modules: value,
+      module_assets: value,
+      ids: value,
+      reasons: value,
+      source: value,
+      used_exports: value,
+      provided_exports: value,
+      optimization_bailout: value,
+      errors: value,
+      errors_count: value,
+      warnings: value,
+      warnings_count: value,
+    }
+  }
+
+  pub fn from_preset(preset: StatsPreset) -> Self {
+    match preset {
+      StatsPreset::None => Self::all(false),
+      StatsPreset::ErrorsOnly => Self {
+        errors: true,
+        errors_count: true,
+        ..Self::all(false)
+      },
+      StatsPreset::ErrorsWarnings => Self {
+        errors: true,
+        errors_count: true,
+        warnings: true,
+        warnings_count: true,
+        ..Self::all(false)
+      },
+      StatsPreset::Normal => Self {
+        orphan_modules: false,
+        source: false,
+        ..Self::all(true)
+      },
+      StatsPreset::Detailed => Self {
+        chunk_modules: false,
+        orphan_modules: false,
+        source: false,
+        ..Self::all(true)
+      },
+      StatsPreset::Verbose => Self::all(true),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn presets() {
+    let normal = StatsOptions::default();
+    assert!(normal.reasons && normal.chunk_modules && !normal.source && !normal.orphan_modules);
+
+    let detailed = StatsOptions::from_preset(StatsPreset::Detailed);
+    assert!(detailed.modules && !detailed.chunk_modules && detailed.chunks && detailed.reasons);
+
+    let errors_only = StatsOptions::from_preset(StatsPreset::ErrorsOnly);
+    assert!(errors_only.errors && !errors_only.warnings && !errors_only.assets);
+
+    assert!(StatsOptions::from_preset(StatsPreset::Verbose).source);
+    assert!(StatsPreset::try_from("minimal").is_err());
+  }
 }
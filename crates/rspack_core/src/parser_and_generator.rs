@@ -24,6 +24,7 @@ pub struct ParseContext<'a> {
   pub module_identifier: ModuleIdentifier,
   pub module_type: &'a ModuleType,
   pub module_user_request: &'a str,
+  pub module_issuer: Option<&'a str>,
   pub module_parser_options: Option<&'a ParserOptions>,
   pub module_source_map_kind: SourceMapKind,
   #[derivative(Debug = "ignore")]
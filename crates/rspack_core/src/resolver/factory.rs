@@ -1,10 +1,17 @@
-use std::{hash::BuildHasherDefault, sync::Arc};
+use std::{
+  hash::BuildHasherDefault,
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+};
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use rustc_hash::FxHasher;
 
 use super::resolver_impl::Resolver;
-use crate::{DependencyCategory, Resolve};
+use crate::{CompilerOptions, DependencyCategory, Resolve, Snapshot, SnapshotOptions};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
 // Actually this should be ResolveOptionsWithDependencyCategory, it's a mistake from webpack, but keep the alignment for easily find the code in webpack
@@ -21,6 +28,12 @@ pub struct ResolverFactory {
   /// Different resolvers are used for different resolution strategies such as ESM and CJS.
   /// All resolvers share the same underlying cache.
   resolvers: DashMap<ResolveOptionsWithDependencyType, Arc<Resolver>, BuildHasherDefault<FxHasher>>,
+  /// Whether to record the dependencies of resolved requests for the resolve snapshot.
+  record_dependencies: AtomicBool,
+  file_dependencies: DashSet<PathBuf, BuildHasherDefault<FxHasher>>,
+  missing_dependencies: DashSet<PathBuf, BuildHasherDefault<FxHasher>>,
+  /// Snapshot of the dependencies of the cached resolutions.
+  snapshot: Mutex<Option<Snapshot>>,
 }
 
 impl Default for ResolverFactory {
@@ -32,6 +45,79 @@ impl Default for ResolverFactory {
 impl ResolverFactory {
   pub fn clear_cache(&self) {
     self.resolver.clear_cache();
+    self.file_dependencies.clear();
+    self.missing_dependencies.clear();
+    *self.snapshot.lock().expect("should lock snapshot") = None;
+  }
+
+  /// Clear the cache unless the snapshot of the cached resolutions is still valid, the cache
+  /// is always cleared when the cache or the resolve snapshot is disabled.
+  pub fn clear_outdated_cache(&self, options: &CompilerOptions) {
+    self.clear_cache_unless_valid(
+      options.cache.is_enabled() && options.snapshot.resolve.is_enabled(),
+    );
+  }
+
+  fn clear_cache_unless_valid(&self, enabled: bool) {
+    self.record_dependencies.store(enabled, Ordering::Relaxed);
+    let is_valid = enabled
+      && self
+        .snapshot
+        .lock()
+        .expect("should lock snapshot")
+        .as_ref()
+        .is_some_and(|snapshot| snapshot.is_valid());
+    if !is_valid {
+      self.clear_cache();
+    }
+  }
+
+  pub fn add_dependencies<'a>(
+    &self,
+    file_dependencies: impl IntoIterator<Item = &'a PathBuf>,
+    missing_dependencies: impl IntoIterator<Item = &'a PathBuf>,
+  ) {
+    if !self.record_dependencies.load(Ordering::Relaxed) {
+      return;
+    }
+    for file in file_dependencies {
+      if !self.file_dependencies.contains(file) {
+        self.file_dependencies.insert(file.clone());
+      }
+    }
+    for file in missing_dependencies {
+      if !self.missing_dependencies.contains(file) {
+        self.missing_dependencies.insert(file.clone());
+      }
+    }
+  }
+
+  /// Snapshot the dependencies of the resolutions made so far.
+  pub fn take_snapshot(&self, options: &CompilerOptions) {
+    self.snapshot_dependencies(&options.snapshot);
+  }
+
+  fn snapshot_dependencies(&self, options: &SnapshotOptions) {
+    if !self.record_dependencies.load(Ordering::Relaxed) {
+      return;
+    }
+    let file_dependencies = self
+      .file_dependencies
+      .iter()
+      .map(|f| f.key().clone())
+      .collect::<Vec<_>>();
+    let missing_dependencies = self
+      .missing_dependencies
+      .iter()
+      .map(|f| f.key().clone())
+      .collect::<Vec<_>>();
+    let snapshot = Snapshot::new(
+      file_dependencies.iter().map(|p| p.as_path()),
+      missing_dependencies.iter().map(|p| p.as_path()),
+      &options.resolve,
+      options,
+    );
+    *self.snapshot.lock().expect("should lock snapshot") = Some(snapshot);
   }
 
   pub fn new(options: Resolve) -> Self {
@@ -39,6 +125,10 @@ impl ResolverFactory {
       base_options: options.clone(),
       resolver: Resolver::new(options),
       resolvers: Default::default(),
+      record_dependencies: AtomicBool::new(false),
+      file_dependencies: Default::default(),
+      missing_dependencies: Default::default(),
+      snapshot: Default::default(),
     }
   }
 
@@ -57,3 +147,55 @@ impl ResolverFactory {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::ResolverFactory;
+  use crate::{Resolve, SnapshotOptions, SnapshotStrategy};
+
+  #[test]
+  fn keep_cache_while_resolve_snapshot_is_valid() {
+    let dir = std::env::temp_dir().join(format!("rspack-resolve-snapshot-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("should create temp dir");
+    let file = dir.join("package.json");
+    let missing = dir.join("index.ts");
+    std::fs::write(&file, "{}").expect("should write file");
+    let options = SnapshotOptions {
+      resolve: SnapshotStrategy {
+        timestamp: false,
+        hash: true,
+      },
+      ..Default::default()
+    };
+    let has_snapshot = |factory: &ResolverFactory| {
+      factory
+        .snapshot
+        .lock()
+        .expect("should lock snapshot")
+        .is_some()
+    };
+
+    let factory = ResolverFactory::new(Resolve {
+      extensions: Some(vec![".js".to_string()]),
+      ..Default::default()
+    });
+    factory.clear_cache_unless_valid(true);
+    factory.add_dependencies([&file], [&missing]);
+    factory.snapshot_dependencies(&options);
+    factory.clear_cache_unless_valid(true);
+    assert!(has_snapshot(&factory));
+
+    std::fs::write(&missing, "").expect("should write file");
+    factory.clear_cache_unless_valid(true);
+    assert!(!has_snapshot(&factory));
+    assert!(factory.file_dependencies.is_empty());
+
+    // dependencies are not recorded when the snapshot is disabled
+    factory.clear_cache_unless_valid(false);
+    factory.add_dependencies([&file], [&missing]);
+    factory.snapshot_dependencies(&options);
+    assert!(!has_snapshot(&factory));
+
+    let _ = std::fs::remove_dir_all(dir);
+  }
+}
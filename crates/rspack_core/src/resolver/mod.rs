@@ -302,6 +302,9 @@ pub async fn resolve(
     .resolve_with_context(args.context.as_ref(), args.specifier, &mut context)
     .map_err(|error| error.into_resolve_error(&args));
 
+  plugin_driver
+    .resolver_factory
+    .add_dependencies(&context.file_dependencies, &context.missing_dependencies);
   args.file_dependencies.extend(context.file_dependencies);
   args
     .missing_dependencies
@@ -8,6 +8,16 @@ bitflags! {
   pub struct RuntimeGlobals: u64 {
     const REQUIRE_SCOPE = 1 << 0;
 
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 1;
+
+    /**
+     * compile a wasm module from its module id for source phase imports
+     */
+    const COMPILE_WASM = 1 << 2;
+
     /**
      * the internal module object
      */
@@ -292,6 +302,8 @@ impl RuntimeGlobals {
       R::GET_FULL_HASH => "__webpack_require__.h",
       R::GLOBAL => "__webpack_require__.g",
       R::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime",
+      R::WASM_INSTANCES => "__webpack_require__.w",
+      R::COMPILE_WASM => "__webpack_require__.vs",
       R::INSTANTIATE_WASM => "__webpack_require__.v",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
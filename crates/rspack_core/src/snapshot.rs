@@ -0,0 +1,349 @@
+use std::{
+  hash::{Hash, Hasher},
+  path::Path,
+  time::UNIX_EPOCH,
+};
+
+use rkyv::{Archive, Deserialize, Serialize};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{SnapshotOptions, SnapshotStrategy};
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct FileSnapshot {
+  path: String,
+  /// Modified time in milliseconds, only available with the `timestamp` strategy
+  timestamp: Option<u64>,
+  /// Content hash, only available with the `hash` strategy
+  hash: Option<String>,
+}
+
+impl FileSnapshot {
+  fn new(path: String, strategy: &SnapshotStrategy) -> Self {
+    let timestamp = strategy.timestamp.then(|| file_timestamp(&path)).flatten();
+    let hash = strategy.hash.then(|| file_hash(&path)).flatten();
+    Self {
+      path,
+      timestamp,
+      hash,
+    }
+  }
+
+  fn is_valid(&self) -> bool {
+    if !Path::new(&self.path).exists() {
+      return false;
+    }
+    // timestamp is checked first, hash is only calculated when timestamp changes or is absent
+    if let Some(timestamp) = self.timestamp {
+      if file_timestamp(&self.path) == Some(timestamp) {
+        return true;
+      }
+      if self.hash.is_none() {
+        return false;
+      }
+    }
+    match &self.hash {
+      Some(hash) => file_hash(&self.path).as_ref() == Some(hash),
+      None => true,
+    }
+  }
+}
+
+/// A package in a managed path, validated by the name and version in its `package.json`.
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct ManagedSnapshot {
+  package_root: String,
+  version: Option<String>,
+}
+
+impl ManagedSnapshot {
+  fn is_valid(&self) -> bool {
+    package_version(&self.package_root) == self.version
+  }
+}
+
+/// A snapshot of the files that a cached item depends on, used to decide whether the cached
+/// item is still valid.
+///
+/// See: https://webpack.js.org/configuration/other-options/#snapshot
+#[derive(Debug, Clone, Default, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct Snapshot {
+  files: Vec<FileSnapshot>,
+  managed: Vec<ManagedSnapshot>,
+  missing: Vec<String>,
+}
+
+impl Snapshot {
+  pub fn new<'a>(
+    files: impl IntoIterator<Item = &'a Path>,
+    missing: impl IntoIterator<Item = &'a Path>,
+    strategy: &SnapshotStrategy,
+    options: &SnapshotOptions,
+  ) -> Self {
+    let mut snapshot = Self::default();
+    let mut managed: HashMap<String, Option<String>> = HashMap::default();
+    for file in files {
+      let path = file.to_string_lossy();
+      if options
+        .immutable_paths
+        .iter()
+        .any(|p| p.match_root(&path).is_some())
+      {
+        continue;
+      }
+      if let Some(package_root) = options.managed_paths.iter().find_map(|p| {
+        p.match_root(&path)
+          .and_then(|root| package_root(&path, root))
+      }) {
+        let version = managed
+          .entry(package_root.to_string())
+          .or_insert_with(|| package_version(package_root));
+        // packages without a readable package.json fall back to the file snapshot
+        if version.is_some() {
+          continue;
+        }
+      }
+      snapshot
+        .files
+        .push(FileSnapshot::new(path.to_string(), strategy));
+    }
+    snapshot.managed = managed
+      .into_iter()
+      .filter_map(|(package_root, version)| {
+        Some(ManagedSnapshot {
+          package_root,
+          version: Some(version?),
+        })
+      })
+      .collect();
+    snapshot.missing = missing
+      .into_iter()
+      .map(|p| p.to_string_lossy().to_string())
+      .collect();
+    snapshot
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.files.is_empty() && self.managed.is_empty() && self.missing.is_empty()
+  }
+
+  /// Check whether the files in the snapshot are unchanged.
+  pub fn is_valid(&self) -> bool {
+    self.files.iter().all(FileSnapshot::is_valid)
+      && self.managed.iter().all(ManagedSnapshot::is_valid)
+      && self.missing.iter().all(|p| !Path::new(p).exists())
+  }
+}
+
+/// Get the package directory of a path in a managed path, scoped packages take two segments,
+/// e.g. `/a/node_modules/@scope/pkg/index.js` -> `/a/node_modules/@scope/pkg`.
+///
+/// Hidden directories such as the virtual store of pnpm are skipped to the innermost
+/// `node_modules`, e.g. `/a/node_modules/.pnpm/pkg@1.0.0/node_modules/pkg/index.js` ->
+/// `/a/node_modules/.pnpm/pkg@1.0.0/node_modules/pkg`, and packages nested in the
+/// `node_modules` of another package are resolved to the nested package.
+fn package_root<'a>(path: &'a str, managed_root: &str) -> Option<&'a str> {
+  let rest = &path[managed_root.len()..];
+  let mut segments = rest.split(['/', '\\']);
+  let first = segments.next().filter(|s| !s.is_empty())?;
+  if first.starts_with('.') {
+    let (index, _) = rest
+      .rmatch_indices("node_modules")
+      .find(|(index, matched)| {
+        rest[..*index].ends_with(['/', '\\'])
+          && rest[index + matched.len()..].starts_with(['/', '\\'])
+      })?;
+    return package_root(
+      path,
+      &path[..managed_root.len() + index + "node_modules".len() + 1],
+    );
+  }
+  let len = if first.starts_with('@') {
+    first.len() + 1 + segments.next().filter(|s| !s.is_empty())?.len()
+  } else {
+    first.len()
+  };
+  let root = &path[..managed_root.len() + len];
+  let nested = &path[root.len()..];
+  if nested.len() > "/node_modules/".len()
+    && nested.starts_with(['/', '\\'])
+    && nested[1..].starts_with("node_modules")
+    && nested[1 + "node_modules".len()..].starts_with(['/', '\\'])
+  {
+    return package_root(path, &path[..root.len() + "/node_modules/".len()]);
+  }
+  Some(root)
+}
+
+fn package_version(package_root: &str) -> Option<String> {
+  let content = std::fs::read(Path::new(package_root).join("package.json")).ok()?;
+  let json: serde_json::Value = serde_json::from_slice(&content).ok()?;
+  let name = json
+    .get("name")
+    .and_then(|v| v.as_str())
+    .unwrap_or_default();
+  let version = json.get("version").and_then(|v| v.as_str())?;
+  Some(format!("{name}@{version}"))
+}
+
+fn file_timestamp(path: &str) -> Option<u64> {
+  std::fs::metadata(path)
+    .and_then(|meta| meta.modified())
+    .ok()
+    .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+    .map(|d| d.as_millis() as u64)
+}
+
+/// Hash the content of a file, or the sorted entries of a directory.
+fn file_hash(path: &str) -> Option<String> {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  let meta = std::fs::metadata(path).ok()?;
+  if meta.is_dir() {
+    let mut entries = std::fs::read_dir(path)
+      .ok()?
+      .filter_map(|entry| entry.ok())
+      .map(|entry| entry.file_name())
+      .collect::<Vec<_>>();
+    entries.sort();
+    entries.hash(&mut hasher);
+  } else {
+    hasher.write(&std::fs::read(path).ok()?);
+  }
+  Some(hasher.digest(&HashDigest::Hex).encoded().to_string())
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::PathBuf;
+
+  use super::{package_root, Snapshot};
+  use crate::{SnapshotOptions, SnapshotStrategy};
+
+  const HASH: SnapshotStrategy = SnapshotStrategy {
+    timestamp: false,
+    hash: true,
+  };
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rspack-snapshot-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("should create temp dir");
+    dir
+  }
+
+  fn write(path: &PathBuf, content: &str) {
+    std::fs::create_dir_all(path.parent().expect("should have parent")).expect("should create dir");
+    std::fs::write(path, content).expect("should write file");
+  }
+
+  #[test]
+  fn snapshot_of_files() {
+    let dir = temp_dir("files");
+    let file = dir.join("a.js");
+    let missing = dir.join("b.js");
+    write(&file, "a");
+
+    let snapshot = Snapshot::new(
+      [file.as_path()],
+      [missing.as_path()],
+      &HASH,
+      &SnapshotOptions::default(),
+    );
+    assert!(snapshot.is_valid());
+
+    write(&file, "changed");
+    assert!(!snapshot.is_valid());
+
+    write(&file, "a");
+    assert!(snapshot.is_valid());
+
+    write(&missing, "b");
+    assert!(!snapshot.is_valid());
+
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn snapshot_of_managed_packages() {
+    let dir = temp_dir("managed");
+    let package_json = dir.join("node_modules/.pnpm/pkg@1.0.0/node_modules/pkg/package.json");
+    let file = dir.join("node_modules/.pnpm/pkg@1.0.0/node_modules/pkg/index.js");
+    write(&package_json, r#"{ "name": "pkg", "version": "1.0.0" }"#);
+    write(&file, "a");
+
+    let snapshot = Snapshot::new(
+      [file.as_path()],
+      std::iter::empty(),
+      &HASH,
+      &SnapshotOptions::default(),
+    );
+    // files of managed packages are validated by the package version
+    write(&file, "changed");
+    assert!(snapshot.is_valid());
+
+    write(&package_json, r#"{ "name": "pkg", "version": "1.0.1" }"#);
+    assert!(!snapshot.is_valid());
+
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn snapshot_of_managed_path_without_package_json() {
+    let dir = temp_dir("unmanaged");
+    let hidden = dir.join("node_modules/.cache/data.js");
+    let file = dir.join("node_modules/pkg/index.js");
+    write(&hidden, "a");
+    write(&file, "a");
+
+    let snapshot = Snapshot::new(
+      [hidden.as_path(), file.as_path()],
+      std::iter::empty(),
+      &HASH,
+      &SnapshotOptions::default(),
+    );
+    assert!(snapshot.is_valid());
+    write(&hidden, "changed");
+    assert!(!snapshot.is_valid());
+
+    write(&hidden, "a");
+    write(&file, "changed");
+    assert!(!snapshot.is_valid());
+
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn package_root_of_managed_path() {
+    let root = "/a/node_modules/";
+    assert_eq!(
+      package_root("/a/node_modules/pkg/index.js", root),
+      Some("/a/node_modules/pkg")
+    );
+    assert_eq!(
+      package_root("/a/node_modules/@scope/pkg/lib/index.js", root),
+      Some("/a/node_modules/@scope/pkg")
+    );
+    assert_eq!(
+      package_root("/a/node_modules/pkg", root),
+      Some("/a/node_modules/pkg")
+    );
+    assert_eq!(
+      package_root(
+        "/a/node_modules/.pnpm/@scope+pkg@1.0.0/node_modules/@scope/pkg/index.js",
+        root
+      ),
+      Some("/a/node_modules/.pnpm/@scope+pkg@1.0.0/node_modules/@scope/pkg")
+    );
+    assert_eq!(
+      package_root("/a/node_modules/pkg/node_modules/dep/index.js", root),
+      Some("/a/node_modules/pkg/node_modules/dep")
+    );
+    assert_eq!(package_root("/a/node_modules/.cache/data.js", root), None);
+    assert_eq!(package_root("/a/node_modules/", root), None);
+    assert_eq!(package_root("/a/node_modules/@scope", root), None);
+  }
+}
@@ -0,0 +1,467 @@
+use itertools::Itertools;
+use rspack_error::Result;
+use serde_json::{json, Map, Value};
+
+use super::{
+  get_stats_module_name_and_id, get_stats_module_profile, Stats, StatsAsset, StatsChunk,
+  StatsChunkGroup, StatsError, StatsModule, StatsModuleProfile, StatsProfileModule,
+  StatsUsedExports, StatsWarning,
+};
+use crate::{ModuleType, PublicPath, StatsOptions};
+
+impl Stats<'_> {
+  /// Same as `stats.toJson()` of webpack, so the result can be read by bundle analysis tools
+  /// such as webpack-bundle-analyzer and statoscope directly.
+  pub fn to_json(&self, options: &StatsOptions) -> Result<Value> {
+    let mut object = Map::new();
+    if options.hash {
+      object.insert("hash".into(), json!(self.get_hash()));
+    }
+    if options.public_path {
+      let public_path = match &self.compilation.options.output.public_path {
+        PublicPath::String(public_path) => public_path.as_str(),
+        PublicPath::Auto => "auto",
+      };
+      object.insert("publicPath".into(), json!(public_path));
+    }
+    if options.output_path {
+      object.insert(
+        "outputPath".into(),
+        json!(self.compilation.options.output.path.to_string_lossy()),
+      );
+    }
+    if options.assets {
+      let (assets, assets_by_chunk_name) = self.get_assets();
+      let assets_by_chunk_name: Map<String, Value> = assets_by_chunk_name
+        .into_iter()
+        .map(|item| (item.name, json!(item.files)))
+        .collect();
+      object.insert("assetsByChunkName".into(), assets_by_chunk_name.into());
+      object.insert(
+        "assets".into(),
+        assets
+          .iter()
+          .map(|asset| asset_to_json(asset, options))
+          .collect(),
+      );
+    }
+    if options.chunks {
+      let chunks = self.get_chunks(
+        options.chunk_modules,
+        options.chunk_relations,
+        options.reasons,
+        options.module_assets,
+        options.nested_modules,
+        options.source,
+        options.used_exports,
+        options.provided_exports,
+        |chunks| {
+          chunks
+            .iter()
+            .map(|chunk| chunk_to_json(chunk, options))
+            .collect::<Value>()
+        },
+      )?;
+      object.insert("chunks".into(), chunks);
+    }
+    if options.modules {
+      let modules = self.get_modules(
+        options.reasons,
+        options.module_assets,
+        options.nested_modules,
+        options.source,
+        options.used_exports,
+        options.provided_exports,
+        |modules| modules_to_json(&modules, options),
+      )?;
+      object.insert("modules".into(), modules);
+    }
+    if options.entrypoints {
+      object.insert(
+        "entrypoints".into(),
+        chunk_groups_to_json(self.get_entrypoints()),
+      );
+    }
+    if options.chunk_groups {
+      object.insert(
+        "namedChunkGroups".into(),
+        chunk_groups_to_json(self.get_named_chunk_groups()),
+      );
+    }
+    if options.errors || options.errors_count {
+      let errors = self.get_errors();
+      if options.errors_count {
+        object.insert("errorsCount".into(), json!(errors.len()));
+      }
+      if options.errors {
+        object.insert(
+          "errors".into(),
+          errors
+            .into_iter()
+            .map(|error| error_to_json(error, options))
+            .collect(),
+        );
+      }
+    }
+    if options.warnings || options.warnings_count {
+      let warnings = self.get_warnings();
+      if options.warnings_count {
+        object.insert("warningsCount".into(), json!(warnings.len()));
+      }
+      if options.warnings {
+        object.insert(
+          "warnings".into(),
+          warnings
+            .into_iter()
+            .map(|warning| warning_to_json(warning, options))
+            .collect(),
+        );
+      }
+    }
+    Ok(object.into())
+  }
+
+  /// The JSON report explaining why the module is included, or how one of its exports is used
+  /// when `export_name` is given.
+  pub fn get_module_explanation(&self, module: &str, export_name: Option<&str>) -> Option<Value> {
+    match export_name {
+      Some(export_name) => self
+        .compilation
+        .explain_export(module, export_name)
+        .map(|explanation| explanation.to_json()),
+      None => self
+        .compilation
+        .explain_module(module)
+        .map(|explanation| explanation.to_json()),
+    }
+  }
+
+  /// A standalone JSON report with the profile of every module and the summary, the times are
+  /// in milliseconds.
+  pub fn get_profile_report(&self, limit: usize) -> Value {
+    let module_graph = self.compilation.get_module_graph();
+    let mut modules = module_graph
+      .modules()
+      .values()
+      .filter_map(|module| {
+        let profile = module_graph
+          .get_profile(&module.identifier())
+          .and_then(get_stats_module_profile)?;
+        let (name, _) = get_stats_module_name_and_id(module, self.compilation);
+        Some((module.identifier(), name, profile))
+      })
+      .collect_vec();
+    modules.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    let modules = modules
+      .into_iter()
+      .map(|(identifier, name, profile)| {
+        let mut object = profile_to_json(&profile);
+        object["identifier"] = json!(identifier.as_str());
+        object["name"] = json!(name);
+        object
+      })
+      .collect_vec();
+
+    let summary = self.get_profile_summary(limit);
+    let to_json = |items: &[StatsProfileModule]| {
+      items
+        .iter()
+        .map(|item| {
+          json!({
+            "identifier": item.identifier.as_str(),
+            "name": item.name,
+            "time": item.time.as_millis(),
+          })
+        })
+        .collect_vec()
+    };
+    json!({
+      "modules": modules,
+      "summary": {
+        "slowestModules": to_json(&summary.slowest_modules),
+        "slowestLoaders": summary.slowest_loaders.iter().map(|loader| json!({
+          "loader": loader.loader,
+          "time": loader.time.as_millis(),
+          "count": loader.count,
+        })).collect_vec(),
+        "slowestResolves": to_json(&summary.slowest_resolves),
+      },
+    })
+  }
+}
+
+fn asset_to_json(asset: &StatsAsset, options: &StatsOptions) -> Value {
+  let mut object = json!({
+    "type": asset.r#type,
+    "name": asset.name,
+    "size": asset.size,
+    "emitted": asset.emitted,
+    "chunkNames": asset.chunk_names,
+    "info": {
+      "development": asset.info.development,
+      "hotModuleReplacement": asset.info.hot_module_replacement,
+      "sourceFilename": asset.info.source_filename,
+    },
+  });
+  if options.ids {
+    object["chunks"] = json!(asset.chunks);
+  }
+  object
+}
+
+fn modules_to_json(modules: &[StatsModule], options: &StatsOptions) -> Value {
+  modules
+    .iter()
+    .filter(|module| options.orphan_modules || !module.orphan)
+    .filter(|module| options.runtime_modules || module.module_type != ModuleType::Runtime)
+    .map(|module| module_to_json(module, options))
+    .collect()
+}
+
+fn module_to_json(module: &StatsModule, options: &StatsOptions) -> Value {
+  let mut object = json!({
+    "type": module.r#type,
+    "moduleType": module.module_type.to_string(),
+    "identifier": module.identifier.as_str(),
+    "name": module.name,
+    "nameForCondition": module.name_for_condition,
+    "size": module.size,
+    "orphan": module.orphan,
+    "issuer": module.issuer,
+    "issuerName": module.issuer_name,
+    "issuerPath": module.issuer_path.iter().map(|issuer| {
+      let mut object = json!({
+        "identifier": issuer.identifier,
+        "name": issuer.name,
+      });
+      if options.ids {
+        object["id"] = json!(issuer.id);
+      }
+      object
+    }).collect_vec(),
+  });
+  if options.ids {
+    object["id"] = json!(module.id);
+    object["issuerId"] = json!(module.issuer_id);
+    object["chunks"] = json!(module.chunks);
+  }
+  if let Some(assets) = &module.assets {
+    object["assets"] = json!(assets);
+  }
+  if let Some(reasons) = &module.reasons {
+    object["reasons"] = reasons
+      .iter()
+      .map(|reason| {
+        let mut object = json!({
+          "moduleIdentifier": reason.module_identifier,
+          "module": reason.module_name,
+          "moduleName": reason.module_name,
+          "type": reason.r#type,
+          "userRequest": reason.user_request,
+        });
+        if options.ids {
+          object["moduleId"] = json!(reason.module_id);
+        }
+        object
+      })
+      .collect();
+  }
+  if options.used_exports {
+    object["usedExports"] = match &module.used_exports {
+      Some(StatsUsedExports::Vec(exports)) => json!(exports),
+      Some(StatsUsedExports::Bool(used)) => json!(used),
+      Some(StatsUsedExports::Null) | None => Value::Null,
+    };
+  }
+  if options.provided_exports {
+    object["providedExports"] = json!(module.provided_exports);
+  }
+  if options.optimization_bailout {
+    object["optimizationBailout"] = json!(module.optimization_bailout);
+  }
+  if let Some(modules) = &module.modules {
+    object["modules"] = modules_to_json(modules, options);
+  }
+  if let Some(source) = module.source {
+    object["source"] = json!(source.source());
+  }
+  if let Some(profile) = &module.profile {
+    object["profile"] = profile_to_json(profile);
+  }
+  object
+}
+
+fn profile_to_json(profile: &StatsModuleProfile) -> Value {
+  json!({
+    "total": profile.factory.as_millis()
+      + profile.integration.as_millis()
+      + profile.building.as_millis()
+      + profile.code_generation.as_millis(),
+    "factory": profile.factory.as_millis(),
+    "resolving": profile.resolving.as_millis(),
+    "integration": profile.integration.as_millis(),
+    "waiting": profile.waiting.as_millis(),
+    "building": profile.building.as_millis(),
+    "loaders": profile.loaders.iter().map(|loader| json!({
+      "loader": loader.loader,
+      "time": loader.time.as_millis(),
+    })).collect_vec(),
+    "parsing": profile.parsing.as_millis(),
+    "codeGeneration": profile.code_generation.as_millis(),
+  })
+}
+
+fn chunk_to_json(chunk: &StatsChunk, options: &StatsOptions) -> Value {
+  let mut object = json!({
+    "type": chunk.r#type,
+    "rendered": true,
+    "initial": chunk.initial,
+    "entry": chunk.entry,
+    "size": chunk.size,
+    "names": chunk.names,
+    "files": chunk.files,
+    "auxiliaryFiles": chunk.auxiliary_files,
+    "childrenByOrder": chunk
+      .children_by_order
+      .iter()
+      .map(|(order, children)| (order.to_string(), json!(children)))
+      .collect::<Map<_, _>>(),
+  });
+  if options.ids {
+    object["id"] = json!(chunk.id);
+  }
+  if let Some(parents) = &chunk.parents {
+    object["parents"] = json!(parents);
+  }
+  if let Some(children) = &chunk.children {
+    object["children"] = json!(children);
+  }
+  if let Some(siblings) = &chunk.siblings {
+    object["siblings"] = json!(siblings);
+  }
+  if let Some(modules) = &chunk.modules {
+    object["modules"] = modules_to_json(modules, options);
+  }
+  object
+}
+
+fn chunk_groups_to_json(chunk_groups: Vec<StatsChunkGroup>) -> Value {
+  chunk_groups
+    .into_iter()
+    .map(|chunk_group| {
+      let object = json!({
+        "name": chunk_group.name,
+        "chunks": chunk_group.chunks,
+        "assets": chunk_group.assets.iter().map(|asset| json!({
+          "name": asset.name,
+          "size": asset.size,
+        })).collect_vec(),
+        "assetsSize": chunk_group.assets_size,
+      });
+      (chunk_group.name, object)
+    })
+    .collect::<Map<_, _>>()
+    .into()
+}
+
+fn error_to_json(error: StatsError, options: &StatsOptions) -> Value {
+  let mut object = json!({
+    "message": error.message,
+    "moduleIdentifier": error.module_identifier,
+    "moduleName": error.module_name,
+  });
+  if options.ids {
+    object["moduleId"] = json!(error.module_id);
+  }
+  object
+}
+
+fn warning_to_json(warning: StatsWarning, options: &StatsOptions) -> Value {
+  let mut object = json!({
+    "message": warning.message,
+    "moduleIdentifier": warning.module_identifier,
+    "moduleName": warning.module_name,
+  });
+  if options.ids {
+    object["moduleId"] = json!(warning.module_id);
+  }
+  object
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{StatsAssetInfo, StatsChunkGroupAsset, StatsPreset};
+
+  fn asset() -> StatsAsset {
+    StatsAsset {
+      r#type: "asset",
+      name: "main.js".to_string(),
+      size: 42.0,
+      chunks: vec![Some("main".to_string())],
+      chunk_names: vec!["main".to_string()],
+      info: StatsAssetInfo {
+        development: false,
+        hot_module_replacement: false,
+        source_filename: None,
+      },
+      emitted: true,
+    }
+  }
+
+  #[test]
+  fn asset_json_follows_ids_toggle() {
+    let normal = asset_to_json(&asset(), &StatsOptions::default());
+    assert_eq!(normal["name"], "main.js");
+    assert_eq!(normal["chunkNames"], json!(["main"]));
+    assert_eq!(normal["info"]["hotModuleReplacement"], false);
+    assert_eq!(normal["chunks"], json!(["main"]));
+
+    let options = StatsOptions {
+      ids: false,
+      ..Default::default()
+    };
+    assert!(asset_to_json(&asset(), &options).get("chunks").is_none());
+  }
+
+  #[test]
+  fn chunk_groups_json_is_keyed_by_name() {
+    let json = chunk_groups_to_json(vec![StatsChunkGroup {
+      name: "main".to_string(),
+      assets: vec![StatsChunkGroupAsset {
+        name: "main.js".to_string(),
+        size: 42.0,
+      }],
+      chunks: vec![Some("main".to_string())],
+      assets_size: 42.0,
+    }]);
+    assert_eq!(
+      json,
+      json!({
+        "main": {
+          "name": "main",
+          "chunks": ["main"],
+          "assets": [{ "name": "main.js", "size": 42.0 }],
+          "assetsSize": 42.0,
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn error_json_follows_ids_toggle() {
+    let error = || StatsError {
+      message: "Module not found".to_string(),
+      module_identifier: Some("/src/index.js".to_string()),
+      module_name: Some("./src/index.js".to_string()),
+      module_id: Some("1".to_string()),
+    };
+    let verbose = error_to_json(error(), &StatsOptions::from_preset(StatsPreset::Verbose));
+    assert_eq!(verbose["moduleName"], "./src/index.js");
+    assert_eq!(verbose["moduleId"], "1");
+
+    let errors_only = error_to_json(error(), &StatsOptions::from_preset(StatsPreset::ErrorsOnly));
+    assert_eq!(errors_only["message"], "Module not found");
+    assert!(errors_only.get("moduleId").is_none());
+  }
+}
@@ -1,3 +1,7 @@
+mod json;
+
+use std::time::Duration;
+
 use either::Either;
 use itertools::Itertools;
 use rspack_error::emitter::{DiagnosticDisplay, DiagnosticDisplayer};
@@ -9,7 +13,7 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use crate::{
   get_chunk_from_ukey, get_chunk_group_from_ukey, ChunkGroupOrderKey, ProvidedExports, UsedExports,
 };
-use crate::{BoxModule, BoxRuntimeModule, Chunk};
+use crate::{BoxModule, BoxRuntimeModule, Chunk, ModuleProfile};
 use crate::{ChunkGroupUkey, Compilation, LogType, ModuleIdentifier, ModuleType, SourceType};
 
 #[derive(Debug, Clone)]
@@ -168,6 +172,66 @@ impl Stats<'_> {
     Ok(f(modules))
   }
 
+  /// Aggregates the module profiles into the slowest modules, loaders and resolves, each list
+  /// is limited to `limit` items.
+  pub fn get_profile_summary(&self, limit: usize) -> StatsProfileSummary {
+    let module_graph = self.compilation.get_module_graph();
+    let mut modules = vec![];
+    let mut resolves = vec![];
+    let mut loaders: HashMap<&str, (Duration, u32)> = HashMap::default();
+    for (identifier, mgm) in module_graph.module_graph_modules() {
+      let Some(profile) = mgm.get_profile() else {
+        continue;
+      };
+      let name = module_graph
+        .module_by_identifier(&identifier)
+        .map(|module| get_stats_module_name_and_id(module, self.compilation).0)
+        .unwrap_or_else(|| identifier.to_string());
+      if let Some(resolving) = profile.resolving.get() {
+        resolves.push((identifier, name.clone(), *resolving));
+      }
+      for timing in profile.loaders.get().into_iter().flatten() {
+        // loader options are part of the identifier, group them by the loader itself
+        let loader = timing
+          .loader
+          .split_once('?')
+          .map_or(timing.loader.as_str(), |(loader, _)| loader);
+        let entry = loaders.entry(loader).or_default();
+        entry.0 += timing.duration;
+        entry.1 += 1;
+      }
+      modules.push((identifier, name, profile.total()));
+    }
+
+    let slowest = |mut items: Vec<(ModuleIdentifier, String, Duration)>| {
+      items.sort_unstable_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+      items
+        .into_iter()
+        .take(limit)
+        .map(|(identifier, name, time)| StatsProfileModule {
+          identifier,
+          name,
+          time: time.into(),
+        })
+        .collect()
+    };
+    let mut loaders = loaders.into_iter().collect_vec();
+    loaders.sort_unstable_by(|a, b| b.1 .0.cmp(&a.1 .0).then_with(|| a.0.cmp(b.0)));
+    StatsProfileSummary {
+      slowest_modules: slowest(modules),
+      slowest_loaders: loaders
+        .into_iter()
+        .take(limit)
+        .map(|(loader, (time, count))| StatsProfileLoader {
+          loader: loader.to_string(),
+          time: time.into(),
+          count,
+        })
+        .collect(),
+      slowest_resolves: slowest(resolves),
+    }
+  }
+
   #[allow(clippy::too_many_arguments)]
   pub fn get_chunks<T>(
     &self,
@@ -501,19 +565,7 @@ impl Stats<'_> {
 
     // TODO: a placeholder for concatenation modules
     let modules = nested_modules.then(Vec::new);
-    let profile = if let Some(p) = mgm.get_profile()
-      && let Some(factory) = p.factory.duration()
-      && let Some(integration) = p.integration.duration()
-      && let Some(building) = p.building.duration()
-    {
-      Some(StatsModuleProfile {
-        factory: StatsMillisecond::new(factory.as_secs(), factory.subsec_millis()),
-        integration: StatsMillisecond::new(integration.as_secs(), integration.subsec_millis()),
-        building: StatsMillisecond::new(building.as_secs(), building.subsec_millis()),
-      })
-    } else {
-      None
-    };
+    let profile = mgm.get_profile().and_then(get_stats_module_profile);
 
     let provided_exports =
       if provided_exports && self.compilation.options.optimization.provided_exports {
@@ -684,6 +736,38 @@ impl Stats<'_> {
   }
 }
 
+fn get_stats_module_profile(p: &ModuleProfile) -> Option<StatsModuleProfile> {
+  if let Some(factory) = p.factory.duration()
+    && let Some(integration) = p.integration.duration()
+    && let Some(building) = p.building.duration()
+  {
+    Some(StatsModuleProfile {
+      factory: StatsMillisecond::new(factory.as_secs(), factory.subsec_millis()),
+      integration: StatsMillisecond::new(integration.as_secs(), integration.subsec_millis()),
+      building: StatsMillisecond::new(building.as_secs(), building.subsec_millis()),
+      resolving: p.resolving.get().copied().unwrap_or_default().into(),
+      parsing: p.parsing.duration().unwrap_or_default().into(),
+      code_generation: p.code_generation.duration().unwrap_or_default().into(),
+      waiting: p.waiting().unwrap_or_default().into(),
+      loaders: p
+        .loaders
+        .get()
+        .map(|loaders| {
+          loaders
+            .iter()
+            .map(|timing| StatsLoaderProfile {
+              loader: timing.loader.to_string(),
+              time: timing.duration.into(),
+            })
+            .collect()
+        })
+        .unwrap_or_default(),
+    })
+  } else {
+    None
+  }
+}
+
 fn get_stats_module_name_and_id(
   module: &BoxModule,
   compilation: &Compilation,
@@ -771,6 +855,38 @@ pub struct StatsModuleProfile {
   pub factory: StatsMillisecond,
   pub integration: StatsMillisecond,
   pub building: StatsMillisecond,
+  pub resolving: StatsMillisecond,
+  pub parsing: StatsMillisecond,
+  pub code_generation: StatsMillisecond,
+  pub waiting: StatsMillisecond,
+  pub loaders: Vec<StatsLoaderProfile>,
+}
+
+#[derive(Debug)]
+pub struct StatsLoaderProfile {
+  pub loader: String,
+  pub time: StatsMillisecond,
+}
+
+#[derive(Debug)]
+pub struct StatsProfileSummary {
+  pub slowest_modules: Vec<StatsProfileModule>,
+  pub slowest_loaders: Vec<StatsProfileLoader>,
+  pub slowest_resolves: Vec<StatsProfileModule>,
+}
+
+#[derive(Debug)]
+pub struct StatsProfileModule {
+  pub identifier: ModuleIdentifier,
+  pub name: String,
+  pub time: StatsMillisecond,
+}
+
+#[derive(Debug)]
+pub struct StatsProfileLoader {
+  pub loader: String,
+  pub time: StatsMillisecond,
+  pub count: u32,
 }
 
 #[derive(Debug)]
@@ -833,4 +949,14 @@ impl StatsMillisecond {
       subsec_millis,
     }
   }
+
+  pub fn as_millis(&self) -> u64 {
+    self.secs * 1000 + self.subsec_millis as u64
+  }
+}
+
+impl From<Duration> for StatsMillisecond {
+  fn from(value: Duration) -> Self {
+    Self::new(value.as_secs(), value.subsec_millis())
+  }
 }
@@ -32,6 +32,7 @@ cfg_async! {
   use futures::future::BoxFuture;
 
   use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+  #[derive(Debug)]
   pub struct AsyncNativeFileSystem;
 
   impl AsyncWritableFileSystem for AsyncNativeFileSystem {
@@ -3,23 +3,36 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
+use rspack_error::error;
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  Xxhash128,
   MD4,
+  MD5,
+  Sha256,
+  Sha512,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash128" => Ok(HashFunction::Xxhash128),
+      "md4" => Ok(HashFunction::MD4),
+      "md5" => Ok(HashFunction::MD5),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha512" => Ok(HashFunction::Sha512),
+      _ => Err(error!(
+        "Unsupported hash function \"{value}\", expected one of \"xxhash64\", \"xxhash128\", \"md4\", \"md5\", \"sha256\" or \"sha512\""
+      )),
     }
   }
 }
@@ -27,13 +40,23 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
+  Base64Url,
+  Base62,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base62" => Ok(HashDigest::Base62),
+      _ => Err(error!(
+        "Unsupported hash digest \"{value}\", expected one of \"hex\", \"base64\", \"base64url\" or \"base62\""
+      )),
     }
   }
 }
@@ -56,14 +79,22 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<xxh3::Xxh3>),
+  Xxhash128(Box<xxh3::Xxh3>),
   MD4(md4::Md4),
+  MD5(md5::Md5),
+  Sha256(sha2::Sha256),
+  Sha512(sha2::Sha512),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash128(_) => write!(f, "RspackHash(Xxhash128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::MD5(_) => write!(f, "RspackHash(MD5)"),
+      Self::Sha256(_) => write!(f, "RspackHash(Sha256)"),
+      Self::Sha512(_) => write!(f, "RspackHash(Sha512)"),
     }
   }
 }
@@ -72,7 +103,11 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(xxh3::Xxh3::new())),
+      HashFunction::Xxhash128 => Self::Xxhash128(Box::new(xxh3::Xxh3::new())),
       HashFunction::MD4 => Self::MD4(md4::Md4::new()),
+      HashFunction::MD5 => Self::MD5(md5::Md5::new()),
+      HashFunction::Sha256 => Self::Sha256(sha2::Sha256::new()),
+      HashFunction::Sha512 => Self::Sha512(sha2::Sha512::new()),
     }
   }
 
@@ -87,7 +122,11 @@ impl RspackHash {
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_le_bytes().to_vec(),
+      RspackHash::Xxhash128(hasher) => hasher.digest128().to_le_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::MD5(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha256(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha512(hash) => hash.finalize().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -97,28 +136,54 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      RspackHash::Xxhash128(hasher) => hasher.digest(),
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::MD5(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha512(hasher) => msb_u64(&hasher.clone().finalize()),
     }
   }
 
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash128(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::MD5(hasher) => hasher.update(bytes),
+      RspackHash::Sha256(hasher) => hasher.update(bytes),
+      RspackHash::Sha512(hasher) => hasher.update(bytes),
+    }
+  }
+}
+
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(bytes)
+}
+
+const BASE62_ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Encode the bytes as a big-endian number in base62, same as the `base62` digest of
+/// `loader-utils`.
+fn encode_base62(bytes: &[u8]) -> String {
+  let mut number = bytes.to_vec();
+  let mut output = vec![];
+  while number.iter().any(|b| *b != 0) {
+    let mut remainder = 0u32;
+    for byte in number.iter_mut() {
+      let value = (remainder << 8) | *byte as u32;
+      *byte = (value / 62) as u8;
+      remainder = value % 62;
     }
+    output.push(BASE62_ALPHABET[remainder as usize]);
+  }
+  if output.is_empty() {
+    output.push(BASE62_ALPHABET[0]);
   }
+  output.reverse();
+  String::from_utf8(output).expect("base62 alphabet should be ascii")
 }
 
 #[derive(Debug, Clone, Eq)]
@@ -130,6 +195,9 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::Base62 => encode_base62(&inner).into(),
     };
     Self { encoded }
   }
@@ -144,6 +212,14 @@ impl RspackHashDigest {
   }
 }
 
+impl From<&str> for RspackHashDigest {
+  fn from(value: &str) -> Self {
+    Self {
+      encoded: value.into(),
+    }
+  }
+}
+
 impl Hash for RspackHashDigest {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.encoded.hash(state);
@@ -155,3 +231,33 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn digest_encodings() {
+    let bytes = vec![0xfb, 0xff, 0x00, 0x01];
+    let encode = |digest| {
+      RspackHashDigest::new(bytes.clone(), &digest)
+        .encoded()
+        .to_string()
+    };
+    assert_eq!(encode(HashDigest::Hex), "fbff0001");
+    assert_eq!(encode(HashDigest::Base64), "+/8AAQ==");
+    assert_eq!(encode(HashDigest::Base64Url), "-_8AAQ");
+    // 0xfbff0001 = 4227792897
+    assert_eq!(encode(HashDigest::Base62), "4C7o41");
+  }
+
+  #[test]
+  fn invalid_names() {
+    assert!(HashFunction::try_from("sha1").is_err());
+    assert!(HashDigest::try_from("base36").is_err());
+    assert!(matches!(
+      HashFunction::try_from("sha256"),
+      Ok(HashFunction::Sha256)
+    ));
+  }
+}
@@ -0,0 +1,182 @@
+use rspack_core::{
+  ApplyContext, ChunkKind, Compilation, CompilationChunkIds, CompilationModuleIds, CompilerOptions,
+  Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{get_full_chunk_name, get_full_module_name, get_used_chunk_ids};
+
+/// Revive module and chunk ids from the records of a previous compilation before the other
+/// id plugins run, and record the final ids once they are assigned.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct RecordIdsPlugin;
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = -100)]
+fn revive_module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(records) = compilation.input_records.clone() else {
+    return Ok(());
+  };
+  let by_identifier = &records.modules.by_identifier;
+  if by_identifier.is_empty() {
+    return Ok(());
+  }
+
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = compilation.used_module_ids.clone();
+  let mut revived = vec![];
+  for module in module_graph.modules().values() {
+    let identifier = module.identifier();
+    if compilation.chunk_graph.get_module_id(identifier).is_some()
+      || compilation
+        .chunk_graph
+        .get_number_of_module_chunks(identifier)
+        == 0
+    {
+      continue;
+    }
+    if let Some(id) = by_identifier.get(&get_full_module_name(module, context)) {
+      if used_ids.insert(id.clone()) {
+        revived.push((identifier, id.clone()));
+      }
+    }
+  }
+  for (identifier, id) in revived {
+    compilation.chunk_graph.set_module_id(identifier, id);
+  }
+
+  // ids of modules that are gone should not be reused by new modules
+  compilation
+    .used_module_ids
+    .extend(by_identifier.values().cloned());
+  Ok(())
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = 100)]
+fn record_module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let by_identifier = module_graph
+    .modules()
+    .values()
+    .filter_map(|module| {
+      let id = compilation.chunk_graph.get_module_id(module.identifier());
+      id.clone()
+        .map(|id| (get_full_module_name(module, context), id))
+    })
+    .collect();
+  compilation.output_records.modules.by_identifier = by_identifier;
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = -100)]
+fn revive_chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(records) = compilation.input_records.clone() else {
+    return Ok(());
+  };
+  let chunk_records = &records.chunks;
+  if chunk_records.by_name.is_empty() && chunk_records.by_source.is_empty() {
+    return Ok(());
+  }
+
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = get_used_chunk_ids(compilation);
+  let mut revived = vec![];
+  for chunk in compilation.chunk_by_ukey.values() {
+    if chunk.id.is_some() || chunk.kind == ChunkKind::HotUpdate {
+      continue;
+    }
+    let id = match &chunk.name {
+      Some(name) => chunk_records.by_name.get(name),
+      None => chunk_records.by_source.get(&get_full_chunk_name(
+        chunk,
+        &compilation.chunk_graph,
+        &module_graph,
+        context,
+      )),
+    };
+    if let Some(id) = id {
+      if used_ids.insert(id.clone()) {
+        revived.push((chunk.ukey, id.clone()));
+      }
+    }
+  }
+  for (ukey, id) in revived {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&ukey);
+    chunk.id = Some(id.clone());
+    chunk.ids = vec![id];
+  }
+
+  // ids of chunks that are gone should not be reused by new chunks
+  compilation.used_chunk_ids.extend(
+    chunk_records
+      .by_name
+      .values()
+      .chain(chunk_records.by_source.values())
+      .cloned(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = 100)]
+fn record_chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut by_name = std::collections::BTreeMap::new();
+  let mut by_source = std::collections::BTreeMap::new();
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = &chunk.id else {
+      continue;
+    };
+    if chunk.kind == ChunkKind::HotUpdate {
+      continue;
+    }
+    match &chunk.name {
+      Some(name) => by_name.insert(name.clone(), id.clone()),
+      None => by_source.insert(
+        get_full_chunk_name(chunk, &compilation.chunk_graph, &module_graph, context),
+        id.clone(),
+      ),
+    };
+  }
+  compilation.output_records.chunks.by_name = by_name;
+  compilation.output_records.chunks.by_source = by_source;
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.RecordIdsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(revive_module_ids::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(record_module_ids::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(revive_chunk_ids::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(record_chunk_ids::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,195 @@
+mod options;
+
+use std::{borrow::Cow, fmt::Display, path::Path};
+
+use lightningcss::{
+  error::{Error as CssError, ErrorLocation},
+  stylesheet::{MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, StyleSheet},
+};
+pub use options::{DraftOptions, LightningcssLoaderOptions};
+use rspack_core::{rspack_sources::SourceMap, LoaderRunnerContext};
+use rspack_error::{error, Diagnostic, DiagnosticKind, Result, TraceableError};
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use sugar_path::SugarPath;
+
+pub const LIGHTNINGCSS_LOADER_IDENTIFIER: &str = "builtin:lightningcss-loader";
+
+#[derive(Debug)]
+pub struct LightningcssLoader {
+  identifier: Identifier,
+  options: LightningcssLoaderOptions,
+}
+
+impl LightningcssLoader {
+  pub fn new(options: LightningcssLoaderOptions) -> Self {
+    Self {
+      identifier: LIGHTNINGCSS_LOADER_IDENTIFIER.into(),
+      options,
+    }
+  }
+
+  /// Panics:
+  /// Panics if `identifier` passed in is not starting with `builtin:lightningcss-loader`.
+  pub fn with_identifier(mut self, identifier: Identifier) -> Self {
+    assert!(identifier.starts_with(LIGHTNINGCSS_LOADER_IDENTIFIER));
+    self.identifier = identifier;
+    self
+  }
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for LightningcssLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    let filename = loader_context.resource_path().to_string_lossy().to_string();
+    let content = std::mem::take(&mut loader_context.content).expect("content should be available");
+    let source = content.try_into_string()?;
+    let targets = self.options.targets()?;
+
+    let error_recovery = self.options.error_recovery.unwrap_or(true);
+    let warnings = error_recovery.then(Default::default);
+    let mut flags = ParserFlags::NESTING;
+    if self.options.draft.custom_media {
+      flags |= ParserFlags::CUSTOM_MEDIA;
+    }
+    // CSS Modules are not enabled here, `:local`, `:global` and `composes` are printed as is,
+    // and the class names are still generated by rspack with `module.generator["css/module"]`.
+    let parser_options = ParserOptions {
+      filename: filename.clone(),
+      css_modules: None,
+      source_index: 0,
+      error_recovery,
+      warnings: warnings.clone(),
+      flags,
+    };
+    let mut stylesheet = StyleSheet::parse(&source, parser_options)
+      .map_err(|e| to_error(&source, "Lightningcss parse error", e))?;
+    // Prefixing and lowering of modern syntax happen in `minify` as well.
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        unused_symbols: Default::default(),
+      })
+      .map_err(|e| to_error(&source, "Lightningcss transform error", e))?;
+
+    let compiler_options = loader_context.context.options.clone();
+    let project_root = compiler_options.context.as_str();
+    let mut source_map = if loader_context.context.module_source_map_kind.enabled() {
+      let mut source_map = parcel_sourcemap::SourceMap::new(project_root);
+      source_map.add_source(&filename);
+      source_map
+        .set_source_content(0, &source)
+        .map_err(|e| error!(e.to_string()))?;
+      Some(source_map)
+    } else {
+      None
+    };
+    let result = stylesheet
+      .to_css(PrinterOptions {
+        minify: self.options.minify,
+        source_map: source_map.as_mut(),
+        targets,
+        ..Default::default()
+      })
+      .map_err(|e| to_error(&source, "Lightningcss print error", e))?;
+
+    if let Some(warnings) = warnings {
+      for warning in warnings.read().expect("should lock warnings").iter() {
+        loader_context.emit_diagnostic(Diagnostic::warn(
+          LIGHTNINGCSS_LOADER_IDENTIFIER.to_string(),
+          warning.to_string(),
+        ));
+      }
+    }
+
+    loader_context.source_map = match source_map {
+      Some(mut source_map) => {
+        if let Some(input) = loader_context.source_map.take() {
+          let input = input.to_json().map_err(|e| error!(e.to_string()))?;
+          let mut input = parcel_sourcemap::SourceMap::from_json(project_root, &input)
+            .map_err(|e| error!(e.to_string()))?;
+          source_map
+            .extends(&mut input)
+            .map_err(|e| error!(e.to_string()))?;
+        }
+        let json = source_map
+          .to_json(None)
+          .map_err(|e| error!(e.to_string()))?;
+        let mut source_map = SourceMap::from_json(&json).map_err(|e| error!(e.to_string()))?;
+        // parcel_sourcemap makes the sources relative to the project root.
+        for source in source_map.sources_mut() {
+          if !source.contains(':') {
+            let path = Path::new(project_root).join(&**source).normalize();
+            *source = Cow::Owned(path.to_string_lossy().to_string());
+          }
+        }
+        Some(source_map)
+      }
+      None => None,
+    };
+    loader_context.content = Some(result.code.into());
+    Ok(())
+  }
+}
+
+fn to_error<T: Display>(source: &str, title: &str, e: CssError<T>) -> rspack_error::Error {
+  match &e.loc {
+    Some(loc) => {
+      let start = offset_of(source, loc);
+      TraceableError::from_file(
+        source.to_string(),
+        start,
+        start,
+        title.to_string(),
+        e.kind.to_string(),
+      )
+      .with_kind(DiagnosticKind::Css)
+      .into()
+    }
+    None => error!("{title}: {}", e.kind),
+  }
+}
+
+/// Converts the 0-based line and the 1-based UTF-16 column of cssparser to a byte offset.
+fn offset_of(source: &str, loc: &ErrorLocation) -> usize {
+  let line_start: usize = source
+    .split_inclusive('\n')
+    .take(loc.line as usize)
+    .map(str::len)
+    .sum();
+  let mut column = loc.column.saturating_sub(1) as usize;
+  let mut offset = line_start;
+  for ch in source[line_start..].chars() {
+    if column == 0 || ch == '\n' {
+      break;
+    }
+    column = column.saturating_sub(ch.len_utf16());
+    offset += ch.len_utf8();
+  }
+  offset
+}
+
+impl Identifiable for LightningcssLoader {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use lightningcss::error::ErrorLocation;
+
+  use super::offset_of;
+
+  #[test]
+  fn error_location_to_offset() {
+    let source = ".a { color: red }\n.bé { color: }\n";
+    let loc = |line, column| ErrorLocation {
+      filename: String::new(),
+      line,
+      column,
+    };
+    assert_eq!(offset_of(source, &loc(0, 1)), 0);
+    assert_eq!(&source[offset_of(source, &loc(1, 7))..][..5], "color");
+    assert_eq!(offset_of(source, &loc(2, 1)), source.len());
+  }
+}
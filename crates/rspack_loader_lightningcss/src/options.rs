@@ -0,0 +1,41 @@
+use lightningcss::targets::{Browsers, Features, Targets};
+use rspack_error::{error, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LightningcssLoaderOptions {
+  /// Minifies the output, this is usually left to the minimizer.
+  pub minify: bool,
+  /// Ignores invalid rules and declarations and reports them as warnings, defaults to `true`.
+  pub error_recovery: Option<bool>,
+  /// Browserslist queries, the JS side fills them from the browserslist config when not set.
+  pub targets: Option<Vec<String>>,
+  /// Bits of `lightningcss::targets::Features` that are always compiled.
+  pub include: Option<u32>,
+  /// Bits of `lightningcss::targets::Features` that are never compiled.
+  pub exclude: Option<u32>,
+  pub draft: DraftOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DraftOptions {
+  /// Parses `@custom-media` rules, which are lowered away in the output.
+  pub custom_media: bool,
+}
+
+impl LightningcssLoaderOptions {
+  pub fn targets(&self) -> Result<Targets> {
+    let browsers = match &self.targets {
+      Some(queries) => Browsers::from_browserslist(queries)
+        .map_err(|e| error!("Failed to resolve browserslist targets {queries:?}: {e}"))?,
+      None => None,
+    };
+    Ok(Targets {
+      browsers,
+      include: Features::from_bits_truncate(self.include.unwrap_or_default()),
+      exclude: Features::from_bits_truncate(self.exclude.unwrap_or_default()),
+    })
+  }
+}
@@ -11,7 +11,10 @@ pub use content::Content;
 pub use loader::{DisplayWithSuffix, Loader};
 pub use plugin::LoaderRunnerPlugin;
 pub use rspack_identifier::{Identifiable, Identifier};
-pub use runner::{run_loaders, AdditionalData, DescriptionData, LoaderContext, ResourceData};
+pub use runner::{
+  run_loaders, AdditionalData, DescriptionData, LoaderContext, LoaderResult, LoaderTiming,
+  ResourceData,
+};
 pub use scheme::{get_scheme, Scheme};
 
 pub const BUILTIN_LOADER_PREFIX: &str = "builtin:";
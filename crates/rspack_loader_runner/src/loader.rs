@@ -2,9 +2,10 @@ use std::{
   fmt::{Debug, Display},
   ops::Deref,
   sync::{
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicU64, Ordering},
     Arc,
   },
+  time::Duration,
 };
 
 use async_trait::async_trait;
@@ -51,6 +52,8 @@ pub struct LoaderItem<C> {
   data: LoaderItemData,
   pitch_executed: AtomicBool,
   normal_executed: AtomicBool,
+  /// Time spent in the pitching and normal stage of the loader, in nanoseconds
+  elapsed: AtomicU64,
 }
 
 impl<C> Debug for LoaderItem<C> {
@@ -96,6 +99,16 @@ impl<C> LoaderItem<C> {
     self.normal_executed.store(true, Ordering::Relaxed)
   }
 
+  pub(crate) fn add_elapsed(&self, elapsed: Duration) {
+    self
+      .elapsed
+      .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+  }
+
+  pub(crate) fn elapsed(&self) -> Duration {
+    Duration::from_nanos(self.elapsed.load(Ordering::Relaxed))
+  }
+
   pub fn __do_not_use_or_you_will_be_fired_set_normal_executed(&self) {
     self.set_normal_executed()
   }
@@ -283,6 +296,7 @@ impl<C> From<Arc<dyn Loader<C>>> for LoaderItem<C> {
       loader,
       pitch_executed: AtomicBool::new(false),
       normal_executed: AtomicBool::new(false),
+      elapsed: AtomicU64::new(0),
     }
   }
 }
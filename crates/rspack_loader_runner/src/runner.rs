@@ -2,12 +2,14 @@ use std::{
   fmt::Debug,
   path::{Path, PathBuf},
   sync::Arc,
+  time::{Duration, Instant},
 };
 
 use anymap::CloneAny;
 use derivative::Derivative;
 use once_cell::sync::OnceCell;
 use rspack_error::{error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_identifier::Identifier;
 use rspack_sources::SourceMap;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -380,7 +382,9 @@ async fn iterate_normal_loaders<C: Send>(loader_context: &mut LoaderContext<'_,
   for p in loader_context.__plugins {
     p.before_each(loader_context)?;
   }
+  let start = Instant::now();
   loader.run(loader_context).await?;
+  loader_context.current_loader().add_elapsed(start.elapsed());
 
   iterate_normal_loaders(loader_context).await
 }
@@ -405,9 +409,11 @@ async fn iterate_pitching_loaders<C: Send>(
   for p in loader_context.__plugins {
     p.before_each(loader_context)?;
   }
+  let start = Instant::now();
   loader.pitch(loader_context).await?;
 
   let current_loader_item = loader_context.current_loader();
+  current_loader_item.add_elapsed(start.elapsed());
 
   // If pitching loader modifies the content,
   // runner should skip the remaining pitching loaders
@@ -427,6 +433,13 @@ async fn iterate_pitching_loaders<C: Send>(
   Ok(())
 }
 
+/// Time spent in a loader, including its pitching stage.
+#[derive(Debug, Clone)]
+pub struct LoaderTiming {
+  pub loader: Identifier,
+  pub duration: Duration,
+}
+
 #[derive(Debug)]
 pub struct LoaderResult {
   pub cacheable: bool,
@@ -438,6 +451,8 @@ pub struct LoaderResult {
   pub content: Content,
   pub source_map: Option<SourceMap>,
   pub additional_data: AdditionalData,
+  /// Timings of the executed loaders, in the order of the loader list
+  pub loader_timings: Vec<LoaderTiming>,
 }
 
 impl<C> TryFrom<LoaderContext<'_, C>> for TWithDiagnosticArray<LoaderResult> {
@@ -452,6 +467,16 @@ impl<C> TryFrom<LoaderContext<'_, C>> for TWithDiagnosticArray<LoaderResult> {
       }
     })?;
 
+    let loader_timings = loader_context
+      .__loader_items
+      .iter()
+      .filter(|item| item.pitch_executed() || item.normal_executed())
+      .map(|item| LoaderTiming {
+        loader: item.loader.identifier(),
+        duration: item.elapsed(),
+      })
+      .collect();
+
     Ok(
       LoaderResult {
         cacheable: loader_context.cacheable,
@@ -463,6 +488,7 @@ impl<C> TryFrom<LoaderContext<'_, C>> for TWithDiagnosticArray<LoaderResult> {
         content,
         source_map: loader_context.source_map,
         additional_data: loader_context.additional_data,
+        loader_timings,
       }
       .with_diagnostic(loader_context.__diagnostics),
     )
@@ -0,0 +1,167 @@
+use std::{
+  collections::HashMap,
+  io,
+  path::{Path, PathBuf},
+  sync::Mutex,
+};
+
+use grass_compiler::Fs;
+use rspack_core::{ResolveResult, Resolver};
+
+/// A virtual load path, grass looks up imports that are not found relative to the importer in it,
+/// and those are resolved by the rspack resolver.
+const RESOLVE_ROOT: &str = "__rspack_sass_resolve__";
+
+/// The file system of grass, which reads the entry from the loader content and resolves imports
+/// with the rspack resolver.
+#[derive(Debug)]
+pub struct RspackImporter<'a> {
+  resolver: &'a Resolver,
+  resource_path: &'a Path,
+  context: PathBuf,
+  resolve_root: PathBuf,
+  source: String,
+  resolved: Mutex<HashMap<String, Option<PathBuf>>>,
+  dependencies: Mutex<Vec<PathBuf>>,
+}
+
+impl<'a> RspackImporter<'a> {
+  pub fn new(resolver: &'a Resolver, resource_path: &'a Path, source: String) -> Self {
+    let context = resource_path
+      .parent()
+      .map(Path::to_path_buf)
+      .unwrap_or_default();
+    Self {
+      resolver,
+      resource_path,
+      resolve_root: context.join(RESOLVE_ROOT),
+      context,
+      source,
+      resolved: Default::default(),
+      dependencies: Default::default(),
+    }
+  }
+
+  pub fn resolve_root(&self) -> &Path {
+    &self.resolve_root
+  }
+
+  /// The files read during the compilation.
+  pub fn into_dependencies(self) -> Vec<PathBuf> {
+    self
+      .dependencies
+      .into_inner()
+      .expect("should lock dependencies")
+  }
+
+  fn resolve_request(&self, request: &str) -> Option<PathBuf> {
+    let mut resolved = self.resolved.lock().expect("should lock resolved");
+    resolved
+      .entry(request.to_string())
+      .or_insert_with(|| match self.resolver.resolve(&self.context, request) {
+        Ok(ResolveResult::Resource(resource)) => Some(resource.path),
+        _ => None,
+      })
+      .clone()
+  }
+
+  /// Resolves a path in the virtual load path, which is one of the candidates grass generates for
+  /// an import, e.g. `pkg/_file.scss` for `@use "pkg/file"`. The candidate is resolved as is first,
+  /// then the original request is resolved, so that packages with a `sass` field also work.
+  fn resolve_virtual(&self, path: &Path) -> Option<PathBuf> {
+    let candidate = path.strip_prefix(&self.resolve_root).ok()?;
+    let candidate = candidate.to_string_lossy().replace('\\', "/");
+    // the legacy `~` prefix of sass-loader
+    let candidate = candidate.strip_prefix('~').unwrap_or(&candidate);
+    self
+      .resolve_request(candidate)
+      .or_else(|| self.resolve_request(&original_request(candidate)?))
+  }
+
+  fn real_path(&self, path: &Path) -> Option<PathBuf> {
+    if path.starts_with(&self.resolve_root) {
+      self.resolve_virtual(path)
+    } else {
+      Some(path.to_path_buf())
+    }
+  }
+}
+
+/// Strips the partial prefix, the extension and the index file grass adds to a request.
+fn original_request(candidate: &str) -> Option<String> {
+  let (dir, basename) = match candidate.rsplit_once('/') {
+    Some((dir, basename)) => (Some(dir), basename),
+    None => (None, candidate),
+  };
+  let basename = basename.strip_prefix('_').unwrap_or(basename);
+  let basename = [".sass", ".scss", ".css"]
+    .iter()
+    .find_map(|ext| basename.strip_suffix(ext))?;
+  let basename = basename.strip_suffix(".import").unwrap_or(basename);
+  match (dir, basename) {
+    (Some(dir), "index") => Some(dir.to_string()),
+    (Some(dir), basename) => Some(format!("{dir}/{basename}")),
+    (None, "index") => None,
+    (None, basename) => Some(basename.to_string()),
+  }
+}
+
+impl Fs for RspackImporter<'_> {
+  fn is_dir(&self, path: &Path) -> bool {
+    path.starts_with(&self.resolve_root) || path.is_dir()
+  }
+
+  fn is_file(&self, path: &Path) -> bool {
+    if path == self.resource_path {
+      return true;
+    }
+    self.real_path(path).is_some_and(|path| path.is_file())
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    let (path, content) = if path == self.resource_path {
+      (path.to_path_buf(), self.source.clone())
+    } else {
+      let path = self
+        .real_path(path)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, path.display().to_string()))?;
+      let content = std::fs::read_to_string(&path)?;
+      (path, content)
+    };
+    let mut dependencies = self.dependencies.lock().expect("should lock dependencies");
+    if !dependencies.contains(&path) {
+      dependencies.push(path);
+    }
+    Ok(content.into_bytes())
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    match self.real_path(path) {
+      Some(path) if path == self.resource_path => Ok(path),
+      Some(path) => std::fs::canonicalize(path),
+      None => Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        path.display().to_string(),
+      )),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::original_request;
+
+  #[test]
+  fn strip_import_candidate() {
+    assert_eq!(
+      original_request("pkg/scss/_variables.scss").as_deref(),
+      Some("pkg/scss/variables")
+    );
+    assert_eq!(
+      original_request("pkg/index.import.sass").as_deref(),
+      Some("pkg")
+    );
+    assert_eq!(original_request("pkg.css").as_deref(), Some("pkg"));
+    assert_eq!(original_request("index.scss"), None);
+  }
+}
@@ -0,0 +1,167 @@
+mod importer;
+mod options;
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use grass_compiler::{codemap::SpanLoc, ErrorKind, InputSyntax, Logger};
+pub use options::SassLoaderOptions;
+use rspack_core::{
+  DependencyCategory, LoaderRunnerContext, Resolve, ResolveOptionsWithDependencyType,
+};
+use rspack_error::{error, Diagnostic, DiagnosticKind, Result, TraceableError};
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+
+use crate::importer::RspackImporter;
+
+pub const SASS_LOADER_IDENTIFIER: &str = "builtin:sass-loader";
+
+#[derive(Debug)]
+pub struct SassLoader {
+  identifier: Identifier,
+  options: SassLoaderOptions,
+}
+
+impl SassLoader {
+  pub fn new(options: SassLoaderOptions) -> Self {
+    Self {
+      identifier: SASS_LOADER_IDENTIFIER.into(),
+      options,
+    }
+  }
+
+  /// Panics:
+  /// Panics if `identifier` passed in is not starting with `builtin:sass-loader`.
+  pub fn with_identifier(mut self, identifier: Identifier) -> Self {
+    assert!(identifier.starts_with(SASS_LOADER_IDENTIFIER));
+    self.identifier = identifier;
+    self
+  }
+}
+
+/// Resolve options used by sass-loader, see https://github.com/webpack-contrib/sass-loader/blob/master/src/utils.js
+fn sass_resolve_options() -> Resolve {
+  let to_vec = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+  Resolve {
+    extensions: Some(to_vec(&[".sass", ".scss", ".css"])),
+    main_files: Some(to_vec(&["_index", "index", "..."])),
+    main_fields: Some(to_vec(&["sass", "style", "main", "..."])),
+    condition_names: Some(to_vec(&["sass", "style", "..."])),
+    prefer_relative: Some(true),
+    ..Default::default()
+  }
+}
+
+#[derive(Debug, Default)]
+struct WarningCollector(Mutex<Vec<String>>);
+
+impl Logger for WarningCollector {
+  fn debug(&self, _location: SpanLoc, _message: &str) {}
+
+  fn warn(&self, location: SpanLoc, message: &str) {
+    self.0.lock().expect("should lock warnings").push(format!(
+      "{message}\n    {}:{}:{}",
+      location.file.name(),
+      location.begin.line + 1,
+      location.begin.column + 1
+    ));
+  }
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for SassLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    let resource_path = loader_context.resource_path().to_path_buf();
+    let content = std::mem::take(&mut loader_context.content).expect("content should be available");
+    let mut source = content.try_into_string()?;
+    if let Some(additional_data) = &self.options.additional_data {
+      source = format!("{additional_data}\n{source}");
+    }
+
+    let resolver = loader_context
+      .context
+      .resolver_factory
+      .get(ResolveOptionsWithDependencyType {
+        resolve_options: Some(Box::new(sass_resolve_options())),
+        resolve_to_context: false,
+        dependency_category: DependencyCategory::CssImport,
+      });
+    let importer = RspackImporter::new(&resolver, &resource_path, source);
+    let logger = WarningCollector::default();
+
+    let sass_options = &self.options.sass_options;
+    let mut options = grass_compiler::Options::default()
+      .fs(&importer)
+      .logger(&logger)
+      .load_paths(&sass_options.load_paths)
+      .load_path(importer.resolve_root())
+      .style(sass_options.style.unwrap_or_default().into())
+      .quiet(sass_options.quiet);
+    if sass_options.indented_syntax {
+      options = options.input_syntax(InputSyntax::Sass);
+    }
+
+    let result = grass_compiler::from_path(&resource_path, &options);
+    let dependencies = importer.into_dependencies();
+
+    for warning in logger.0.into_inner().expect("should lock warnings") {
+      loader_context.emit_diagnostic(Diagnostic::warn(
+        SASS_LOADER_IDENTIFIER.to_string(),
+        warning,
+      ));
+    }
+
+    let css = result.map_err(|e| match e.kind() {
+      ErrorKind::ParseError { message, loc, .. } => {
+        let start = offset_of(&loc, loc.begin);
+        let end = offset_of(&loc, loc.end).max(start);
+        // the error may come from an imported file
+        let message = if Path::new(loc.file.name()) == resource_path {
+          message
+        } else {
+          format!("{message} in {}", loc.file.name())
+        };
+        TraceableError::from_file(
+          loc.file.source().to_string(),
+          start,
+          end,
+          "Sass compile error".to_string(),
+          message,
+        )
+        .with_kind(DiagnosticKind::Scss)
+        .into()
+      }
+      ErrorKind::FromUtf8Error(message) => error!(message),
+      ErrorKind::IoError(e) => error!(e.to_string()),
+      _ => error!("failed to compile {}", resource_path.display()),
+    })?;
+
+    // TODO: grass doesn't track the positions of the generated css, so no source map is
+    // generated, the source map of the previous loaders is passed through as is
+    loader_context.file_dependencies.extend(dependencies);
+    loader_context.content = Some(css.into());
+    Ok(())
+  }
+}
+
+/// Converts the char based line and column of grass to a byte offset.
+fn offset_of(loc: &SpanLoc, line_col: grass_compiler::codemap::LineCol) -> usize {
+  let file = &loc.file;
+  if line_col.line >= file.num_lines() {
+    return file.source().len();
+  }
+  let line_start = (file.line_span(line_col.line).low() - file.span.low()) as usize;
+  let column = file
+    .source_line(line_col.line)
+    .chars()
+    .take(line_col.column)
+    .map(char::len_utf8)
+    .sum::<usize>();
+  line_start + column
+}
+
+impl Identifiable for SassLoader {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use grass_compiler::OutputStyle;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SassLoaderOptions {
+  /// Prepends Sass code before the actual entry file.
+  pub additional_data: Option<String>,
+  pub sass_options: SassOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SassOptions {
+  pub style: Option<SassOutputStyle>,
+  pub load_paths: Vec<PathBuf>,
+  /// Parses the entry file with the indented syntax.
+  pub indented_syntax: bool,
+  /// Silences warnings from `@warn` and deprecations.
+  pub quiet: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SassOutputStyle {
+  #[default]
+  Expanded,
+  Compressed,
+}
+
+impl From<SassOutputStyle> for OutputStyle {
+  fn from(value: SassOutputStyle) -> Self {
+    match value {
+      SassOutputStyle::Expanded => Self::Expanded,
+      SassOutputStyle::Compressed => Self::Compressed,
+    }
+  }
+}
@@ -2,6 +2,7 @@
 
 mod compiler;
 mod options;
+mod svgr;
 mod transformer;
 
 use std::default::Default;
@@ -15,6 +16,7 @@ use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
 use rspack_plugin_javascript::ast::{self, SourceMapConfig};
 use rspack_plugin_javascript::TransformOutput;
 use rspack_util::source_map::SourceMapKind;
+pub use svgr::{SvgrLoader, SvgrLoaderOptions, SVGR_LOADER_IDENTIFIER};
 use swc_config::{config_types::MergingOption, merge::Merge};
 use swc_core::base::config::SourceMapsConfig;
 use swc_core::base::config::{InputSourceMap, OutputCharset, TransformConfig};
@@ -0,0 +1,231 @@
+//! Converts the SVG elements to JSX, like `hast-util-to-babel-ast` does for svgr.
+
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::ast::{
+    Expr, Ident, JSXAttr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXClosingElement,
+    JSXElement, JSXElementChild, JSXElementName, JSXExpr, JSXExprContainer, JSXNamespacedName,
+    JSXOpeningElement, JSXText, KeyValueProp, Lit, ObjectLit, Prop, PropName, PropOrSpread, Str,
+  },
+};
+use swc_xml_ast::{Child, Element};
+
+pub(crate) fn to_jsx_element(element: &Element) -> JSXElement {
+  let name = to_jsx_element_name(&element.tag_name);
+  let attrs = element
+    .attributes
+    .iter()
+    .map(|attr| {
+      let value = attr.value.as_deref().unwrap_or_default();
+      let value = if attr.name == "style" {
+        expr_container(to_style_object(value))
+      } else {
+        to_jsx_attr_value(value)
+      };
+      jsx_attr(&to_jsx_attr_name(&attr.name), value)
+    })
+    .collect();
+  let is_raw_text = matches!(&*element.tag_name, "style" | "script");
+  let children = element
+    .children
+    .iter()
+    .filter_map(|child| match child {
+      Child::Element(element) => Some(JSXElementChild::JSXElement(Box::new(to_jsx_element(
+        element,
+      )))),
+      Child::Text(text) => to_jsx_text(&text.data, is_raw_text),
+      Child::CdataSection(cdata) => to_jsx_text(&cdata.data, true),
+      Child::Comment(_) | Child::ProcessingInstruction(_) | Child::DocumentType(_) => None,
+    })
+    .collect::<Vec<_>>();
+  jsx_element(name, attrs, children)
+}
+
+pub(crate) fn jsx_element(
+  name: JSXElementName,
+  attrs: Vec<JSXAttrOrSpread>,
+  children: Vec<JSXElementChild>,
+) -> JSXElement {
+  let self_closing = children.is_empty();
+  JSXElement {
+    span: DUMMY_SP,
+    closing: (!self_closing).then(|| JSXClosingElement {
+      span: DUMMY_SP,
+      name: name.clone(),
+    }),
+    opening: JSXOpeningElement {
+      name,
+      span: DUMMY_SP,
+      attrs,
+      self_closing,
+      type_args: None,
+    },
+    children,
+  }
+}
+
+pub(crate) fn jsx_attr(name: &str, value: JSXAttrValue) -> JSXAttrOrSpread {
+  JSXAttrOrSpread::JSXAttr(JSXAttr {
+    span: DUMMY_SP,
+    name: JSXAttrName::Ident(Ident::new(name.into(), DUMMY_SP)),
+    value: Some(value),
+  })
+}
+
+pub(crate) fn expr_container(expr: Expr) -> JSXAttrValue {
+  JSXAttrValue::JSXExprContainer(JSXExprContainer {
+    span: DUMMY_SP,
+    expr: JSXExpr::Expr(Box::new(expr)),
+  })
+}
+
+fn to_jsx_element_name(name: &str) -> JSXElementName {
+  match name.split_once(':') {
+    Some((ns, name)) => JSXElementName::JSXNamespacedName(JSXNamespacedName {
+      ns: Ident::new(ns.into(), DUMMY_SP),
+      name: Ident::new(name.into(), DUMMY_SP),
+    }),
+    None => JSXElementName::Ident(Ident::new(name.into(), DUMMY_SP)),
+  }
+}
+
+/// JSX strings can not contain escapes, fall back to an expression for those values.
+fn to_jsx_attr_value(value: &str) -> JSXAttrValue {
+  let lit = Lit::Str(Str::from(value));
+  if value.contains(['"', '\\']) || value.contains(char::is_control) {
+    expr_container(Expr::Lit(lit))
+  } else {
+    JSXAttrValue::Lit(lit)
+  }
+}
+
+fn to_jsx_text(text: &str, is_raw_text: bool) -> Option<JSXElementChild> {
+  if text.trim().is_empty() {
+    return None;
+  }
+  if is_raw_text || text.contains(['{', '}', '<', '>', '&', '\n']) {
+    return Some(JSXElementChild::JSXExprContainer(JSXExprContainer {
+      span: DUMMY_SP,
+      expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(Str::from(text))))),
+    }));
+  }
+  Some(JSXElementChild::JSXText(JSXText {
+    span: DUMMY_SP,
+    value: text.into(),
+    raw: text.into(),
+  }))
+}
+
+fn to_jsx_attr_name(name: &str) -> String {
+  match name {
+    "class" => return "className".to_string(),
+    "for" => return "htmlFor".to_string(),
+    "tabindex" => return "tabIndex".to_string(),
+    _ => {}
+  }
+  if name.starts_with("data-") || name.starts_with("aria-") {
+    return name.to_string();
+  }
+  camel_case(name, &['-', ':'])
+}
+
+fn camel_case(name: &str, separators: &[char]) -> String {
+  let mut result = String::with_capacity(name.len());
+  let mut upper = false;
+  for ch in name.chars() {
+    if separators.contains(&ch) {
+      upper = !result.is_empty();
+    } else if upper {
+      result.extend(ch.to_uppercase());
+      upper = false;
+    } else {
+      result.push(ch);
+    }
+  }
+  result
+}
+
+fn to_style_object(style: &str) -> Expr {
+  let props = split_declarations(style)
+    .into_iter()
+    .filter_map(|declaration| {
+      let (property, value) = declaration.split_once(':')?;
+      let (property, value) = (property.trim(), value.trim());
+      if property.is_empty() || value.is_empty() {
+        return None;
+      }
+      let key = if property.starts_with("--") {
+        PropName::Str(Str::from(property))
+      } else {
+        let property = property.to_ascii_lowercase();
+        // `-ms-` is the only vendor prefix starting with a lower case letter in React.
+        let property = match property.strip_prefix("-ms-") {
+          Some(rest) => format!("ms-{rest}"),
+          None => property,
+        };
+        let key = camel_case(&property, &['-']);
+        let key = if property.starts_with('-') {
+          let mut chars = key.chars();
+          chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+        } else {
+          key
+        };
+        PropName::Ident(Ident::new(key.into(), DUMMY_SP))
+      };
+      Some(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key,
+        value: Box::new(Expr::Lit(Lit::Str(Str::from(value)))),
+      }))))
+    })
+    .collect();
+  Expr::Object(ObjectLit {
+    span: DUMMY_SP,
+    props,
+  })
+}
+
+/// Splits the declarations by `;`, except the ones in parentheses like `url(data:...;base64,...)`.
+fn split_declarations(style: &str) -> Vec<&str> {
+  let mut declarations = vec![];
+  let mut depth = 0usize;
+  let mut start = 0;
+  for (index, ch) in style.char_indices() {
+    match ch {
+      '(' => depth += 1,
+      ')' => depth = depth.saturating_sub(1),
+      ';' if depth == 0 => {
+        declarations.push(&style[start..index]);
+        start = index + 1;
+      }
+      _ => {}
+    }
+  }
+  declarations.push(&style[start..]);
+  declarations
+}
+
+#[cfg(test)]
+mod test {
+  use super::{camel_case, split_declarations, to_jsx_attr_name};
+
+  #[test]
+  fn attr_names() {
+    assert_eq!(to_jsx_attr_name("class"), "className");
+    assert_eq!(to_jsx_attr_name("stroke-width"), "strokeWidth");
+    assert_eq!(to_jsx_attr_name("xlink:href"), "xlinkHref");
+    assert_eq!(to_jsx_attr_name("xmlns:xlink"), "xmlnsXlink");
+    assert_eq!(to_jsx_attr_name("aria-hidden"), "aria-hidden");
+    assert_eq!(camel_case("-webkit-mask", &['-']), "webkitMask");
+  }
+
+  #[test]
+  fn style_declarations() {
+    assert_eq!(
+      split_declarations("fill:red;background:url(data:image/png;base64,AA==)"),
+      vec!["fill:red", "background:url(data:image/png;base64,AA==)"]
+    );
+  }
+}
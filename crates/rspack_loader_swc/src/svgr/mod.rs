@@ -0,0 +1,104 @@
+mod jsx;
+mod optimize;
+mod options;
+mod transform;
+
+use std::path::Path;
+
+pub use options::SvgrLoaderOptions;
+use rspack_core::LoaderRunnerContext;
+use rspack_error::Result;
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+
+pub const SVGR_LOADER_IDENTIFIER: &str = "builtin:svgr-loader";
+
+#[derive(Debug)]
+pub struct SvgrLoader {
+  identifier: Identifier,
+  options: SvgrLoaderOptions,
+}
+
+impl SvgrLoader {
+  pub fn new(options: SvgrLoaderOptions) -> Self {
+    Self {
+      identifier: SVGR_LOADER_IDENTIFIER.into(),
+      options,
+    }
+  }
+
+  /// Panics:
+  /// Panics if `identifier` passed in is not starting with `builtin:svgr-loader`.
+  pub fn with_identifier(mut self, identifier: Identifier) -> Self {
+    assert!(identifier.starts_with(SVGR_LOADER_IDENTIFIER));
+    self.identifier = identifier;
+    self
+  }
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for SvgrLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    let resource_path = loader_context.resource_path().to_path_buf();
+    let content = std::mem::take(&mut loader_context.content).expect("content should be available");
+    let source = content.try_into_string()?;
+
+    let file_name = resource_path
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_default();
+    // `!!` skips the configured loaders, the default `dependency: "url"` rule
+    // turns the file into an `asset/resource` module.
+    let url_request = format!("!!./{file_name}");
+    let code = transform::transform(
+      source,
+      &resource_path.to_string_lossy(),
+      &component_name(&resource_path),
+      &url_request,
+      &self.options,
+    )?;
+
+    loader_context.content = Some(code.into());
+    loader_context.source_map = None;
+    Ok(())
+  }
+}
+
+impl Identifiable for SvgrLoader {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+/// `arrow-left.svg` to `SvgArrowLeft`, like svgr does.
+fn component_name(resource_path: &Path) -> String {
+  let stem = resource_path
+    .file_stem()
+    .map(|stem| stem.to_string_lossy())
+    .unwrap_or_default();
+  let mut name = String::from("Svg");
+  for word in stem.split(|c: char| !c.is_ascii_alphanumeric()) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+      name.push(first.to_ascii_uppercase());
+      name.extend(chars);
+    }
+  }
+  name
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::Path;
+
+  use super::component_name;
+
+  #[test]
+  fn component_names() {
+    assert_eq!(
+      component_name(Path::new("/a/arrow-left.svg")),
+      "SvgArrowLeft"
+    );
+    assert_eq!(component_name(Path::new("/a/1_icon.svg")), "Svg1Icon");
+    assert_eq!(component_name(Path::new("/a/Logo.svg")), "SvgLogo");
+  }
+}
@@ -0,0 +1,86 @@
+//! A small subset of the default preset of svgo, enough for the output of the common editors.
+
+use std::collections::HashSet;
+
+use swc_xml_ast::{Child, Element};
+
+/// Prefixes of the namespaces used by Inkscape, Sketch, Affinity and Illustrator.
+const EDITOR_NAMESPACES: &[&str] = &["inkscape", "sodipodi", "sketch", "serif", "i", "x", "graph"];
+
+const CONTAINERS: &[&str] = &[
+  "defs", "g", "mask", "clipPath", "marker", "pattern", "symbol",
+];
+
+pub(crate) fn optimize(svg: &mut Element) {
+  optimize_element(svg);
+
+  let mut used = HashSet::new();
+  collect_prefixes(svg, &mut used);
+  svg
+    .attributes
+    .retain(|attr| match attr.name.strip_prefix("xmlns:") {
+      Some(prefix) => used.contains(prefix),
+      None => true,
+    });
+}
+
+fn optimize_element(element: &mut Element) {
+  element.attributes.retain(|attr| {
+    let value = attr.value.as_deref().unwrap_or_default();
+    !value.trim().is_empty() && !is_editor_name(&attr.name)
+  });
+  for attr in element.attributes.iter_mut() {
+    if let Some(value) = &attr.value {
+      let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+      if collapsed != **value {
+        attr.value = Some(collapsed.into());
+      }
+    }
+  }
+
+  element.children.retain_mut(|child| match child {
+    Child::Element(element) => {
+      if element.tag_name == "metadata" || is_editor_name(&element.tag_name) {
+        return false;
+      }
+      optimize_element(element);
+      !(CONTAINERS.contains(&&*element.tag_name)
+        && element.children.is_empty()
+        && !element.attributes.iter().any(|attr| attr.name == "id"))
+    }
+    Child::Text(text) => !text.data.trim().is_empty(),
+    Child::CdataSection(_) => true,
+    Child::Comment(_) | Child::ProcessingInstruction(_) | Child::DocumentType(_) => false,
+  });
+}
+
+fn is_editor_name(name: &str) -> bool {
+  let prefix = match name.strip_prefix("xmlns:") {
+    Some(prefix) => prefix,
+    None => match name.split_once(':') {
+      Some((prefix, _)) => prefix,
+      None => return false,
+    },
+  };
+  EDITOR_NAMESPACES.contains(&prefix)
+}
+
+fn collect_prefixes(element: &Element, used: &mut HashSet<String>) {
+  let names = std::iter::once(&element.tag_name).chain(
+    element
+      .attributes
+      .iter()
+      .filter(|attr| !attr.name.starts_with("xmlns:"))
+      .map(|attr| &attr.name),
+  );
+  for name in names {
+    if let Some((prefix, _)) = name.split_once(':') {
+      used.insert(prefix.to_string());
+    }
+  }
+  for child in &element.children {
+    if let Child::Element(element) = child {
+      collect_prefixes(element, used);
+    }
+  }
+}
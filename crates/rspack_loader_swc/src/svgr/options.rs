@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportType {
+  #[default]
+  Default,
+  Named,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JsxRuntime {
+  #[default]
+  Classic,
+  Automatic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ExpandProps {
+  Bool(bool),
+  Position(ExpandPropsPosition),
+}
+
+impl Default for ExpandProps {
+  fn default() -> Self {
+    Self::Position(ExpandPropsPosition::End)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExpandPropsPosition {
+  Start,
+  End,
+}
+
+impl ExpandProps {
+  pub(crate) fn position(&self) -> Option<ExpandPropsPosition> {
+    match self {
+      Self::Bool(true) => Some(ExpandPropsPosition::End),
+      Self::Bool(false) => None,
+      Self::Position(position) => Some(*position),
+    }
+  }
+}
+
+/// Options of `builtin:svgr-loader`, named after the options of `@svgr/webpack`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SvgrLoaderOptions {
+  /// Forwards the ref to the root `<svg>` element.
+  #[serde(rename = "ref")]
+  pub ref_: bool,
+  /// Adds `title` and `titleId` props rendered as the `<title>` of the SVG.
+  pub title: bool,
+  /// Wraps the component with `React.memo`.
+  pub memo: bool,
+  /// Emits a TSX module with typed props.
+  pub typescript: bool,
+  /// Where the props are spread on the root `<svg>` element, `false` to not spread them.
+  pub expand_props: ExpandProps,
+  /// Keeps `width` and `height` of the root `<svg>` element.
+  pub dimensions: bool,
+  /// Replaces `width` and `height` of the root `<svg>` element with `1em`.
+  pub icon: bool,
+  /// Attributes added to the root `<svg>` element.
+  pub svg_props: BTreeMap<String, String>,
+  /// Attribute values replaced in every element.
+  pub replace_attr_values: BTreeMap<String, String>,
+  /// Runs the built-in optimizations before converting the SVG.
+  pub svgo: bool,
+  pub jsx_runtime: JsxRuntime,
+  pub export_type: ExportType,
+  /// Name of the component export when `exportType` is `named`.
+  pub named_export: String,
+  /// Name of an extra export holding the URL of the SVG emitted as an asset module.
+  pub url_export: Option<String>,
+}
+
+impl Default for SvgrLoaderOptions {
+  fn default() -> Self {
+    Self {
+      ref_: false,
+      title: false,
+      memo: false,
+      typescript: false,
+      expand_props: Default::default(),
+      dimensions: true,
+      icon: false,
+      svg_props: Default::default(),
+      replace_attr_values: Default::default(),
+      svgo: true,
+      jsx_runtime: Default::default(),
+      export_type: Default::default(),
+      named_export: "ReactComponent".to_string(),
+      url_export: None,
+    }
+  }
+}
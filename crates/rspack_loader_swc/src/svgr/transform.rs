@@ -0,0 +1,359 @@
+use std::sync::Arc;
+
+use rspack_error::{error, Result, TraceableError};
+use rspack_plugin_javascript::{
+  ast::{self, SourceMapConfig},
+  IsModule,
+};
+use swc_core::{
+  base::config::JsMinifyFormatOptions,
+  common::{FileName, SourceFile, SourceMap, DUMMY_SP},
+  ecma::{
+    ast::{
+      BinExpr, BinaryOp, CondExpr, EsVersion, Expr, Ident, JSXAttrOrSpread, JSXClosingElement,
+      JSXElementChild, JSXElementName, JSXExpr, JSXExprContainer, Lit, Null, ParenExpr,
+      SpreadElement, Str,
+    },
+    parser::{EsConfig, Syntax, TsConfig},
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+use swc_xml_ast::{Attribute, Child, Element};
+use swc_xml_parser::parser::ParserConfig;
+
+use super::{
+  jsx::{expr_container, jsx_attr, jsx_element, to_jsx_element},
+  optimize::optimize,
+  options::{ExpandPropsPosition, ExportType, JsxRuntime, SvgrLoaderOptions},
+};
+
+const JSX_PLACEHOLDER: &str = "__SVGR_JSX__";
+
+/// Transforms the SVG to a JSX or TSX module exporting a React component.
+pub(crate) fn transform(
+  source: String,
+  filename: &str,
+  component_name: &str,
+  url_request: &str,
+  options: &SvgrLoaderOptions,
+) -> Result<String> {
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Custom(filename.to_string()), source);
+  let mut svg = parse_svg(&fm)?;
+
+  if options.svgo {
+    optimize(&mut svg);
+  }
+  if !options.replace_attr_values.is_empty() {
+    replace_attr_values(&mut svg, options);
+  }
+  if !options.dimensions || options.icon {
+    svg
+      .attributes
+      .retain(|attr| attr.name != "width" && attr.name != "height");
+  }
+  if options.icon {
+    set_attr(&mut svg, "width", "1em");
+    set_attr(&mut svg, "height", "1em");
+  }
+  for (name, value) in &options.svg_props {
+    set_attr(&mut svg, name, value);
+  }
+  let default_title = options.title.then(|| take_title(&mut svg)).flatten();
+
+  let mut element = to_jsx_element(&svg);
+  let attrs = &mut element.opening.attrs;
+  if options.ref_ {
+    attrs.push(jsx_attr("ref", expr_container(ident("ref"))));
+  }
+  if options.title {
+    attrs.push(jsx_attr(
+      "aria-labelledby",
+      expr_container(ident("titleId")),
+    ));
+    element.children.insert(0, title_child(default_title));
+  }
+  let props = JSXAttrOrSpread::SpreadElement(SpreadElement {
+    dot3_token: DUMMY_SP,
+    expr: Box::new(ident("props")),
+  });
+  match options.expand_props.position() {
+    Some(ExpandPropsPosition::Start) => element.opening.attrs.insert(0, props),
+    Some(ExpandPropsPosition::End) => element.opening.attrs.push(props),
+    None => {}
+  }
+  element.closing = (!element.children.is_empty()).then(|| JSXClosingElement {
+    span: DUMMY_SP,
+    name: element.opening.name.clone(),
+  });
+  element.opening.self_closing = element.closing.is_none();
+
+  let template = component_template(component_name, url_request, options);
+  let template_fm = cm.new_source_file(FileName::Anon, template);
+  let syntax = if options.typescript {
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    })
+  } else {
+    Syntax::Es(EsConfig {
+      jsx: true,
+      ..Default::default()
+    })
+  };
+  let mut program = ast::parse_js(
+    template_fm,
+    EsVersion::latest(),
+    syntax,
+    IsModule::Bool(true),
+    None,
+  )
+  .map_err(|_| error!("Failed to parse the component template of {filename}"))?;
+  program.visit_mut_with(&mut ReplacePlaceholder {
+    jsx: Some(Expr::JSXElement(Box::new(element))),
+  });
+
+  let output = ast::print(
+    &program,
+    cm,
+    EsVersion::latest(),
+    SourceMapConfig::default(),
+    None,
+    false,
+    None,
+    &JsMinifyFormatOptions::default(),
+  )?;
+  Ok(output.code)
+}
+
+fn parse_svg(fm: &SourceFile) -> Result<Element> {
+  let mut errors = vec![];
+  let document = swc_xml_parser::parse_file_as_document(fm, ParserConfig::default(), &mut errors);
+  let to_error = |e: swc_xml_parser::error::Error| -> rspack_error::Error {
+    let message = e.message().to_string();
+    let span = e.into_inner().0;
+    TraceableError::from_source_file(
+      fm,
+      (span.lo - fm.start_pos).0 as usize,
+      (span.hi - fm.start_pos).0 as usize,
+      "SVG parse error".to_string(),
+      message,
+    )
+    .into()
+  };
+  let document = document.map_err(to_error)?;
+  if let Some(e) = errors.into_iter().next() {
+    return Err(to_error(e));
+  }
+  document
+    .children
+    .into_iter()
+    .find_map(|child| match child {
+      Child::Element(element) if element.tag_name == "svg" => Some(element),
+      _ => None,
+    })
+    .ok_or_else(|| error!("{} does not contain an <svg> root element", fm.name))
+}
+
+fn set_attr(element: &mut Element, name: &str, value: &str) {
+  match element.attributes.iter_mut().find(|attr| attr.name == name) {
+    Some(attr) => attr.value = Some(value.into()),
+    None => element.attributes.push(Attribute {
+      span: DUMMY_SP,
+      namespace: None,
+      prefix: None,
+      name: name.into(),
+      raw_name: None,
+      value: Some(value.into()),
+      raw_value: None,
+    }),
+  }
+}
+
+fn replace_attr_values(element: &mut Element, options: &SvgrLoaderOptions) {
+  for attr in element.attributes.iter_mut() {
+    if let Some(new_value) = attr
+      .value
+      .as_deref()
+      .and_then(|value| options.replace_attr_values.get(value))
+    {
+      attr.value = Some(new_value.as_str().into());
+    }
+  }
+  for child in element.children.iter_mut() {
+    if let Child::Element(element) = child {
+      replace_attr_values(element, options);
+    }
+  }
+}
+
+/// Removes the `<title>` of the SVG, its text is used when the `title` prop is not passed.
+fn take_title(svg: &mut Element) -> Option<String> {
+  let index = svg
+    .children
+    .iter()
+    .position(|child| matches!(child, Child::Element(element) if element.tag_name == "title"))?;
+  let Child::Element(title) = svg.children.remove(index) else {
+    unreachable!()
+  };
+  Some(
+    title
+      .children
+      .iter()
+      .filter_map(|child| match child {
+        Child::Text(text) => Some(&*text.data),
+        Child::CdataSection(cdata) => Some(&*cdata.data),
+        _ => None,
+      })
+      .collect(),
+  )
+}
+
+/// `{title === undefined ? <title id={titleId}>default</title> : title ? <title id={titleId}>{title}</title> : null}`
+fn title_child(default_title: Option<String>) -> JSXElementChild {
+  let title_element = |child: JSXElementChild| {
+    Box::new(Expr::JSXElement(Box::new(jsx_element(
+      JSXElementName::Ident(Ident::new("title".into(), DUMMY_SP)),
+      vec![jsx_attr("id", expr_container(ident("titleId")))],
+      vec![child],
+    ))))
+  };
+  let mut expr = Expr::Cond(CondExpr {
+    span: DUMMY_SP,
+    test: Box::new(ident("title")),
+    cons: title_element(JSXElementChild::JSXExprContainer(JSXExprContainer {
+      span: DUMMY_SP,
+      expr: JSXExpr::Expr(Box::new(ident("title"))),
+    })),
+    alt: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+  });
+  if let Some(default_title) = default_title {
+    expr = Expr::Cond(CondExpr {
+      span: DUMMY_SP,
+      test: Box::new(Expr::Bin(BinExpr {
+        span: DUMMY_SP,
+        op: BinaryOp::EqEqEq,
+        left: Box::new(ident("title")),
+        right: Box::new(ident("undefined")),
+      })),
+      cons: title_element(JSXElementChild::JSXExprContainer(JSXExprContainer {
+        span: DUMMY_SP,
+        expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(Str::from(default_title))))),
+      })),
+      alt: Box::new(expr),
+    });
+  }
+  JSXElementChild::JSXExprContainer(JSXExprContainer {
+    span: DUMMY_SP,
+    expr: JSXExpr::Expr(Box::new(expr)),
+  })
+}
+
+fn ident(sym: &str) -> Expr {
+  Expr::Ident(Ident::new(sym.into(), DUMMY_SP))
+}
+
+fn component_template(
+  component_name: &str,
+  url_request: &str,
+  options: &SvgrLoaderOptions,
+) -> String {
+  let typescript = options.typescript;
+  let mut code = String::new();
+
+  if options.jsx_runtime == JsxRuntime::Classic {
+    code += "import * as React from \"react\";\n";
+  }
+  let imports = [(options.ref_, "forwardRef"), (options.memo, "memo")]
+    .into_iter()
+    .filter_map(|(enabled, name)| enabled.then_some(name))
+    .collect::<Vec<_>>();
+  if !imports.is_empty() {
+    code += &format!("import {{ {} }} from \"react\";\n", imports.join(", "));
+  }
+  if typescript {
+    let types = if options.ref_ {
+      "SVGProps, Ref"
+    } else {
+      "SVGProps"
+    };
+    code += &format!("import type {{ {types} }} from \"react\";\n");
+    if options.title {
+      code += "interface SVGRProps {\n  title?: string;\n  titleId?: string;\n}\n";
+    }
+  }
+
+  let expand_props = options.expand_props.position().is_some();
+  let mut params = vec![];
+  let props = match (options.title, expand_props) {
+    (true, true) => Some("{ title, titleId, ...props }"),
+    (true, false) => Some("{ title, titleId }"),
+    (false, true) => Some("props"),
+    (false, false) => options.ref_.then_some("_"),
+  };
+  if let Some(props) = props {
+    let mut param = props.to_string();
+    if typescript {
+      param += ": SVGProps<SVGSVGElement>";
+      if options.title {
+        param += " & SVGRProps";
+      }
+    }
+    params.push(param);
+  }
+  if options.ref_ {
+    params.push(
+      if typescript {
+        "ref: Ref<SVGSVGElement>"
+      } else {
+        "ref"
+      }
+      .to_string(),
+    );
+  }
+  code += &format!(
+    "const {component_name} = ({}) => {JSX_PLACEHOLDER};\n",
+    params.join(", ")
+  );
+
+  let mut exported = component_name;
+  if options.ref_ {
+    code += &format!("const ForwardRef = forwardRef({exported});\n");
+    exported = "ForwardRef";
+  }
+  if options.memo {
+    code += &format!("const Memo = memo({exported});\n");
+    exported = "Memo";
+  }
+  match options.export_type {
+    ExportType::Default => code += &format!("export default {exported};\n"),
+    ExportType::Named => code += &format!("export {{ {exported} as {} }};\n", options.named_export),
+  }
+  if let Some(url_export) = &options.url_export {
+    code += &format!(
+      "export const {url_export} = new URL({}, import.meta.url).href;\n",
+      serde_json::to_string(url_request).expect("should serialize a string")
+    );
+  }
+  code
+}
+
+struct ReplacePlaceholder {
+  jsx: Option<Expr>,
+}
+
+impl VisitMut for ReplacePlaceholder {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Ident(ident) = expr
+      && ident.sym == JSX_PLACEHOLDER
+      && let Some(jsx) = self.jsx.take()
+    {
+      *expr = Expr::Paren(ParenExpr {
+        span: DUMMY_SP,
+        expr: Box::new(jsx),
+      });
+      return;
+    }
+    expr.visit_mut_children_with(self);
+  }
+}
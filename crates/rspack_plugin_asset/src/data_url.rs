@@ -0,0 +1,76 @@
+//! Text encoders of data urls, which are smaller than base64 for text assets.
+
+use std::fmt::Write;
+
+pub const UTF8_ENCODING: &str = "utf8";
+pub const SVG_ENCODING: &str = "svg";
+
+/// Percent-encodes the text, keeps the characters which are safe in urls and quoted strings.
+pub fn encode_utf8(text: &str) -> String {
+  percent_encode(
+    text,
+    |c| c.is_ascii_alphanumeric() || b"-_.!~*'();/?:@&=+$,".contains(&c),
+    false,
+  )
+}
+
+/// Minifies and percent-encodes the svg like [mini-svg-data-uri](https://github.com/tigt/mini-svg-data-uri).
+///
+/// The whitespaces are collapsed and the double quotes are replaced with single quotes,
+/// which don't need to be encoded. Spaces are still encoded to keep the url valid in `srcset`.
+pub fn encode_svg(svg: &str) -> String {
+  let svg = svg.strip_prefix('\u{feff}').unwrap_or(svg);
+  let mut body = String::with_capacity(svg.len());
+  for word in svg.split_whitespace() {
+    if !body.is_empty() {
+      body.push(' ');
+    }
+    body.push_str(word);
+  }
+  let body = body.replace('"', "'");
+  // Lowercase hex compresses better.
+  percent_encode(
+    &body,
+    |c| c.is_ascii_alphanumeric() || b"-_.!~*'()=:/".contains(&c),
+    true,
+  )
+}
+
+fn percent_encode(text: &str, is_safe: impl Fn(u8) -> bool, lowercase: bool) -> String {
+  let mut encoded = String::with_capacity(text.len());
+  for &c in text.as_bytes() {
+    if is_safe(c) {
+      encoded.push(c as char);
+    } else {
+      if lowercase {
+        write!(encoded, "%{c:02x}")
+      } else {
+        write!(encoded, "%{c:02X}")
+      }
+      .expect("should write to string");
+    }
+  }
+  encoded
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn svg() {
+    let svg = "\u{feff}<svg xmlns=\"http://www.w3.org/2000/svg\">\n  <path d=\"M0 0h1\" fill=\"#fff\"/>\n</svg>\n";
+    assert_eq!(
+      encode_svg(svg),
+      "%3csvg%20xmlns='http://www.w3.org/2000/svg'%3e%20%3cpath%20d='M0%200h1'%20fill='%23fff'/%3e%20%3c/svg%3e"
+    );
+  }
+
+  #[test]
+  fn utf8() {
+    assert_eq!(
+      encode_utf8("a = 100% #1\n\"ü\""),
+      "a%20=%20100%25%20%231%0A%22%C3%BC%22"
+    );
+  }
+}
@@ -0,0 +1,545 @@
+//! Optimizes, converts and resizes the images of asset modules when `generator.image` is set.
+
+use std::io::Cursor;
+
+use image::{
+  codecs::{
+    gif::GifEncoder,
+    jpeg::JpegEncoder,
+    png::{CompressionType, FilterType as PngFilterType, PngEncoder},
+    webp::WebPEncoder,
+  },
+  imageops::FilterType,
+  DynamicImage, GenericImageView,
+};
+use rkyv::{with::Skip, Archive, Deserialize, Serialize};
+use rspack_core::AssetGeneratorImageOptions;
+use rspack_error::{error, Result};
+
+const DEFAULT_QUALITY: u8 = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Archive, Serialize, Deserialize)]
+#[archive(compare(PartialEq), check_bytes)]
+pub(crate) enum ImageFormat {
+  Png,
+  Jpeg,
+  Gif,
+  Webp,
+  Avif,
+}
+
+impl ImageFormat {
+  fn from_name(name: &str) -> Option<Self> {
+    match name.to_ascii_lowercase().as_str() {
+      "png" => Some(Self::Png),
+      "jpg" | "jpeg" => Some(Self::Jpeg),
+      "gif" => Some(Self::Gif),
+      "webp" => Some(Self::Webp),
+      "avif" => Some(Self::Avif),
+      _ => None,
+    }
+  }
+
+  fn guess(source: &[u8]) -> Option<Self> {
+    match image::guess_format(source).ok()? {
+      image::ImageFormat::Png => Some(Self::Png),
+      image::ImageFormat::Jpeg => Some(Self::Jpeg),
+      image::ImageFormat::Gif => Some(Self::Gif),
+      image::ImageFormat::WebP => Some(Self::Webp),
+      _ => None,
+    }
+  }
+
+  fn to_image_format(self) -> Option<image::ImageFormat> {
+    match self {
+      Self::Png => Some(image::ImageFormat::Png),
+      Self::Jpeg => Some(image::ImageFormat::Jpeg),
+      Self::Gif => Some(image::ImageFormat::Gif),
+      Self::Webp => Some(image::ImageFormat::WebP),
+      Self::Avif => None,
+    }
+  }
+
+  pub(crate) fn extension(self) -> &'static str {
+    match self {
+      Self::Png => "png",
+      Self::Jpeg => "jpg",
+      Self::Gif => "gif",
+      Self::Webp => "webp",
+      Self::Avif => "avif",
+    }
+  }
+
+  pub(crate) fn mimetype(self) -> &'static str {
+    match self {
+      Self::Png => "image/png",
+      Self::Jpeg => "image/jpeg",
+      Self::Gif => "image/gif",
+      Self::Webp => "image/webp",
+      Self::Avif => "image/avif",
+    }
+  }
+}
+
+/// The outputs requested by the resource query, like `?width=320,640&format=webp`.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ImageQuery {
+  widths: Vec<u32>,
+  format: Option<ImageFormat>,
+}
+
+impl ImageQuery {
+  fn parse(query: &str) -> Result<Self> {
+    let mut result = Self::default();
+    for (key, value) in query
+      .trim_start_matches('?')
+      .split('&')
+      .filter_map(|pair| pair.split_once('='))
+    {
+      match key {
+        "width" | "w" => {
+          for width in value.split(',').filter(|width| !width.is_empty()) {
+            let width = width
+              .parse::<u32>()
+              .ok()
+              .filter(|width| *width > 0)
+              .ok_or_else(|| error!("Invalid image width {width:?} in query {query:?}"))?;
+            result.widths.push(width);
+          }
+        }
+        "format" => {
+          result.format = Some(
+            ImageFormat::from_name(value)
+              .ok_or_else(|| error!("Unsupported image format {value:?} in query {query:?}"))?,
+          );
+        }
+        _ => {}
+      }
+    }
+    Ok(result)
+  }
+}
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub(crate) struct ImageOutput {
+  pub(crate) width: u32,
+  pub(crate) height: u32,
+  /// Not persisted in the cache, the outputs are regenerated from the cached source.
+  #[with(Skip)]
+  pub(crate) content: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub(crate) struct ProcessedImage {
+  pub(crate) format: ImageFormat,
+  /// Whether the format is changed by the query, the extension of the filename follows it.
+  pub(crate) converted: bool,
+  /// Whether `width` is in the query, the module exports an object for `srcset` then.
+  pub(crate) responsive: bool,
+  /// The first one replaces the source of the module, the rest are emitted as related assets.
+  pub(crate) outputs: Vec<ImageOutput>,
+}
+
+impl ProcessedImage {
+  /// Renames `images/photo.png?width=320,640` to `images/photo-320w.webp?width=320,640`
+  /// for the filename template of each output.
+  pub(crate) fn file_name(&self, source_file_name: &str, output: &ImageOutput) -> String {
+    let (path, query) = source_file_name.split_at(
+      source_file_name
+        .find(['?', '#'])
+        .unwrap_or(source_file_name.len()),
+    );
+    let name_start = path.rfind('/').map_or(0, |index| index + 1);
+    let (stem, extension) = match path[name_start..].rfind('.') {
+      Some(index) if index > 0 => path.split_at(name_start + index),
+      _ => (path, ""),
+    };
+    let mut file_name = stem.to_string();
+    if self.responsive {
+      file_name += &format!("-{}w", output.width);
+    }
+    if self.converted || extension.is_empty() {
+      file_name += ".";
+      file_name += self.format.extension();
+    } else {
+      file_name += extension;
+    }
+    file_name + query
+  }
+}
+
+/// Returns `None` for the sources that are not images supported by the pipeline.
+pub(crate) fn process(
+  source: &[u8],
+  query: Option<&str>,
+  options: &AssetGeneratorImageOptions,
+) -> Result<Option<ProcessedImage>> {
+  // The query of other assets may mean something else, it's only parsed for supported images.
+  let Some(source_format) = ImageFormat::guess(source) else {
+    return Ok(None);
+  };
+  let query = match query {
+    Some(query) => ImageQuery::parse(query)?,
+    None => ImageQuery::default(),
+  };
+
+  if query.widths.is_empty() && query.format.map_or(true, |f| f == source_format) {
+    let (width, height) =
+      image::io::Reader::with_format(Cursor::new(source), image_format(source_format))
+        .into_dimensions()
+        .map_err(|e| error!("Failed to read the image: {e}"))?;
+    let content = if options.optimize.unwrap_or(true) {
+      optimize(source, source_format).unwrap_or_else(|| source.to_vec())
+    } else {
+      source.to_vec()
+    };
+    return Ok(Some(ProcessedImage {
+      format: source_format,
+      converted: false,
+      responsive: false,
+      outputs: vec![ImageOutput {
+        width,
+        height,
+        content,
+      }],
+    }));
+  }
+
+  let format = query.format.unwrap_or(source_format);
+  let quality = options.quality.unwrap_or(DEFAULT_QUALITY).clamp(1, 100);
+  let image = image::load_from_memory_with_format(source, image_format(source_format))
+    .map_err(|e| error!("Failed to decode the image: {e}"))?;
+  let mut widths: Vec<u32> = vec![];
+  for width in &query.widths {
+    // Widths larger than the image all produce the original size.
+    let width = (*width).min(image.width());
+    if !widths.contains(&width) {
+      widths.push(width);
+    }
+  }
+  if widths.is_empty() {
+    widths.push(image.width());
+  }
+  let outputs = widths
+    .into_iter()
+    .map(|width| {
+      let resized = resize(&image, width);
+      Ok(ImageOutput {
+        width: resized.width(),
+        height: resized.height(),
+        content: encode(&resized, format, quality)?,
+      })
+    })
+    .collect::<Result<Vec<_>>>()?;
+  Ok(Some(ProcessedImage {
+    format,
+    converted: format != source_format,
+    responsive: !query.widths.is_empty(),
+    outputs,
+  }))
+}
+
+fn image_format(format: ImageFormat) -> image::ImageFormat {
+  format
+    .to_image_format()
+    .expect("should be a format supported by image")
+}
+
+/// Images are only scaled down, the aspect ratio is kept.
+fn resize(image: &DynamicImage, width: u32) -> DynamicImage {
+  let (original_width, original_height) = image.dimensions();
+  if width >= original_width {
+    return image.clone();
+  }
+  let height =
+    ((original_height as f64 * width as f64 / original_width as f64).round() as u32).max(1);
+  image.resize_exact(width, height, FilterType::Lanczos3)
+}
+
+fn encode(image: &DynamicImage, format: ImageFormat, quality: u8) -> Result<Vec<u8>> {
+  let mut content = vec![];
+  let result = match format {
+    ImageFormat::Png => image.write_with_encoder(PngEncoder::new_with_quality(
+      &mut content,
+      CompressionType::Best,
+      PngFilterType::Adaptive,
+    )),
+    ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+      .write_with_encoder(JpegEncoder::new_with_quality(&mut content, quality)),
+    ImageFormat::Gif => {
+      GifEncoder::new(&mut content).encode_frame(image::Frame::new(image.to_rgba8()))
+    }
+    // Only the lossless WebP encoder is available without libwebp.
+    ImageFormat::Webp => DynamicImage::ImageRgba8(image.to_rgba8())
+      .write_with_encoder(WebPEncoder::new_lossless(&mut content)),
+    ImageFormat::Avif => {
+      let rgba = image.to_rgba8();
+      let pixels = rgba
+        .pixels()
+        .map(|p| ravif::RGBA8::new(p[0], p[1], p[2], p[3]))
+        .collect::<Vec<_>>();
+      let encoded = ravif::Encoder::new()
+        .with_quality(quality as f32)
+        .with_speed(6)
+        .encode_rgba(ravif::Img::new(
+          &pixels,
+          rgba.width() as usize,
+          rgba.height() as usize,
+        ))
+        .map_err(|e| error!("Failed to encode the image to avif: {e}"))?;
+      return Ok(encoded.avif_file);
+    }
+  };
+  result.map_err(|e| error!("Failed to encode the image to {}: {e}", format.extension()))?;
+  Ok(content)
+}
+
+/// Lossless optimizations, returns `None` when the result is not smaller.
+fn optimize(source: &[u8], format: ImageFormat) -> Option<Vec<u8>> {
+  let optimized = match format {
+    ImageFormat::Png => optimize_png(source),
+    ImageFormat::Jpeg => strip_jpeg_markers(source),
+    ImageFormat::Gif => optimize_gif(source),
+    ImageFormat::Webp | ImageFormat::Avif => None,
+  }?;
+  (optimized.len() < source.len()).then_some(optimized)
+}
+
+/// Re-encodes the pixels with the same color type at the best compression level,
+/// only the chunks affecting the colors are kept.
+fn optimize_png(source: &[u8]) -> Option<Vec<u8>> {
+  let mut decoder = png::Decoder::new(source);
+  decoder.set_transformations(png::Transformations::IDENTITY);
+  let mut reader = decoder.read_info().ok()?;
+  let info = reader.info();
+  // Animated PNGs and the embedded color profiles are left as is.
+  if info.animation_control.is_some() || info.icc_profile.is_some() {
+    return None;
+  }
+  let (width, height) = (info.width, info.height);
+  let (color_type, bit_depth) = (info.color_type, info.bit_depth);
+  let palette = info.palette.clone();
+  let trns = info.trns.clone();
+  let (gamma, chromaticities, srgb) = (info.source_gamma, info.source_chromaticities, info.srgb);
+  let mut pixels = vec![0; reader.output_buffer_size()];
+  let frame = reader.next_frame(&mut pixels).ok()?;
+  pixels.truncate(frame.buffer_size());
+
+  let mut content = vec![];
+  let mut encoder = png::Encoder::new(&mut content, width, height);
+  encoder.set_color(color_type);
+  encoder.set_depth(bit_depth);
+  if let Some(palette) = palette {
+    encoder.set_palette(palette);
+  }
+  if let Some(trns) = trns {
+    encoder.set_trns(trns);
+  }
+  if let Some(srgb) = srgb {
+    encoder.set_source_srgb(srgb);
+  } else {
+    if let Some(gamma) = gamma {
+      encoder.set_source_gamma(gamma);
+    }
+    if let Some(chromaticities) = chromaticities {
+      encoder.set_source_chromaticities(chromaticities);
+    }
+  }
+  encoder.set_compression(png::Compression::Best);
+  encoder.set_adaptive_filter(png::AdaptiveFilterType::Adaptive);
+  let mut writer = encoder.write_header().ok()?;
+  writer.write_image_data(&pixels).ok()?;
+  writer.finish().ok()?;
+  Some(content)
+}
+
+/// Removes comments and the application segments not affecting the rendering,
+/// JFIF, Exif (for the orientation), ICC profiles and Adobe segments are kept.
+fn strip_jpeg_markers(source: &[u8]) -> Option<Vec<u8>> {
+  if !source.starts_with(&[0xFF, 0xD8]) {
+    return None;
+  }
+  let mut content = Vec::with_capacity(source.len());
+  content.extend_from_slice(&source[..2]);
+  let mut offset = 2;
+  loop {
+    // Markers may be preceded by any number of fill bytes.
+    while source.get(offset) == Some(&0xFF) && source.get(offset + 1) == Some(&0xFF) {
+      offset += 1;
+    }
+    if *source.get(offset)? != 0xFF {
+      return None;
+    }
+    let marker = *source.get(offset + 1)?;
+    if marker == 0xD9 || (0xD0..=0xD7).contains(&marker) || marker == 0x01 {
+      content.extend_from_slice(&source[offset..offset + 2]);
+      if marker == 0xD9 {
+        return Some(content);
+      }
+      offset += 2;
+      continue;
+    }
+    let length = u16::from_be_bytes([*source.get(offset + 2)?, *source.get(offset + 3)?]) as usize;
+    // The length includes its own two bytes.
+    if length < 2 {
+      return None;
+    }
+    let segment = source.get(offset..offset + 2 + length)?;
+    // The entropy-coded data follows the start of scan, copy the rest as is.
+    if marker == 0xDA {
+      content.extend_from_slice(&source[offset..]);
+      return Some(content);
+    }
+    let payload = &segment[4..];
+    let keep = match marker {
+      0xFE => false,
+      0xE0 | 0xEE => true,
+      0xE1 => payload.starts_with(b"Exif\0"),
+      0xE2 => payload.starts_with(b"ICC_PROFILE\0"),
+      0xE3..=0xEF => false,
+      _ => true,
+    };
+    if keep {
+      content.extend_from_slice(segment);
+    }
+    offset += 2 + length;
+  }
+}
+
+/// Re-encodes the indexed frames, the comments and unknown extensions are dropped.
+fn optimize_gif(source: &[u8]) -> Option<Vec<u8>> {
+  let mut options = gif::DecodeOptions::new();
+  options.set_color_output(gif::ColorOutput::Indexed);
+  let mut decoder = options.read_info(source).ok()?;
+  let mut frames = vec![];
+  while let Some(frame) = decoder.read_next_frame().ok()? {
+    frames.push(frame.clone());
+  }
+  let mut content = vec![];
+  {
+    let mut encoder = gif::Encoder::new(
+      &mut content,
+      decoder.width(),
+      decoder.height(),
+      decoder.global_palette().unwrap_or_default(),
+    )
+    .ok()?;
+    if frames.len() > 1 {
+      encoder.set_repeat(decoder.repeat()).ok()?;
+    }
+    for frame in &frames {
+      encoder.write_frame(frame).ok()?;
+    }
+  }
+  Some(content)
+}
+
+#[cfg(test)]
+mod test {
+  use std::io::Cursor;
+
+  use rspack_core::AssetGeneratorImageOptions;
+
+  use super::{process, strip_jpeg_markers, ImageFormat, ImageOutput, ImageQuery, ProcessedImage};
+
+  #[test]
+  fn image_query() {
+    assert_eq!(
+      ImageQuery::parse("?width=320,640&format=webp").expect("should parse"),
+      ImageQuery {
+        widths: vec![320, 640],
+        format: Some(ImageFormat::Webp),
+      }
+    );
+    assert_eq!(
+      ImageQuery::parse("?foo&w=100").expect("should parse"),
+      ImageQuery {
+        widths: vec![100],
+        format: None,
+      }
+    );
+    assert!(ImageQuery::parse("?width=0").is_err());
+    assert!(ImageQuery::parse("?format=bmp").is_err());
+  }
+
+  #[test]
+  fn file_name() {
+    let output = ImageOutput {
+      width: 320,
+      height: 240,
+      content: vec![],
+    };
+    let mut image = ProcessedImage {
+      format: ImageFormat::Webp,
+      converted: true,
+      responsive: true,
+      outputs: vec![],
+    };
+    assert_eq!(
+      image.file_name("images/photo.png?width=320&format=webp", &output),
+      "images/photo-320w.webp?width=320&format=webp"
+    );
+    image.converted = false;
+    image.responsive = false;
+    assert_eq!(image.file_name("a.b/.photo", &output), "a.b/.photo.webp");
+    assert_eq!(image.file_name("photo.PNG", &output), "photo.PNG");
+  }
+
+  #[test]
+  fn strip_malformed_jpeg() {
+    // comment segment with a length shorter than the length field itself
+    assert_eq!(
+      strip_jpeg_markers(&[0xFF, 0xD8, 0xFF, 0xFE, 0x00, 0x01, 0xFF, 0xD9]),
+      None
+    );
+    // truncated segment
+    assert_eq!(
+      strip_jpeg_markers(&[0xFF, 0xD8, 0xFF, 0xE1, 0x00, 0x10, b'E']),
+      None
+    );
+    assert_eq!(
+      strip_jpeg_markers(&[0xFF, 0xD8, 0xFF, 0xFE, 0x00, 0x03, b'x', 0xFF, 0xD9]),
+      Some(vec![0xFF, 0xD8, 0xFF, 0xD9])
+    );
+  }
+
+  #[test]
+  fn skip_other_assets() {
+    let options = AssetGeneratorImageOptions::default();
+    assert!(
+      process(b"<svg></svg>", Some("?width=abc&format=bmp"), &options)
+        .expect("should not parse the query")
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn resize_and_convert() {
+    let mut png = vec![];
+    image::RgbImage::from_fn(64, 32, |x, y| image::Rgb([x as u8 * 4, y as u8 * 8, 128]))
+      .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+      .expect("should encode");
+    let image = process(
+      &png,
+      Some("?width=16,32,128&format=webp"),
+      &AssetGeneratorImageOptions::default(),
+    )
+    .expect("should process")
+    .expect("should be an image");
+    assert!(image.converted && image.responsive);
+    assert_eq!(
+      image
+        .outputs
+        .iter()
+        .map(|output| (output.width, output.height))
+        .collect::<Vec<_>>(),
+      vec![(16, 8), (32, 16), (64, 32)]
+    );
+    assert!(image
+      .outputs
+      .iter()
+      .all(|output| &output.content[8..12] == b"WEBP"));
+  }
+}
@@ -1,5 +1,8 @@
 #![feature(let_chains)]
 
+mod data_url;
+mod images;
+
 use std::{collections::HashMap, hash::Hash};
 
 use async_trait::async_trait;
@@ -8,18 +11,24 @@ use rkyv::{from_bytes, to_bytes, AlignedVec, Archive, Deserialize, Serialize};
 use rspack_core::{
   rspack_sources::{BoxSource, RawSource, SourceExt},
   tree_shaking::visitor::OptimizeAnalyzeResult,
-  AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetParserDataUrl, BuildExtraDataType,
-  BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ChunkUkey, CodeGenerationDataAssetInfo,
-  CodeGenerationDataFilename, CodeGenerationDataUrl, Compilation, CompilationRenderManifest,
-  CompilerOptions, GenerateContext, Module, ModuleGraph, NormalModule, ParseContext,
-  ParserAndGenerator, PathData, Plugin, RenderManifestEntry, ResourceData, RuntimeGlobals,
-  SourceType, NAMESPACE_OBJECT_EXPORT,
+  AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetGeneratorImageOptions,
+  AssetGeneratorTransform, AssetGeneratorTransformFnArgs, AssetInfo, AssetParserDataUrl,
+  AssetParserDataUrlFnArgs, BuildExtraDataType, BuildMetaDefaultObject, BuildMetaExportsType,
+  ChunkGraph, ChunkUkey, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
+  CodeGenerationDataUrl, Compilation, CompilationRenderManifest, CompilerOptions, GenerateContext,
+  Module, ModuleGraph, NormalModule, ParseContext, ParserAndGenerator, PathData, Plugin,
+  RenderManifestEntry, ResourceData, RuntimeGlobals, SourceType, NAMESPACE_OBJECT_EXPORT,
 };
 use rspack_error::{error, Diagnostic, IntoTWithDiagnosticArray, Result};
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::identifier::make_paths_relative;
 
+use crate::{
+  data_url::{SVG_ENCODING, UTF8_ENCODING},
+  images::ProcessedImage,
+};
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct AssetPlugin;
@@ -64,35 +73,65 @@ impl CanonicalizedDataUrlOption {
   }
 }
 
+/// Resized or converted images emitted along with the asset.
+#[derive(Debug, Clone)]
+struct CodeGenerationDataImageVariants {
+  inner: Vec<(String, AssetInfo, BoxSource)>,
+}
+
 #[derive(Debug)]
 pub struct AssetParserAndGenerator {
   emit: bool,
   data_url: DataUrlOptions,
+  image: Option<AssetGeneratorImageOptions>,
+  transform: Option<AssetGeneratorTransform>,
   parsed_asset_config: Option<CanonicalizedDataUrlOption>,
+  parsed_image: Option<ProcessedImage>,
 }
 
 impl AssetParserAndGenerator {
-  pub fn with_auto(option: Option<AssetParserDataUrl>, emit: bool) -> Self {
+  pub fn with_auto(
+    option: Option<AssetParserDataUrl>,
+    emit: bool,
+    image: Option<AssetGeneratorImageOptions>,
+    transform: Option<AssetGeneratorTransform>,
+  ) -> Self {
     Self {
       emit,
       data_url: DataUrlOptions::Auto(option),
+      image,
+      transform,
       parsed_asset_config: None,
+      parsed_image: None,
     }
   }
 
-  pub fn with_inline() -> Self {
+  pub fn with_inline(
+    image: Option<AssetGeneratorImageOptions>,
+    transform: Option<AssetGeneratorTransform>,
+  ) -> Self {
     Self {
       emit: false,
       data_url: DataUrlOptions::Inline(true),
+      image,
+      transform,
       parsed_asset_config: None,
+      parsed_image: None,
     }
   }
 
-  pub fn with_resource(emit: bool) -> Self {
+  pub fn with_resource(
+    emit: bool,
+    image: Option<AssetGeneratorImageOptions>,
+    transform: Option<AssetGeneratorTransform>,
+  ) -> Self {
     Self {
       emit,
       data_url: DataUrlOptions::Inline(false),
+      image,
+      transform,
       parsed_asset_config: None,
+      parsed_image: None,
     }
   }
 
@@ -100,7 +139,10 @@ impl AssetParserAndGenerator {
     Self {
       emit: false,
       data_url: DataUrlOptions::Source,
+      image: None,
+      transform: None,
       parsed_asset_config: None,
+      parsed_image: None,
     }
   }
 
@@ -141,6 +183,11 @@ impl AssetParserAndGenerator {
     {
       return Ok(mimetype.to_owned());
     }
+    if let Some(image) = &self.parsed_image
+      && image.converted
+    {
+      return Ok(image.format.mimetype().to_owned());
+    }
     if let Some(mimetype) = &resource_data.mimetype
       && let Some(parameters) = &resource_data.parameters
     {
@@ -189,9 +236,32 @@ impl AssetParserAndGenerator {
     if encoding == DEFAULT_ENCODING {
       return Ok(rspack_base64::encode_to_string(source.buffer()));
     }
+    if encoding == UTF8_ENCODING || encoding == SVG_ENCODING {
+      let buffer = source.buffer();
+      let text = std::str::from_utf8(&buffer).map_err(|_| {
+        error!(
+          "{:?} is not a valid UTF-8 text, which is required by the {encoding} encoding",
+          resource_data.resource_path
+        )
+      })?;
+      return Ok(if encoding == SVG_ENCODING {
+        data_url::encode_svg(text)
+      } else {
+        data_url::encode_utf8(text)
+      });
+    }
     Err(error!("Unsupported encoding {encoding}"))
   }
 
+  /// Parameters of the data url after the mimetype.
+  fn get_encoding_parameters(&self, encoding: &str) -> String {
+    match encoding {
+      "" | SVG_ENCODING => String::new(),
+      UTF8_ENCODING => String::from(";charset=utf-8"),
+      _ => format!(";{encoding}"),
+    }
+  }
+
   fn get_source_file_name(&self, module: &NormalModule, compilation: &Compilation) -> String {
     let relative = make_paths_relative(
       compilation.options.context.as_ref(),
@@ -205,6 +275,42 @@ impl AssetParserAndGenerator {
     }
     relative
   }
+
+  /// Exports the url, or an object for `srcset` when the image is resized by the `width` query.
+  fn export_urls(&self, urls: Vec<String>) -> String {
+    let Some(image) = self.parsed_image.as_ref().filter(|image| image.responsive) else {
+      return urls
+        .into_iter()
+        .next()
+        .expect("should have the url of the asset");
+    };
+    let main = &image.outputs[0];
+    let images = image
+      .outputs
+      .iter()
+      .zip(&urls)
+      .map(|(output, url)| {
+        format!(
+          "{{ src: {url}, width: {}, height: {} }}",
+          output.width, output.height
+        )
+      })
+      .collect::<Vec<_>>();
+    let src_set = image
+      .outputs
+      .iter()
+      .zip(&urls)
+      .map(|(output, url)| format!(r#"{url} + " {}w""#, output.width))
+      .collect::<Vec<_>>();
+    format!(
+      r#"{{ src: {}, srcSet: [{}].join(", "), width: {}, height: {}, images: [{}], toString() {{ return this.src; }} }}"#,
+      urls[0],
+      src_set.join(", "),
+      main.width,
+      main.height,
+      images.join(", ")
+    )
+  }
 }
 
 // Webpack's default parser.dataUrlCondition.maxSize
@@ -277,30 +383,66 @@ impl ParserAndGenerator for AssetParserAndGenerator {
     build_info.strict = true;
     build_meta.exports_type = BuildMetaExportsType::Default;
     build_meta.default_object = BuildMetaDefaultObject::False;
+
+    let source = if let Some(transform) = &self.transform {
+      let content = (transform.0)(AssetGeneratorTransformFnArgs {
+        content: source.buffer().to_vec(),
+        filename: parse_context
+          .resource_data
+          .resource_path
+          .to_string_lossy()
+          .to_string(),
+      })?;
+      RawSource::from(content).boxed()
+    } else {
+      source
+    };
+
+    self.parsed_image = None;
+    let source = if let Some(options) = &self.image
+      && let Some(mut image) = images::process(
+        &source.buffer(),
+        parse_context.resource_data.resource_query.as_deref(),
+        options,
+      )? {
+      // The first output replaces the source, so the hash and the data url condition follow it.
+      let content = std::mem::take(&mut image.outputs[0].content);
+      self.parsed_image = Some(image);
+      RawSource::from(content).boxed()
+    } else {
+      source
+    };
     let size = source.size();
 
     self.parsed_asset_config = match &self.data_url {
       DataUrlOptions::Source => Some(CanonicalizedDataUrlOption::Source),
       DataUrlOptions::Inline(val) => Some(CanonicalizedDataUrlOption::Asset(*val)),
       DataUrlOptions::Auto(option) => {
-        let limit_size = parse_context
+        let condition = parse_context
           .module_parser_options
-          .and_then(|x| {
-            x.get_asset()
-              .and_then(|x| x.data_url_condition.as_ref())
-              .and_then(|x| match x {
-                AssetParserDataUrl::Options(x) => x.max_size,
-              })
-          })
-          .or_else(|| {
-            option.as_ref().and_then(|x| match x {
-              AssetParserDataUrl::Options(x) => x.max_size,
-            })
-          })
-          .unwrap_or(DEFAULT_MAX_SIZE);
-        Some(CanonicalizedDataUrlOption::Asset(
-          size <= limit_size as usize,
-        ))
+          .and_then(|x| x.get_asset().and_then(|x| x.data_url_condition.as_ref()))
+          .into_iter()
+          .chain(option)
+          .find(|x| match x {
+            AssetParserDataUrl::Options(x) => x.max_size.is_some(),
+            AssetParserDataUrl::Func(_) => true,
+          });
+        let is_inline = match condition {
+          Some(AssetParserDataUrl::Func(condition)) => condition(AssetParserDataUrlFnArgs {
+            source: source.buffer().to_vec(),
+            filename: parse_context
+              .resource_data
+              .resource_path
+              .to_string_lossy()
+              .to_string(),
+            issuer: parse_context.module_issuer.map(ToOwned::to_owned),
+          })?,
+          Some(AssetParserDataUrl::Options(x)) => {
+            size <= x.max_size.unwrap_or(DEFAULT_MAX_SIZE) as usize
+          }
+          None => size <= DEFAULT_MAX_SIZE as usize,
+        };
+        Some(CanonicalizedDataUrlOption::Asset(is_inline))
       }
     };
     let analyze_result = OptimizeAnalyzeResult::default();
@@ -353,11 +495,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
             let encoded_content = self.get_encoded_content(resource_data, &encoding, source)?;
             encoded_source = format!(
               r#"data:{mimetype}{},{encoded_content}"#,
-              if encoding.is_empty() {
-                String::new()
-              } else {
-                format!(";{encoding}")
-              }
+              self.get_encoding_parameters(&encoding)
             );
           }
 
@@ -365,7 +503,21 @@ impl ParserAndGenerator for AssetParserAndGenerator {
             .data
             .insert(CodeGenerationDataUrl::new(encoded_source.clone()));
 
-          serde_json::to_string(&encoded_source).map_err(|e| error!(e.to_string()))?
+          let mut urls = vec![encoded_source];
+          if let Some(image) = &self.parsed_image {
+            urls.extend(image.outputs.iter().skip(1).map(|output| {
+              format!(
+                "data:{};base64,{}",
+                image.format.mimetype(),
+                rspack_base64::encode_to_string(&output.content)
+              )
+            }));
+          }
+          let urls = urls
+            .iter()
+            .map(|url| serde_json::to_string(url).map_err(|e| error!(e.to_string())))
+            .collect::<Result<Vec<_>>>()?;
+          self.export_urls(urls)
         } else if parsed_asset_config.is_resource() {
           // Use [Rule.generator.filename] if it is set, otherwise use [output.assetModuleFilename].
           let asset_filename_template = generate_context
@@ -373,33 +525,62 @@ impl ParserAndGenerator for AssetParserAndGenerator {
             .and_then(|x| x.asset_filename())
             .unwrap_or(&compilation.options.output.asset_module_filename);
 
-          let contenthash = self.hash_for_source(source, &compilation.options);
-          let contenthash = contenthash.rendered(compilation.options.output.hash_digest_length);
-
           let source_file_name = self.get_source_file_name(normal_module, compilation);
-          let (filename, mut asset_info) = compilation.get_asset_path_with_info(
-            asset_filename_template,
-            PathData::default()
-              .module(module)
-              .chunk_graph(&generate_context.compilation.chunk_graph)
-              .content_hash(contenthash)
-              .hash(contenthash)
-              .filename(&source_file_name),
-          )?;
-
-          let asset_path = if let Some(public_path) = generate_context
+          let get_asset_path = |source: &BoxSource, file_name: &str| {
+            let contenthash = self.hash_for_source(source, &compilation.options);
+            let contenthash = contenthash.rendered(compilation.options.output.hash_digest_length);
+            compilation.get_asset_path_with_info(
+              asset_filename_template,
+              PathData::default()
+                .module(module)
+                .chunk_graph(&generate_context.compilation.chunk_graph)
+                .content_hash(contenthash)
+                .hash(contenthash)
+                .filename(file_name),
+            )
+          };
+          let public_path = generate_context
             .module_generator_options
-            .and_then(|x| x.asset_public_path())
-          {
-            let public_path = public_path.render(compilation, &filename);
-            serde_json::to_string(&format!("{public_path}{filename}"))
-              .map_err(|e| error!(e.to_string()))?
-          } else {
+            .and_then(|x| x.asset_public_path());
+          let mut public_path_required = false;
+          let mut to_url = |filename: &str| -> Result<String> {
+            if let Some(public_path) = public_path {
+              let public_path = public_path.render(compilation, filename);
+              serde_json::to_string(&format!("{public_path}{filename}"))
+                .map_err(|e| error!(e.to_string()))
+            } else {
+              public_path_required = true;
+              Ok(format!(
+                r#"{} + "{}""#,
+                RuntimeGlobals::PUBLIC_PATH,
+                filename
+              ))
+            }
+          };
+
+          let main_file_name = match &self.parsed_image {
+            Some(image) => image.file_name(&source_file_name, &image.outputs[0]),
+            None => source_file_name.clone(),
+          };
+          let (filename, mut asset_info) = get_asset_path(source, &main_file_name)?;
+          let mut urls = vec![to_url(&filename)?];
+          let mut variants = vec![];
+          if let Some(image) = &self.parsed_image {
+            for output in image.outputs.iter().skip(1) {
+              let variant_source = RawSource::from(output.content.clone()).boxed();
+              let (variant_filename, mut variant_info) =
+                get_asset_path(&variant_source, &image.file_name(&source_file_name, output))?;
+              variant_info.set_source_filename(source_file_name.clone());
+              urls.push(to_url(&variant_filename)?);
+              asset_info.related.variants.push(variant_filename.clone());
+              variants.push((variant_filename, variant_info, variant_source));
+            }
+          }
+          if public_path_required {
             generate_context
               .runtime_requirements
               .insert(RuntimeGlobals::PUBLIC_PATH);
-            format!(r#"{} + "{}""#, RuntimeGlobals::PUBLIC_PATH, filename)
-          };
+          }
           asset_info.set_source_filename(source_file_name);
 
           generate_context
@@ -415,8 +596,13 @@ impl ParserAndGenerator for AssetParserAndGenerator {
           generate_context
             .data
             .insert(CodeGenerationDataAssetInfo::new(asset_info));
+          if !variants.is_empty() {
+            generate_context
+              .data
+              .insert(CodeGenerationDataImageVariants { inner: variants });
+          }
 
-          asset_path
+          self.export_urls(urls)
         } else if parsed_asset_config.is_source() {
           format!(r"{:?}", source.source())
         } else {
@@ -459,14 +645,18 @@ impl ParserAndGenerator for AssetParserAndGenerator {
   fn store(&self, extra_data: &mut HashMap<BuildExtraDataType, AlignedVec>) {
     extra_data.insert(
       BuildExtraDataType::AssetParserAndGenerator,
-      to_bytes::<_, 256>(&self.parsed_asset_config).expect("Failed to store extra data"),
+      to_bytes::<_, 256>(&(self.parsed_asset_config.clone(), self.parsed_image.clone()))
+        .expect("Failed to store extra data"),
     );
   }
 
   fn resume(&mut self, extra_data: &HashMap<BuildExtraDataType, AlignedVec>) {
     if let Some(data) = extra_data.get(&BuildExtraDataType::AssetParserAndGenerator) {
-      self.parsed_asset_config = from_bytes::<Option<CanonicalizedDataUrlOption>>(data)
-        .expect("Failed to resume extra data");
+      // Only the parameters of the image variants are stored, `parse` has already regenerated
+      // them from the cached source, so the parsed image is kept.
+      (self.parsed_asset_config, _) =
+        from_bytes::<(Option<CanonicalizedDataUrlOption>, Option<ProcessedImage>)>(data)
+          .expect("Failed to resume extra data");
     }
   }
 
@@ -515,20 +705,30 @@ async fn render_manifest(
           .get::<CodeGenerationDataAssetInfo>()
           .expect("should have asset_info")
           .inner();
-        RenderManifestEntry::new(
+        let mut entries = vec![RenderManifestEntry::new(
           source.clone(),
           asset_filename.to_owned(),
           asset_info.to_owned(),
           true,
           true,
-        )
+        )];
+        if let Some(variants) = code_gen_result
+          .data
+          .get::<CodeGenerationDataImageVariants>()
+        {
+          entries.extend(variants.inner.iter().map(|(filename, info, source)| {
+            RenderManifestEntry::new(source.clone(), filename.clone(), info.clone(), true, true)
+          }));
+        }
+        entries
       });
 
       Ok(result)
     })
-    .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
+    .collect::<Result<Vec<Option<Vec<RenderManifestEntry>>>>>()?
     .into_par_iter()
     .flatten()
+    .flatten()
     .collect::<Vec<RenderManifestEntry>>();
 
   manifest.extend(assets);
@@ -562,17 +762,34 @@ impl Plugin for AssetPlugin {
         let emit: Option<bool> = generator_options
           .and_then(|x| x.get_asset())
           .and_then(|x| x.emit);
+        let image = generator_options
+          .and_then(|x| x.get_asset())
+          .and_then(|x| x.image.clone());
+        let transform = generator_options
+          .and_then(|x| x.get_asset())
+          .and_then(|x| x.transform.clone());
 
         Box::new(AssetParserAndGenerator::with_auto(
           data_url_condition.clone(),
           emit.unwrap_or(true),
+          image,
+          transform,
         ))
       }),
     );
 
     ctx.context.register_parser_and_generator_builder(
       rspack_core::ModuleType::AssetInline,
-      Box::new(|_, _| Box::new(AssetParserAndGenerator::with_inline())),
+      Box::new(|_, generator_options| {
+        let image = generator_options
+          .and_then(|x| x.get_asset_inline())
+          .and_then(|x| x.image.clone());
+        let transform = generator_options
+          .and_then(|x| x.get_asset_inline())
+          .and_then(|x| x.transform.clone());
+
+        Box::new(AssetParserAndGenerator::with_inline(image, transform))
+      }),
     );
 
     ctx.context.register_parser_and_generator_builder(
@@ -581,8 +798,18 @@ impl Plugin for AssetPlugin {
         let emit = generator_options
           .and_then(|x| x.get_asset_resource())
           .and_then(|x| x.emit);
+        let image = generator_options
+          .and_then(|x| x.get_asset_resource())
+          .and_then(|x| x.image.clone());
+        let transform = generator_options
+          .and_then(|x| x.get_asset_resource())
+          .and_then(|x| x.transform.clone());
 
-        Box::new(AssetParserAndGenerator::with_resource(emit.unwrap_or(true)))
+        Box::new(AssetParserAndGenerator::with_resource(
+          emit.unwrap_or(true),
+          image,
+          transform,
+        ))
       }),
     );
 
@@ -61,7 +61,8 @@ impl DependencyTemplate for CssLocalIdentDependency {
     source: &mut TemplateReplaceSource,
     _code_generatable_context: &mut TemplateContext,
   ) {
-    source.replace(self.start, self.end, &self.local_ident, None);
+    // keep the original name in the source map, the renamed ident is meaningless when debugging
+    source.replace(self.start, self.end, &self.local_ident, Some(&self.name));
   }
 
   fn dependency_id(&self) -> Option<DependencyId> {
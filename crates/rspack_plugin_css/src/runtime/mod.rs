@@ -5,7 +5,9 @@ use rspack_core::{
   RuntimeModuleStage,
 };
 use rspack_identifier::Identifier;
-use rspack_plugin_runtime::{chunk_has_css, get_chunk_runtime_requirements, stringify_chunks};
+use rspack_plugin_runtime::{
+  chunk_has_css, get_chunk_runtime_requirements, stringify_chunks, CreateLinkData, RuntimePlugin,
+};
 use rspack_util::source_map::SourceMapKind;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -99,12 +101,17 @@ impl RuntimeModule for CssLoadingRuntimeModule {
         "".to_string()
       };
 
+      let mut create_link = CreateLinkData {
+        code: cross_origin_content,
+        chunk: chunk_ukey,
+      };
+      RuntimePlugin::get_compilation_hooks(compilation)
+        .create_link
+        .call(compilation, &mut create_link)?;
+
       source.add(RawSource::from(
         include_str!("./css_loading.js")
-          .replace(
-            "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-            &cross_origin_content,
-          )
+          .replace("__CROSS_ORIGIN_LOADING_PLACEHOLDER__", &create_link.code)
           .replace("__UNIQUE_NAME__", unique_name),
       ));
 
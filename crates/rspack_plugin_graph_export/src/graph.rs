@@ -0,0 +1,407 @@
+use std::collections::VecDeque;
+
+use rspack_core::{
+  runtime_to_string, AsyncDependenciesBlockIdentifier, ChunkGroupUkey, ChunkUkey, Compilation,
+  DependenciesBlock, DependencyId, ModuleGraph, ModuleIdentifier, RuntimeSpec,
+};
+use rspack_identifier::IdentifierMap;
+use rspack_regex::RspackRegex;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+  Module,
+  Block,
+  Chunk,
+  ChunkGroup,
+}
+
+impl NodeKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      NodeKind::Module => "module",
+      NodeKind::Block => "block",
+      NodeKind::Chunk => "chunk",
+      NodeKind::ChunkGroup => "chunk_group",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+  /// A module or an async block depends on a module
+  Dependency,
+  /// A module or an async block contains an async block
+  Block,
+  /// An async block is loaded by a chunk group
+  BlockChunkGroup,
+  /// A chunk group is a child of another chunk group
+  ChildChunkGroup,
+  /// A chunk belongs to a chunk group
+  Chunk,
+  /// A module belongs to a chunk
+  ChunkModule,
+}
+
+impl EdgeKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      EdgeKind::Dependency => "dependency",
+      EdgeKind::Block => "block",
+      EdgeKind::BlockChunkGroup => "block_chunk_group",
+      EdgeKind::ChildChunkGroup => "child_chunk_group",
+      EdgeKind::Chunk => "chunk",
+      EdgeKind::ChunkModule => "chunk_module",
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct Node {
+  pub id: String,
+  pub kind: NodeKind,
+  pub label: String,
+  pub attributes: Vec<(&'static str, String)>,
+}
+
+#[derive(Debug)]
+pub struct Edge {
+  pub source: String,
+  pub target: String,
+  pub kind: EdgeKind,
+  pub attributes: Vec<(&'static str, String)>,
+}
+
+/// The module graph and the chunk graph reachable from an entry.
+#[derive(Debug)]
+pub struct Graph {
+  pub name: String,
+  pub nodes: Vec<Node>,
+  pub edges: Vec<Edge>,
+}
+
+pub struct GraphBuilder<'a> {
+  compilation: &'a Compilation,
+  module_graph: ModuleGraph<'a>,
+  module_filter: Option<&'a RspackRegex>,
+  runtime: Option<RuntimeSpec>,
+  modules: IdentifierMap<Option<String>>,
+  blocks: HashMap<AsyncDependenciesBlockIdentifier, String>,
+  chunks: HashMap<ChunkUkey, String>,
+  chunk_groups: HashMap<ChunkGroupUkey, String>,
+  graph: Graph,
+}
+
+impl<'a> GraphBuilder<'a> {
+  pub fn new(
+    compilation: &'a Compilation,
+    name: &str,
+    module_filter: Option<&'a RspackRegex>,
+  ) -> Self {
+    Self {
+      compilation,
+      module_graph: compilation.get_module_graph(),
+      module_filter,
+      runtime: None,
+      modules: Default::default(),
+      blocks: Default::default(),
+      chunks: Default::default(),
+      chunk_groups: Default::default(),
+      graph: Graph {
+        name: name.to_string(),
+        nodes: vec![],
+        edges: vec![],
+      },
+    }
+  }
+
+  /// Returns `None` if the entry doesn't exist.
+  pub fn build(mut self) -> Option<Graph> {
+    let entry = self.compilation.entries.get(&self.graph.name)?;
+    let entrypoint = self.compilation.entrypoints.get(&self.graph.name).copied();
+    if let Some(entrypoint) = entrypoint {
+      let runtime_chunk = self
+        .compilation
+        .chunk_group_by_ukey
+        .expect_get(&entrypoint)
+        .get_runtime_chunk(&self.compilation.chunk_group_by_ukey);
+      self.runtime = Some(
+        self
+          .compilation
+          .chunk_by_ukey
+          .expect_get(&runtime_chunk)
+          .runtime
+          .clone(),
+      );
+    }
+
+    let mut queue: VecDeque<ModuleIdentifier> = entry
+      .all_dependencies()
+      .filter_map(|dep| self.module_graph.module_identifier_by_dependency_id(dep))
+      .copied()
+      .collect();
+    let mut visited: HashSet<ModuleIdentifier> = queue.iter().copied().collect();
+    while let Some(module) = queue.pop_front() {
+      for target in self.add_module_dependencies(module) {
+        if visited.insert(target) {
+          queue.push_back(target);
+        }
+      }
+    }
+
+    if let Some(entrypoint) = entrypoint {
+      self.add_chunk_groups(entrypoint);
+    }
+    Some(self.graph)
+  }
+
+  /// Adds the module with its dependencies and async blocks, returns the referenced modules.
+  fn add_module_dependencies(
+    &mut self,
+    module_identifier: ModuleIdentifier,
+  ) -> Vec<ModuleIdentifier> {
+    let Some(module) = self.module_graph.module_by_identifier(&module_identifier) else {
+      return vec![];
+    };
+    let dependencies = module.get_dependencies().to_vec();
+    let block_ids = module.get_blocks().to_vec();
+    let source = self.module_node(module_identifier);
+    let mut targets = self.add_dependencies(source.as_deref(), &dependencies);
+    let mut blocks: VecDeque<_> = block_ids
+      .into_iter()
+      .map(|block| (source.clone(), block))
+      .collect();
+    while let Some((parent, block_id)) = blocks.pop_front() {
+      let Some(block) = self.module_graph.block_by_id(&block_id) else {
+        continue;
+      };
+      let dependencies = block.get_dependencies().to_vec();
+      let child_blocks = block.get_blocks().to_vec();
+      // blocks of the modules excluded by the filter are only traversed
+      let block_node = parent.is_some().then(|| self.block_node(block_id));
+      if let Some(parent) = &parent
+        && let Some(block_node) = &block_node
+      {
+        self.add_edge(parent, block_node, EdgeKind::Block, vec![]);
+      }
+      targets.extend(self.add_dependencies(block_node.as_deref(), &dependencies));
+      if let Some(block_node) = &block_node
+        && let Some(chunk_group) = self
+          .compilation
+          .chunk_graph
+          .get_block_chunk_group(&block_id, &self.compilation.chunk_group_by_ukey)
+      {
+        let chunk_group_node = self.chunk_group_node(chunk_group.ukey);
+        self.add_edge(
+          block_node,
+          &chunk_group_node,
+          EdgeKind::BlockChunkGroup,
+          vec![],
+        );
+      }
+      blocks.extend(
+        child_blocks
+          .into_iter()
+          .map(|block| (block_node.clone(), block)),
+      );
+    }
+    targets
+  }
+
+  fn add_dependencies(
+    &mut self,
+    source: Option<&str>,
+    dependencies: &[DependencyId],
+  ) -> Vec<ModuleIdentifier> {
+    let mut targets = vec![];
+    for dependency_id in dependencies {
+      let Some(connection) = self.module_graph.connection_by_dependency(dependency_id) else {
+        continue;
+      };
+      let target = *connection.module_identifier();
+      targets.push(target);
+      let Some(source) = source else {
+        continue;
+      };
+      let mut attributes = vec![];
+      if let Some(dependency) = self.module_graph.dependency_by_id(dependency_id) {
+        attributes.push(("dependency_type", dependency.dependency_type().to_string()));
+        if let Some(dependency) = dependency.as_module_dependency() {
+          attributes.push(("request", dependency.user_request().to_string()));
+        }
+      }
+      attributes.push((
+        "active",
+        connection
+          .is_active(&self.module_graph, self.runtime.as_ref())
+          .to_string(),
+      ));
+      if let Some(target_node) = self.module_node(target) {
+        self.add_edge(source, &target_node, EdgeKind::Dependency, attributes);
+      }
+    }
+    targets
+  }
+
+  fn add_chunk_groups(&mut self, entrypoint: ChunkGroupUkey) {
+    let chunk_group_by_ukey = &self.compilation.chunk_group_by_ukey;
+    let mut queue = VecDeque::from([entrypoint]);
+    let mut visited = HashSet::from_iter([entrypoint]);
+    while let Some(ukey) = queue.pop_front() {
+      let chunk_group = chunk_group_by_ukey.expect_get(&ukey);
+      let chunk_group_node = self.chunk_group_node(ukey);
+      for chunk in &chunk_group.chunks {
+        let chunk_node = self.chunk_node(*chunk);
+        self.add_edge(&chunk_group_node, &chunk_node, EdgeKind::Chunk, vec![]);
+      }
+      for child in chunk_group
+        .children_iterable()
+        .chain(chunk_group.async_entrypoints_iterable())
+      {
+        let child_node = self.chunk_group_node(*child);
+        self.add_edge(
+          &chunk_group_node,
+          &child_node,
+          EdgeKind::ChildChunkGroup,
+          vec![],
+        );
+        if visited.insert(*child) {
+          queue.push_back(*child);
+        }
+      }
+    }
+  }
+
+  /// Returns `None` if the module is excluded by the filter.
+  fn module_node(&mut self, module_identifier: ModuleIdentifier) -> Option<String> {
+    if let Some(id) = self.modules.get(&module_identifier) {
+      return id.clone();
+    }
+    let module = self.module_graph.module_by_identifier(&module_identifier)?;
+    let label = module
+      .readable_identifier(&self.compilation.options.context)
+      .to_string();
+    if let Some(module_filter) = self.module_filter
+      && !module_filter.test(&label)
+    {
+      self.modules.insert(module_identifier, None);
+      return None;
+    }
+    let id = format!("module{}", self.modules.len());
+    self.graph.nodes.push(Node {
+      id: id.clone(),
+      kind: NodeKind::Module,
+      label,
+      attributes: vec![
+        ("identifier", module_identifier.to_string()),
+        ("module_type", module.module_type().to_string()),
+      ],
+    });
+    self.modules.insert(module_identifier, Some(id.clone()));
+    Some(id)
+  }
+
+  fn block_node(&mut self, block_id: AsyncDependenciesBlockIdentifier) -> String {
+    if let Some(id) = self.blocks.get(&block_id) {
+      return id.clone();
+    }
+    let block = self.module_graph.block_by_id_expect(&block_id);
+    let mut attributes = vec![];
+    if let Some(loc) = block.loc() {
+      attributes.push(("loc", format!("{}-{}", loc.start(), loc.end())));
+    }
+    let id = format!("block{}", self.blocks.len());
+    self.graph.nodes.push(Node {
+      id: id.clone(),
+      kind: NodeKind::Block,
+      label: block
+        .get_group_options()
+        .and_then(|options| options.name())
+        .unwrap_or("async block")
+        .to_string(),
+      attributes,
+    });
+    self.blocks.insert(block_id, id.clone());
+    id
+  }
+
+  fn chunk_node(&mut self, chunk_ukey: ChunkUkey) -> String {
+    if let Some(id) = self.chunks.get(&chunk_ukey) {
+      return id.clone();
+    }
+    let chunk = self.compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+    let id = format!("chunk{}", self.chunks.len());
+    self.graph.nodes.push(Node {
+      id: id.clone(),
+      kind: NodeKind::Chunk,
+      label: chunk
+        .name
+        .clone()
+        .or_else(|| chunk.id.clone())
+        .unwrap_or_else(|| "(anonymous)".to_string()),
+      attributes: vec![
+        ("runtime", runtime_to_string(&chunk.runtime)),
+        (
+          "initial",
+          chunk
+            .can_be_initial(&self.compilation.chunk_group_by_ukey)
+            .to_string(),
+        ),
+        (
+          "files",
+          chunk.files.iter().cloned().collect::<Vec<_>>().join(","),
+        ),
+      ],
+    });
+    self.chunks.insert(chunk_ukey, id.clone());
+
+    let mut modules = self
+      .compilation
+      .chunk_graph
+      .get_chunk_modules(&chunk_ukey, &self.module_graph)
+      .into_iter()
+      .map(|module| module.identifier())
+      .collect::<Vec<_>>();
+    modules.sort_unstable();
+    for module in modules {
+      if let Some(module_node) = self.module_node(module) {
+        self.add_edge(&id, &module_node, EdgeKind::ChunkModule, vec![]);
+      }
+    }
+    id
+  }
+
+  fn chunk_group_node(&mut self, ukey: ChunkGroupUkey) -> String {
+    if let Some(id) = self.chunk_groups.get(&ukey) {
+      return id.clone();
+    }
+    let chunk_group = self.compilation.chunk_group_by_ukey.expect_get(&ukey);
+    let id = format!("chunk_group{}", self.chunk_groups.len());
+    self.graph.nodes.push(Node {
+      id: id.clone(),
+      kind: NodeKind::ChunkGroup,
+      label: chunk_group.name().unwrap_or("(anonymous)").to_string(),
+      attributes: vec![
+        ("entrypoint", chunk_group.kind.is_entrypoint().to_string()),
+        ("initial", chunk_group.is_initial().to_string()),
+      ],
+    });
+    self.chunk_groups.insert(ukey, id.clone());
+    id
+  }
+
+  fn add_edge(
+    &mut self,
+    source: &str,
+    target: &str,
+    kind: EdgeKind,
+    attributes: Vec<(&'static str, String)>,
+  ) {
+    self.graph.edges.push(Edge {
+      source: source.to_string(),
+      target: target.to_string(),
+      kind,
+      attributes,
+    });
+  }
+}
@@ -0,0 +1,112 @@
+#![feature(let_chains)]
+
+mod graph;
+mod render;
+
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, AssetInfo, Compilation, CompilationAsset, CompilationProcessAssets,
+  CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+
+use crate::graph::GraphBuilder;
+use crate::render::{render_dot, render_graphml};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphExportFormat {
+  Dot,
+  GraphML,
+}
+
+impl TryFrom<&str> for GraphExportFormat {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "dot" => Ok(Self::Dot),
+      "graphml" => Ok(Self::GraphML),
+      _ => Err(error!("Unsupported graph export format: {value}")),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct GraphExportRspackPluginOptions {
+  /// Filename of the exported graph, `[name]` is replaced with the entry name
+  pub filename: String,
+  pub format: GraphExportFormat,
+  /// Entries to export, all entries are exported when empty
+  pub entries: Vec<String>,
+  /// Only the modules whose readable identifier matches are exported
+  pub module_filter: Option<RspackRegex>,
+}
+
+/// Emits the module graph and the chunk graph of each entry as Graphviz DOT or GraphML, for
+/// investigating why a module ends up in a chunk.
+#[plugin]
+#[derive(Debug)]
+pub struct GraphExportRspackPlugin {
+  options: GraphExportRspackPluginOptions,
+}
+
+impl GraphExportRspackPlugin {
+  pub fn new(options: GraphExportRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for GraphExportRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let entries: Vec<String> = if self.options.entries.is_empty() {
+    compilation.entries.keys().cloned().collect()
+  } else {
+    self.options.entries.clone()
+  };
+
+  let mut assets = vec![];
+  for entry in entries {
+    let Some(graph) =
+      GraphBuilder::new(compilation, &entry, self.options.module_filter.as_ref()).build()
+    else {
+      continue;
+    };
+    let content = match self.options.format {
+      GraphExportFormat::Dot => render_dot(&graph),
+      GraphExportFormat::GraphML => render_graphml(&graph),
+    };
+    assets.push((self.options.filename.replace("[name]", &entry), content));
+  }
+
+  for (filename, content) in assets {
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawSource::from(content).boxed()),
+        AssetInfo::default().with_development(true),
+      ),
+    );
+  }
+  Ok(())
+}
+
+impl Plugin for GraphExportRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.GraphExportRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
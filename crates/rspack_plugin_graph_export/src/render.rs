@@ -0,0 +1,206 @@
+use std::fmt::Write;
+
+use rustc_hash::FxHashSet as HashSet;
+
+use crate::graph::{EdgeKind, Graph, NodeKind};
+
+fn escape_dot(value: &str) -> String {
+  value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(value: &str) -> String {
+  value
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+pub fn render_dot(graph: &Graph) -> String {
+  let mut out = String::new();
+  let _ = writeln!(out, "digraph \"{}\" {{", escape_dot(&graph.name));
+  out.push_str("  rankdir=LR;\n");
+  for node in &graph.nodes {
+    let shape = match node.kind {
+      NodeKind::Module => "box",
+      NodeKind::Block => "diamond",
+      NodeKind::Chunk => "folder",
+      NodeKind::ChunkGroup => "tab",
+    };
+    let _ = write!(
+      out,
+      "  \"{}\" [label=\"{}\", shape={shape}, kind=\"{}\"",
+      node.id,
+      escape_dot(&node.label),
+      node.kind.as_str()
+    );
+    for (key, value) in &node.attributes {
+      let _ = write!(out, ", {key}=\"{}\"", escape_dot(value));
+    }
+    out.push_str("];\n");
+  }
+  for edge in &graph.edges {
+    let _ = write!(
+      out,
+      "  \"{}\" -> \"{}\" [kind=\"{}\"",
+      edge.source,
+      edge.target,
+      edge.kind.as_str()
+    );
+    for (key, value) in &edge.attributes {
+      let _ = write!(out, ", {key}=\"{}\"", escape_dot(value));
+    }
+    if let Some((_, dependency_type)) = edge
+      .attributes
+      .iter()
+      .find(|(key, _)| *key == "dependency_type")
+    {
+      let _ = write!(out, ", label=\"{}\"", escape_dot(dependency_type));
+    }
+    if edge
+      .attributes
+      .iter()
+      .any(|(key, value)| *key == "active" && value == "false")
+    {
+      out.push_str(", style=dashed");
+    } else if edge.kind != EdgeKind::Dependency {
+      out.push_str(", style=dotted");
+    }
+    out.push_str("];\n");
+  }
+  out.push_str("}\n");
+  out
+}
+
+pub fn render_graphml(graph: &Graph) -> String {
+  let mut node_keys = vec!["kind", "label"];
+  let mut edge_keys = vec!["kind"];
+  let mut seen = HashSet::default();
+  for node in &graph.nodes {
+    for (key, _) in &node.attributes {
+      if seen.insert(("node", *key)) {
+        node_keys.push(key);
+      }
+    }
+  }
+  for edge in &graph.edges {
+    for (key, _) in &edge.attributes {
+      if seen.insert(("edge", *key)) {
+        edge_keys.push(key);
+      }
+    }
+  }
+
+  let mut out = String::new();
+  out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+  out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+  for key in &node_keys {
+    let _ = writeln!(
+      out,
+      "  <key id=\"node_{key}\" for=\"node\" attr.name=\"{key}\" attr.type=\"string\"/>"
+    );
+  }
+  for key in &edge_keys {
+    let _ = writeln!(
+      out,
+      "  <key id=\"edge_{key}\" for=\"edge\" attr.name=\"{key}\" attr.type=\"string\"/>"
+    );
+  }
+  let _ = writeln!(
+    out,
+    "  <graph id=\"{}\" edgedefault=\"directed\">",
+    escape_xml(&graph.name)
+  );
+  for node in &graph.nodes {
+    let _ = writeln!(out, "    <node id=\"{}\">", node.id);
+    let data = [("kind", node.kind.as_str()), ("label", node.label.as_str())];
+    for (key, value) in data.into_iter().chain(
+      node
+        .attributes
+        .iter()
+        .map(|(key, value)| (*key, value.as_str())),
+    ) {
+      let _ = writeln!(
+        out,
+        "      <data key=\"node_{key}\">{}</data>",
+        escape_xml(value)
+      );
+    }
+    out.push_str("    </node>\n");
+  }
+  for (index, edge) in graph.edges.iter().enumerate() {
+    let _ = writeln!(
+      out,
+      "    <edge id=\"edge{index}\" source=\"{}\" target=\"{}\">",
+      edge.source, edge.target
+    );
+    let _ = writeln!(
+      out,
+      "      <data key=\"edge_kind\">{}</data>",
+      edge.kind.as_str()
+    );
+    for (key, value) in &edge.attributes {
+      let _ = writeln!(
+        out,
+        "      <data key=\"edge_{key}\">{}</data>",
+        escape_xml(value)
+      );
+    }
+    out.push_str("    </edge>\n");
+  }
+  out.push_str("  </graph>\n</graphml>\n");
+  out
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::graph::{Edge, Node};
+
+  fn graph() -> Graph {
+    Graph {
+      name: "main".to_string(),
+      nodes: vec![
+        Node {
+          id: "module0".to_string(),
+          kind: NodeKind::Module,
+          label: "./src/index.js".to_string(),
+          attributes: vec![],
+        },
+        Node {
+          id: "module1".to_string(),
+          kind: NodeKind::Module,
+          label: "./src/<\"a\">.js".to_string(),
+          attributes: vec![],
+        },
+      ],
+      edges: vec![Edge {
+        source: "module0".to_string(),
+        target: "module1".to_string(),
+        kind: EdgeKind::Dependency,
+        attributes: vec![
+          ("dependency_type", "esm import".to_string()),
+          ("active", "false".to_string()),
+        ],
+      }],
+    }
+  }
+
+  #[test]
+  fn dot() {
+    let dot = render_dot(&graph());
+    assert!(dot.starts_with("digraph \"main\" {"));
+    assert!(dot.contains(r#""module1" [label="./src/<\"a\">.js", shape=box, kind="module"];"#));
+    assert!(dot.contains(
+      r#""module0" -> "module1" [kind="dependency", dependency_type="esm import", active="false", label="esm import", style=dashed];"#
+    ));
+  }
+
+  #[test]
+  fn graphml() {
+    let graphml = render_graphml(&graph());
+    assert!(graphml.contains("<key id=\"edge_active\" for=\"edge\" attr.name=\"active\""));
+    assert!(graphml.contains("<data key=\"node_label\">./src/&lt;&quot;a&quot;&gt;.js</data>"));
+    assert!(graphml.contains("<edge id=\"edge0\" source=\"module0\" target=\"module1\">"));
+  }
+}
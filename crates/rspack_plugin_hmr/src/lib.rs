@@ -51,13 +51,23 @@ async fn compilation(
 
 #[plugin_hook(CompilationProcessAssets for HotModuleReplacementPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  // the first compilation after a restart compares against the hmr state in records
+  let records = compilation.records.take().or_else(|| {
+    let input_records = compilation.input_records.as_ref()?;
+    let hmr = input_records.hmr.as_ref()?;
+    Some(CompilationRecords::revive(
+      hmr,
+      input_records.hash.as_deref(),
+      compilation,
+    ))
+  });
   let Some(CompilationRecords {
     old_chunks,
     all_old_runtime,
     old_all_modules,
     old_runtime_modules,
     old_hash,
-  }) = compilation.records.take()
+  }) = records
   else {
     return Ok(());
   };
@@ -3,13 +3,13 @@ use std::{collections::HashMap, path::PathBuf, str::FromStr};
 use rspack_core::{Compilation, PublicPath};
 #[cfg(feature = "testing")]
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sugar_path::SugarPath;
 
 use crate::sri::HtmlSriHashFunction;
 
 #[cfg_attr(feature = "testing", derive(JsonSchema))]
-#[derive(Deserialize, Debug, Clone, Copy)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum HtmlInject {
   Head,
@@ -36,7 +36,7 @@ impl FromStr for HtmlInject {
 }
 
 #[cfg_attr(feature = "testing", derive(JsonSchema))]
-#[derive(Deserialize, Debug, Clone, Copy)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum HtmlScriptLoading {
   Blocking,
@@ -63,7 +63,48 @@ impl FromStr for HtmlScriptLoading {
 }
 
 #[cfg_attr(feature = "testing", derive(JsonSchema))]
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HtmlChunkSortMode {
+  /// in the order of the entries
+  #[default]
+  Auto,
+  /// in the order of `chunks`
+  Manual,
+  /// runtime chunks first, then the shared chunks, then the entry chunks
+  Dependency,
+}
+
+impl FromStr for HtmlChunkSortMode {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if s.eq("auto") {
+      Ok(HtmlChunkSortMode::Auto)
+    } else if s.eq("manual") {
+      Ok(HtmlChunkSortMode::Manual)
+    } else if s.eq("dependency") {
+      Ok(HtmlChunkSortMode::Dependency)
+    } else {
+      Err(anyhow::Error::msg(
+        "chunksSortMode in html config only support 'auto', 'manual' or 'dependency'",
+      ))
+    }
+  }
+}
+
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlPageOptions {
+  /// entry injected into the page, defaults to the page name
+  pub entry: Option<String>,
+  /// merged into the shared `template_parameters`
+  pub template_parameters: Option<HashMap<String, String>>,
+}
+
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct HtmlRspackPluginOptions {
   /// emitted file name in output path
@@ -73,6 +114,10 @@ pub struct HtmlRspackPluginOptions {
   pub template: Option<String>,
   pub template_content: Option<String>,
   pub template_parameters: Option<HashMap<String, String>>,
+  /// glob of template files, one page is generated for each of them
+  pub template_glob: Option<String>,
+  /// options of the pages generated from `template_glob`, keyed by the template file stem
+  pub pages: Option<HashMap<String, HtmlPageOptions>>,
   /// `head`, `body`, `false`
   #[serde(default = "default_inject")]
   pub inject: HtmlInject,
@@ -85,6 +130,9 @@ pub struct HtmlRspackPluginOptions {
   /// entry_chunk_name (only entry chunks are supported)
   pub chunks: Option<Vec<String>>,
   pub excluded_chunks: Option<Vec<String>>,
+  /// `auto`, `manual` or `dependency`
+  #[serde(default)]
+  pub chunks_sort_mode: HtmlChunkSortMode,
 
   /// hash func that used in subsource integrity
   /// sha384, sha256 or sha512
@@ -115,11 +163,14 @@ impl Default for HtmlRspackPluginOptions {
       template: None,
       template_content: None,
       template_parameters: None,
+      template_glob: None,
+      pages: None,
       inject: default_inject(),
       public_path: None,
       script_loading: default_script_loading(),
       chunks: None,
       excluded_chunks: None,
+      chunks_sort_mode: HtmlChunkSortMode::default(),
       sri: None,
       minify: false,
       title: None,
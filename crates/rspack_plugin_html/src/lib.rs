@@ -6,4 +6,5 @@ pub mod sri;
 pub mod visitors;
 
 mod plugin;
+mod template;
 pub use plugin::*;
@@ -1,27 +1,29 @@
 use std::{
-  collections::hash_map::DefaultHasher,
+  collections::{hash_map::DefaultHasher, HashMap},
   fs,
   hash::{Hash, Hasher},
   path::{Path, PathBuf},
 };
 
 use anyhow::Context;
-use dojang::dojang::Dojang;
+use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::{
   parse_to_url,
   rspack_sources::{RawSource, SourceExt},
   Compilation, CompilationAsset, CompilationProcessAssets, FilenameTemplate, PathData, Plugin,
 };
-use rspack_error::{AnyhowError, Result};
+use rspack_error::{error, AnyhowError, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::infallible::ResultInfallibleExt as _;
+use sugar_path::SugarPath;
 use swc_html::visit::VisitMutWith;
 
 use crate::{
-  config::{HtmlInject, HtmlRspackPluginOptions},
+  config::{HtmlChunkSortMode, HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading},
   parser::HtmlCompiler,
   sri::{add_sri, create_digest_from_asset},
+  template::render_template,
   visitors::asset::{AssetWriter, HTMLPluginTag},
 };
 
@@ -37,104 +39,279 @@ impl HtmlRspackPlugin {
   }
 }
 
-#[plugin_hook(CompilationProcessAssets for HtmlRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE)]
-async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
-  let config = &self.config;
+struct HtmlPage {
+  content: String,
+  url: String,
+  normalized_template_name: String,
+  chunks: Option<Vec<String>>,
+  template_parameters: Option<HashMap<String, String>>,
+}
 
-  let parser = HtmlCompiler::new(config);
-  let (content, url, normalized_template_name) = if let Some(content) = &config.template_content {
-    (
-      content.clone(),
-      parse_to_url("template_content.html").path().to_string(),
-      "template_content.html".to_string(),
-    )
-  } else if let Some(template) = &config.template {
-    // TODO: support loader query form
-    let resolved_template = path_clean::clean(
-      AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
-    );
+impl HtmlRspackPlugin {
+  fn get_pages(&self, compilation: &mut Compilation) -> Result<Vec<HtmlPage>> {
+    let config = &self.config;
 
-    let content = fs::read_to_string(&resolved_template)
-      .context(format!(
-        "failed to read `{}` from `{}`",
-        resolved_template.display(),
-        &compilation.options.context
-      ))
-      .map_err(AnyhowError::from)?;
+    if let Some(template_glob) = &config.template_glob {
+      return self.get_glob_pages(compilation, template_glob);
+    }
+
+    let (content, url, normalized_template_name) = if let Some(content) = &config.template_content {
+      (
+        content.clone(),
+        parse_to_url("template_content.html").path().to_string(),
+        "template_content.html".to_string(),
+      )
+    } else if let Some(template) = &config.template {
+      // TODO: support loader query form
+      let resolved_template = path_clean::clean(
+        AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
+      );
+      let (content, url) = read_template(compilation, resolved_template)?;
+      (content, url, template.clone())
+    } else {
+      (
+        default_template().to_owned(),
+        parse_to_url("default.html").path().to_string(),
+        "default.html".to_string(),
+      )
+    };
 
-    let url = resolved_template.to_string_lossy().to_string();
-    compilation.file_dependencies.insert(resolved_template);
-
-    (content, url, template.clone())
-  } else {
-    (
-      default_template().to_owned(),
-      parse_to_url("default.html").path().to_string(),
-      "default.html".to_string(),
-    )
-  };
-
-  // process with template parameters
-  let template_result = if let Some(template_parameters) = &self.config.template_parameters {
-    let mut dj = Dojang::new();
-    dj.add(url.clone(), content)
-      .expect("failed to add template");
-    dj.render(&url, serde_json::json!(template_parameters))
-      .expect("failed to render template")
-  } else {
-    content
-  };
-
-  let ast_with_diagnostic = parser.parse_file(&url, template_result)?;
-
-  let (mut current_ast, diagnostic) = ast_with_diagnostic.split_into_parts();
-
-  if !diagnostic.is_empty() {
-    compilation.extend_diagnostics(diagnostic);
+    Ok(vec![HtmlPage {
+      content,
+      url,
+      normalized_template_name,
+      chunks: config.chunks.clone(),
+      template_parameters: config.template_parameters.clone(),
+    }])
   }
-  let included_assets = compilation
-    .entrypoints
-    .keys()
-    .filter(|&entry_name| {
-      let mut included = true;
-      if let Some(included_chunks) = &config.chunks {
-        included = included_chunks.iter().any(|c| c.eq(entry_name));
-      }
-      if let Some(excluded_chunks) = &config.excluded_chunks {
-        included = included && !excluded_chunks.iter().any(|c| c.eq(entry_name));
+
+  fn get_glob_pages(
+    &self,
+    compilation: &mut Compilation,
+    template_glob: &str,
+  ) -> Result<Vec<HtmlPage>> {
+    let config = &self.config;
+    let context = AsRef::<Path>::as_ref(&compilation.options.context).to_path_buf();
+    let pattern = context.join(template_glob);
+
+    // watch the directory so that newly added templates are picked up
+    let base = pattern
+      .ancestors()
+      .find(|dir| !dir.to_string_lossy().contains(['*', '?', '[', '{']))
+      .unwrap_or(&context)
+      .to_path_buf();
+    compilation.context_dependencies.insert(base);
+
+    let templates = glob::glob(&pattern.to_string_lossy())
+      .map_err(|e| error!("invalid templateGlob `{template_glob}`: {e}"))?
+      .filter_map(|entry| entry.ok())
+      .filter(|path| path.is_file())
+      .sorted()
+      .collect::<Vec<_>>();
+    if templates.is_empty() {
+      compilation.push_diagnostic(Diagnostic::warn(
+        "HtmlRspackPlugin".to_string(),
+        format!("no template matches templateGlob `{template_glob}`"),
+      ));
+    }
+
+    // the pages are keyed by the template file stem and emitted to the same `filename`, so every
+    // page must get its own name and output file
+    if templates.len() > 1 && !config.filename.contains("[name]") {
+      return Err(error!(
+        "filename `{}` must contain `[name]` as templateGlob `{template_glob}` matches multiple templates",
+        config.filename
+      ));
+    }
+    let mut names: HashMap<String, &PathBuf> = HashMap::default();
+    for template in &templates {
+      let name = template
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+      if let Some(existing) = names.insert(name.clone(), template) {
+        return Err(error!(
+          "templateGlob `{template_glob}` matches multiple templates named `{name}`: `{}` and `{}`",
+          existing.relative(&context).display(),
+          template.relative(&context).display()
+        ));
       }
-      included
-    })
-    .map(|entry_name| compilation.entrypoint_by_name(entry_name))
-    .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
-    .filter_map(|asset_name| {
-      let asset = compilation.assets().get(&asset_name).expect("TODO:");
-      if asset.info.hot_module_replacement || asset.info.development {
-        None
-      } else {
-        Some((asset_name.clone(), asset))
+    }
+
+    let mut pages = vec![];
+    for resolved_template in templates {
+      let name = resolved_template
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+      let page_options = config.pages.as_ref().and_then(|pages| pages.get(&name));
+      let entry = page_options
+        .and_then(|page| page.entry.clone())
+        .unwrap_or_else(|| name.clone());
+      if !compilation.entrypoints.contains_key(&entry) {
+        compilation.push_diagnostic(Diagnostic::warn(
+          "HtmlRspackPlugin".to_string(),
+          format!("entry `{entry}` of page `{name}` is not found, no chunk is injected"),
+        ));
       }
-    })
-    .collect::<Vec<_>>();
 
-  let mut tags = vec![];
-  // if inject is 'false', don't do anything
-  if !matches!(config.inject, HtmlInject::False) {
-    for (asset_name, asset) in included_assets {
+      let template_parameters = match (
+        &config.template_parameters,
+        page_options.and_then(|page| page.template_parameters.as_ref()),
+      ) {
+        (None, None) => None,
+        (shared, page) => Some(
+          shared
+            .iter()
+            .chain(page)
+            .flatten()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
+        ),
+      };
+
+      let normalized_template_name = resolved_template
+        .relative(&context)
+        .to_string_lossy()
+        .to_string();
+      let (content, url) = read_template(compilation, resolved_template)?;
+      pages.push(HtmlPage {
+        content,
+        url,
+        normalized_template_name,
+        chunks: Some(vec![entry]),
+        template_parameters,
+      });
+    }
+    Ok(pages)
+  }
+
+  /// Returns the assets of the included chunks, and whether the asset is a module which is
+  /// statically imported by an entry chunk in esm output.
+  fn get_included_assets<'a>(
+    &self,
+    compilation: &'a Compilation,
+    chunks: Option<&[String]>,
+  ) -> Vec<(String, &'a CompilationAsset, bool)> {
+    let config = &self.config;
+    let mut entry_names = compilation
+      .entrypoints
+      .keys()
+      .filter(|&entry_name| {
+        let mut included = true;
+        if let Some(included_chunks) = chunks {
+          included = included_chunks.iter().any(|c| c.eq(entry_name));
+        }
+        if let Some(excluded_chunks) = &config.excluded_chunks {
+          included = included && !excluded_chunks.iter().any(|c| c.eq(entry_name));
+        }
+        included
+      })
+      .collect::<Vec<_>>();
+    if let (HtmlChunkSortMode::Manual, Some(chunks)) = (config.chunks_sort_mode, chunks) {
+      entry_names.sort_by_key(|&entry_name| chunks.iter().position(|c| c.eq(entry_name)));
+    }
+    let entrypoints = entry_names
+      .into_iter()
+      .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+      .collect::<Vec<_>>();
+
+    let entry_chunks = entrypoints
+      .iter()
+      .map(|entry| entry.get_entry_point_chunk())
+      .collect::<Vec<_>>();
+    let chunk_ukeys = if matches!(config.chunks_sort_mode, HtmlChunkSortMode::Dependency) {
+      entrypoints
+        .iter()
+        .map(|entry| entry.get_runtime_chunk(&compilation.chunk_group_by_ukey))
+        .filter(|chunk| !entry_chunks.contains(chunk))
+        .chain(
+          entrypoints
+            .iter()
+            .flat_map(|entry| entry.chunks.iter().copied())
+            .filter(|chunk| !entry_chunks.contains(chunk)),
+        )
+        .chain(entry_chunks.iter().copied())
+        .unique()
+        .collect::<Vec<_>>()
+    } else {
+      entrypoints
+        .iter()
+        .flat_map(|entry| entry.chunks.iter().copied())
+        .unique()
+        .collect::<Vec<_>>()
+    };
+
+    // the initial chunks are imported by the entry chunks only when they are loaded as modules
+    let module_scripts = compilation.options.output.module
+      && matches!(config.script_loading, HtmlScriptLoading::Module);
+    chunk_ukeys
+      .iter()
+      .flat_map(|chunk_ukey| {
+        let preload = module_scripts && !entry_chunks.contains(chunk_ukey);
+        compilation
+          .chunk_by_ukey
+          .expect_get(chunk_ukey)
+          .files
+          .iter()
+          .map(move |asset_name| (asset_name, preload))
+      })
+      .filter_map(|(asset_name, preload)| {
+        let asset = compilation.assets().get(asset_name).expect("TODO:");
+        if asset.info.hot_module_replacement || asset.info.development {
+          None
+        } else {
+          Some((asset_name.clone(), asset, preload))
+        }
+      })
+      .collect::<Vec<_>>()
+  }
+
+  fn emit_page(&self, compilation: &mut Compilation, page: HtmlPage) -> Result<()> {
+    let config = &self.config;
+    let parser = HtmlCompiler::new(config);
+    let HtmlPage {
+      content,
+      url,
+      normalized_template_name,
+      chunks,
+      template_parameters,
+    } = page;
+
+    let public_path = config.get_public_path(compilation, &config.filename);
+    let included_assets = self.get_included_assets(compilation, chunks.as_deref());
+
+    // the tags are still exposed to the template when inject is 'false'
+    let script_inject = match config.inject {
+      HtmlInject::False if matches!(config.script_loading, HtmlScriptLoading::Blocking) => {
+        HtmlInject::Body
+      }
+      HtmlInject::False => HtmlInject::Head,
+      inject => inject,
+    };
+    let mut js_files = vec![];
+    let mut css_files = vec![];
+    let mut tags = vec![];
+    for (asset_name, asset, preload) in included_assets {
       if let Some(extension) = Path::new(&asset_name).extension() {
-        let asset_uri = format!(
-          "{}{asset_name}",
-          config.get_public_path(compilation, &self.config.filename),
-        );
+        let asset_uri = format!("{public_path}{asset_name}");
         let mut tag: Option<HTMLPluginTag> = None;
         if extension.eq_ignore_ascii_case("css") {
           tag = Some(HTMLPluginTag::create_style(&asset_uri, HtmlInject::Head));
+          css_files.push(asset_uri);
+        } else if preload
+          && (extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs"))
+        {
+          // initial chunks are imported by the entry chunk, preload them to avoid a waterfall
+          tag = Some(HTMLPluginTag::create_module_preload(&asset_uri));
+          js_files.push(asset_uri);
         } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
           tag = Some(HTMLPluginTag::create_script(
             &asset_uri,
-            config.inject,
+            script_inject,
             &config.script_loading,
-          ))
+          ));
+          js_files.push(asset_uri);
         }
 
         if let Some(tag) = tag {
@@ -142,45 +319,127 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         }
       }
     }
-  }
 
-  // if some plugin changes assets in the same stage after this plugin
-  // both the name and the integrity may be inaccurate
-  if let Some(hash_func) = &config.sri {
-    tags
-      .par_iter_mut()
-      .filter_map(|(tag, asset)| asset.get_source().map(|s| (tag, s)))
-      .for_each(|(tag, asset)| {
-        let sri_value = create_digest_from_asset(hash_func, asset);
-        add_sri(tag, &sri_value);
-      });
+    // if some plugin changes assets in the same stage after this plugin
+    // both the name and the integrity may be inaccurate
+    if let Some(hash_func) = &config.sri {
+      tags
+        .par_iter_mut()
+        .filter_map(|(tag, asset)| asset.get_source().map(|s| (tag, s)))
+        .for_each(|(tag, asset)| {
+          let sri_value = create_digest_from_asset(hash_func, asset);
+          add_sri(tag, &sri_value);
+        });
+    }
+
+    let tags = tags.into_iter().map(|(tag, _)| tag).collect::<Vec<_>>();
+
+    // only the templates with `templateParameters` are rendered, so static templates containing
+    // `<%` are emitted as is
+    let template_result = if let Some(template_parameters) = &template_parameters {
+      let render_tags = |append_to: HtmlInject| {
+        tags
+          .iter()
+          .filter(|tag| tag.append_to == append_to)
+          .map(|tag| tag.to_string())
+          .join("")
+      };
+      let mut context = serde_json::Map::new();
+      for (key, value) in template_parameters {
+        context.insert(key.clone(), value.clone().into());
+      }
+      context.insert(
+        "htmlRspackPlugin".to_string(),
+        serde_json::json!({
+          "files": {
+            "js": js_files,
+            "css": css_files,
+            "publicPath": public_path,
+          },
+          "tags": {
+            "headTags": render_tags(HtmlInject::Head),
+            "bodyTags": render_tags(HtmlInject::Body),
+          },
+          "options": config,
+        }),
+      );
+      match render_template(&normalized_template_name, content, &context.into()) {
+        Ok(result) => result,
+        Err(e) => {
+          compilation.push_diagnostic(e.into());
+          return Ok(());
+        }
+      }
+    } else {
+      content
+    };
+
+    let ast_with_diagnostic = parser.parse_file(&url, template_result)?;
+
+    let (mut current_ast, diagnostic) = ast_with_diagnostic.split_into_parts();
+
+    if !diagnostic.is_empty() {
+      compilation.extend_diagnostics(diagnostic);
+    }
+
+    // if inject is 'false', the tags are placed by the template
+    let injected_tags = if matches!(config.inject, HtmlInject::False) {
+      &[]
+    } else {
+      tags.as_slice()
+    };
+    let mut visitor = AssetWriter::new(config, injected_tags, compilation);
+    current_ast.visit_mut_with(&mut visitor);
+
+    let source = parser.codegen(&mut current_ast)?;
+    let hash = hash_for_source(&source);
+    let html_file_name = FilenameTemplate::from(config.filename.clone());
+    // Use the same filename as template
+    let output_path = compilation
+      .options
+      .output
+      .path
+      .join(normalized_template_name);
+    let (output_path, asset_info) = compilation
+      .get_path_with_info(
+        &html_file_name,
+        PathData::default()
+          .filename(&output_path.to_string_lossy())
+          .content_hash(&hash),
+      )
+      .always_ok();
+    compilation.emit_asset(
+      output_path,
+      CompilationAsset::new(Some(RawSource::from(source).boxed()), asset_info),
+    );
+    Ok(())
   }
+}
 
-  let tags = tags.into_iter().map(|(tag, _)| tag).collect::<Vec<_>>();
-  let mut visitor = AssetWriter::new(config, &tags, compilation);
-  current_ast.visit_mut_with(&mut visitor);
-
-  let source = parser.codegen(&mut current_ast)?;
-  let hash = hash_for_source(&source);
-  let html_file_name = FilenameTemplate::from(config.filename.clone());
-  // Use the same filename as template
-  let output_path = compilation
-    .options
-    .output
-    .path
-    .join(normalized_template_name);
-  let (output_path, asset_info) = compilation
-    .get_path_with_info(
-      &html_file_name,
-      PathData::default()
-        .filename(&output_path.to_string_lossy())
-        .content_hash(&hash),
-    )
-    .always_ok();
-  compilation.emit_asset(
-    output_path,
-    CompilationAsset::new(Some(RawSource::from(source).boxed()), asset_info),
-  );
+fn read_template(
+  compilation: &mut Compilation,
+  resolved_template: PathBuf,
+) -> Result<(String, String)> {
+  let content = fs::read_to_string(&resolved_template)
+    .context(format!(
+      "failed to read `{}` from `{}`",
+      resolved_template.display(),
+      &compilation.options.context
+    ))
+    .map_err(AnyhowError::from)?;
+
+  let url = resolved_template.to_string_lossy().to_string();
+  compilation.file_dependencies.insert(resolved_template);
+  Ok((content, url))
+}
+
+#[plugin_hook(CompilationProcessAssets for HtmlRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let config = &self.config;
+
+  for page in self.get_pages(compilation)? {
+    self.emit_page(compilation, page)?;
+  }
 
   if let Some(favicon) = &self.config.favicon {
     let url = parse_to_url(favicon);
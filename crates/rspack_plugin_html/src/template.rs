@@ -0,0 +1,91 @@
+use std::ops::Range;
+
+use dojang::dojang::Dojang;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_error::{DiagnosticKind, TraceableError};
+use serde_json::Value;
+
+static TEMPLATE_TAG_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"(?s)<%.*?%>").expect("should be valid regex"));
+
+const UNCLOSED_BLOCK_ERROR: &str = "No closing bracket found";
+
+fn render(content: &str, context: Value) -> Result<String, String> {
+  let mut dj = Dojang::new();
+  dj.add("template".to_string(), content.to_string())?;
+  dj.render("template", context)
+}
+
+/// Renders the EJS-like template, the error is reported with the span of the failing tag.
+pub fn render_template(
+  template_name: &str,
+  content: String,
+  context: &Value,
+) -> rspack_error::Result<String> {
+  render(&content, context.clone()).map_err(|message| {
+    let span = locate_error(&content, context).unwrap_or(0..0);
+    let message = message.lines().next().unwrap_or_default().trim();
+    TraceableError::from_file(
+      content,
+      span.start,
+      span.end,
+      "HTML template error".to_string(),
+      format!("failed to render `{template_name}`: {message}"),
+    )
+    .with_kind(DiagnosticKind::Html)
+    .into()
+  })
+}
+
+/// Dojang doesn't report the position of errors, find the first tag that fails when the template
+/// is rendered up to it.
+fn locate_error(content: &str, context: &Value) -> Option<Range<usize>> {
+  let tags = TEMPLATE_TAG_REGEX
+    .find_iter(content)
+    .map(|tag| tag.range())
+    .collect::<Vec<_>>();
+  let mut rendered_end = 0;
+  for tag in &tags {
+    match render(&content[..tag.end], context.clone()) {
+      Ok(_) => rendered_end = tag.end,
+      // a block opened by a previous tag is closed later
+      Err(message) if message.starts_with(UNCLOSED_BLOCK_ERROR) => {}
+      Err(_) => return Some(tag.clone()),
+    }
+  }
+  // either the block opened after the last rendered tag is never closed, or a tag is not closed
+  tags
+    .into_iter()
+    .find(|tag| tag.start >= rendered_end)
+    .or_else(|| {
+      content[rendered_end..]
+        .find("<%")
+        .map(|start| rendered_end + start..content.len())
+    })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn error_span(content: &str) -> Option<&str> {
+    locate_error(content, &serde_json::json!({ "a": { "b": [1] } })).map(|span| &content[span])
+  }
+
+  #[test]
+  fn locate_template_error() {
+    assert_eq!(
+      error_span("<div>\n<%= a.b[0] %>\n<%= missing(1) %>\n</div>"),
+      Some("<%= missing(1) %>")
+    );
+    assert_eq!(
+      error_span("<% if (a) { %>\n<%= a.b[0] %>\n"),
+      Some("<% if (a) { %>")
+    );
+    assert_eq!(
+      error_span("<%= a.b[0] %>\n<%= a.b[0]\n</div>"),
+      Some("<%= a.b[0]\n</div>")
+    );
+  }
+}
@@ -1,4 +1,5 @@
 use std::env;
+use std::fmt;
 use std::path::PathBuf;
 
 use itertools::Itertools;
@@ -40,6 +41,24 @@ impl HTMLPluginTag {
     }
   }
 
+  pub fn create_module_preload(href: &str) -> HTMLPluginTag {
+    HTMLPluginTag {
+      tag_name: "link".to_string(),
+      append_to: HtmlInject::Head,
+      attributes: vec![
+        HtmlPluginAttribute {
+          attr_name: "href".to_string(),
+          attr_value: Some(href.to_string()),
+        },
+        HtmlPluginAttribute {
+          attr_name: "rel".to_string(),
+          attr_value: Some("modulepreload".to_string()),
+        },
+      ],
+      void_tag: true,
+    }
+  }
+
   pub fn create_script(
     src: &str,
     append_to: HtmlInject,
@@ -74,6 +93,28 @@ impl HTMLPluginTag {
   }
 }
 
+impl fmt::Display for HTMLPluginTag {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "<{}", self.tag_name)?;
+    for attribute in &self.attributes {
+      match &attribute.attr_value {
+        Some(value) => write!(
+          f,
+          " {}=\"{}\"",
+          attribute.attr_name,
+          value.replace('&', "&amp;").replace('"', "&quot;")
+        )?,
+        None => write!(f, " {}", attribute.attr_name)?,
+      }
+    }
+    write!(f, ">")?;
+    if !self.void_tag {
+      write!(f, "</{}>", self.tag_name)?;
+    }
+    Ok(())
+  }
+}
+
 // attributes are presented as plain string.
 // namespace is not supported currently.
 #[derive(Debug)]
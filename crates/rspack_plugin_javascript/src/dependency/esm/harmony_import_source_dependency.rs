@@ -0,0 +1,111 @@
+use rspack_core::{
+  module_id, AsContextDependency, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ExtendedReferencedExport, InitFragmentExt, InitFragmentKey,
+  InitFragmentStage, ModuleDependency, ModuleGraph, NormalInitFragment, RuntimeGlobals,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::Atom;
+
+/// `import source x from "./x.wasm"`, the binding is the compiled `WebAssembly.Module`
+/// and the imported module is never evaluated.
+#[derive(Debug, Clone)]
+pub struct HarmonyImportSourceDependency {
+  id: DependencyId,
+  request: Atom,
+  name: Atom,
+  source_order: i32,
+  span: Option<ErrorSpan>,
+}
+
+impl HarmonyImportSourceDependency {
+  pub fn new(request: Atom, name: Atom, source_order: i32, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      name,
+      source_order,
+      span,
+    }
+  }
+}
+
+impl Dependency for HarmonyImportSourceDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::EsmImportSource
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "HarmonyImportSourceDependency"
+  }
+}
+
+impl ModuleDependency for HarmonyImportSourceDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+}
+
+impl DependencyTemplate for HarmonyImportSourceDependency {
+  fn apply(
+    &self,
+    _source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      init_fragments,
+      ..
+    } = code_generatable_context;
+    runtime_requirements.insert(RuntimeGlobals::COMPILE_WASM);
+    let module_id = module_id(compilation, &self.id, &self.request, false);
+    init_fragments.push(
+      NormalInitFragment::new(
+        format!(
+          "var {} = await {}({module_id});\n",
+          self.name,
+          RuntimeGlobals::COMPILE_WASM
+        ),
+        InitFragmentStage::StageHarmonyImports,
+        self.source_order,
+        InitFragmentKey::unique(),
+        None,
+      )
+      .boxed(),
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for HarmonyImportSourceDependency {}
@@ -4,6 +4,7 @@ mod harmony_export_header_dependency;
 mod harmony_export_imported_specifier_dependency;
 mod harmony_export_specifier_dependency;
 mod harmony_import_dependency;
+mod harmony_import_source_dependency;
 mod harmony_import_specifier_dependency;
 mod import_dependency;
 mod import_eager_dependency;
@@ -19,6 +20,7 @@ pub use self::harmony_export_specifier_dependency::HarmonyExportSpecifierDepende
 pub use self::harmony_import_dependency::get_import_emitted_runtime;
 pub use self::harmony_import_dependency::harmony_import_dependency_apply;
 pub use self::harmony_import_dependency::{HarmonyImportSideEffectDependency, Specifier};
+pub use self::harmony_import_source_dependency::HarmonyImportSourceDependency;
 pub use self::harmony_import_specifier_dependency::HarmonyImportSpecifierDependency;
 pub use self::import_dependency::ImportDependency;
 pub use self::import_eager_dependency::ImportEagerDependency;
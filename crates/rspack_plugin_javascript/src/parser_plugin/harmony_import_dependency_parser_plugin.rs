@@ -3,14 +3,15 @@ use rspack_core::{extract_member_expression_chain, ConstDependency, DependencyTy
 use swc_core::atoms::Atom;
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{
-  AssignExpr, AssignOp, AssignTarget, AssignTargetPat, Callee, ImportSpecifier, ModuleExportName,
-  OptChainExpr,
+  AssignExpr, AssignOp, AssignTarget, AssignTargetPat, Callee, ImportPhase, ImportSpecifier,
+  ModuleExportName, OptChainExpr,
 };
 use swc_core::ecma::ast::{Expr, Ident, ImportDecl};
 
 use super::JavascriptParserPlugin;
 use crate::dependency::{
-  HarmonyImportSideEffectDependency, HarmonyImportSpecifierDependency, Specifier,
+  HarmonyImportSideEffectDependency, HarmonyImportSourceDependency,
+  HarmonyImportSpecifierDependency, Specifier,
 };
 use crate::visitors::{collect_destructuring_assignment_properties, JavascriptParser, TagInfoData};
 use crate::visitors::{ExtraSpanInfo, ImporterReferenceInfo};
@@ -36,6 +37,43 @@ pub(super) fn handle_harmony_import_side_effects_dep(
   parser.dependencies.push(Box::new(dependency));
 }
 
+/// `import source x from "./x.wasm"` binds the compiled module, nothing is evaluated.
+/// The binding is created by top level await, so the importer becomes async.
+fn handle_harmony_import_source_dep(parser: &mut JavascriptParser, import_decl: &ImportDecl) {
+  let Some(name) = import_decl.specifiers.iter().find_map(|s| match s {
+    ImportSpecifier::Default(d) => Some(d.local.sym.clone()),
+    _ => None,
+  }) else {
+    return;
+  };
+  parser
+    .dependencies
+    .push(Box::new(HarmonyImportSourceDependency::new(
+      import_decl.src.value.clone(),
+      name,
+      parser.last_harmony_import_order,
+      Some(import_decl.span.into()),
+    )));
+  parser.build_meta.has_top_level_await = true;
+  remove_import_decl(parser, import_decl);
+}
+
+fn remove_import_decl(parser: &mut JavascriptParser, import_decl: &ImportDecl) {
+  parser
+    .presentational_dependencies
+    .push(Box::new(ConstDependency::new(
+      import_decl.span.real_lo(),
+      import_decl.span.real_hi(),
+      if parser.is_asi_position(import_decl.span_lo()) {
+        ";".into()
+      } else {
+        "".into()
+      },
+      None,
+    )));
+  parser.unset_asi_position(import_decl.span_hi());
+}
+
 pub struct HarmonyImportDependencyParserPlugin;
 
 const HARMONY_SPECIFIER_TAG: &str = "_identifier__harmony_specifier_tag__";
@@ -61,6 +99,10 @@ impl JavascriptParserPlugin for HarmonyImportDependencyParserPlugin {
     _source: &str,
   ) -> Option<bool> {
     parser.last_harmony_import_order += 1;
+    if import_decl.phase == ImportPhase::Source {
+      handle_harmony_import_source_dep(parser, import_decl);
+      return Some(true);
+    }
     let mut specifiers = vec![];
     import_decl.specifiers.iter().for_each(|s| match s {
       ImportSpecifier::Named(n) => {
@@ -125,31 +167,22 @@ impl JavascriptParserPlugin for HarmonyImportDependencyParserPlugin {
       DependencyType::EsmImport(import_decl.span.into()),
       false,
     );
-
-    parser
-      .presentational_dependencies
-      .push(Box::new(ConstDependency::new(
-        import_decl.span.real_lo(),
-        import_decl.span.real_hi(),
-        if parser.is_asi_position(import_decl.span_lo()) {
-          ";".into()
-        } else {
-          "".into()
-        },
-        None,
-      )));
-    parser.unset_asi_position(import_decl.span_hi());
+    remove_import_decl(parser, import_decl);
     Some(true)
   }
 
   fn import_specifier(
     &self,
     parser: &mut JavascriptParser,
-    _statement: &ImportDecl,
+    statement: &ImportDecl,
     _source: &Atom,
     _export_name: Option<&str>,
     identifier_name: &str,
   ) -> Option<bool> {
+    if statement.phase == ImportPhase::Source {
+      // the binding is a plain `WebAssembly.Module`, not a harmony specifier
+      return Some(true);
+    }
     // TODO: fill data with `Some({name, source, ids, source_order, assertions })`
     parser.tag_variable::<MockData>(identifier_name.to_string(), HARMONY_SPECIFIER_TAG, None);
     Some(true)
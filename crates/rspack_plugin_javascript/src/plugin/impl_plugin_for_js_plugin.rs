@@ -226,21 +226,18 @@ async fn render_manifest(
   _diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<()> {
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
-  let source = if matches!(chunk.kind, ChunkKind::HotUpdate) {
-    self.render_chunk(compilation, chunk_ukey).await?
-  } else if chunk.has_runtime(&compilation.chunk_group_by_ukey) {
-    self.render_main(compilation, chunk_ukey).await?
-  } else {
-    if !chunk_has_js(
+  let is_hot_update = matches!(chunk.kind, ChunkKind::HotUpdate);
+  let is_main_chunk = chunk.has_runtime(&compilation.chunk_group_by_ukey);
+  if !is_hot_update
+    && !is_main_chunk
+    && !chunk_has_js(
       chunk_ukey,
       &compilation.chunk_graph,
       &compilation.get_module_graph(),
-    ) {
-      return Ok(());
-    }
-
-    self.render_chunk(compilation, chunk_ukey).await?
-  };
+    )
+  {
+    return Ok(());
+  }
 
   let filename_template = get_js_chunk_filename_template(
     chunk,
@@ -259,6 +256,15 @@ async fn render_manifest(
       )
       .runtime(&chunk.runtime),
   )?;
+  let source = if is_main_chunk && !is_hot_update {
+    self
+      .render_main(compilation, chunk_ukey, &output_path)
+      .await?
+  } else {
+    self
+      .render_chunk(compilation, chunk_ukey, &output_path)
+      .await?
+  };
   asset_info.set_javascript_module(compilation.options.output.module);
   manifest.push(RenderManifestEntry::new(
     source,
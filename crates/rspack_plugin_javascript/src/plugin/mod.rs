@@ -465,6 +465,7 @@ impl JsPlugin {
     &self,
     compilation: &Compilation,
     chunk_ukey: &ChunkUkey,
+    output_path: &str,
   ) -> Result<BoxSource> {
     let drive = Self::get_compilation_drives(compilation);
     let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
@@ -690,7 +691,9 @@ impl JsPlugin {
     let mut final_source = render_init_fragments(
       sources.boxed(),
       chunk_init_fragments,
-      &mut ChunkRenderContext {},
+      &mut ChunkRenderContext {
+        output_path: output_path.to_string(),
+      },
     )?;
     if let Some(source) = drive.render(RenderJsArgs {
       compilation,
@@ -710,6 +713,7 @@ impl JsPlugin {
     &self,
     compilation: &Compilation,
     chunk_ukey: &ChunkUkey,
+    output_path: &str,
   ) -> Result<BoxSource> {
     let drive = Self::get_compilation_drives(compilation);
     let module_graph = &compilation.get_module_graph();
@@ -752,7 +756,9 @@ impl JsPlugin {
     let source_with_fragments = render_init_fragments(
       chunk_modules_source,
       chunk_init_fragments,
-      &mut ChunkRenderContext {},
+      &mut ChunkRenderContext {
+        output_path: output_path.to_string(),
+      },
     )?;
     let chunk_modules_source = if let Some(source) = drive.render(RenderJsArgs {
       compilation,
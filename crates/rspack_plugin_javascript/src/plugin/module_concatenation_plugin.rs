@@ -916,6 +916,8 @@ impl ModuleConcatenationPlugin {
             },
             plugin_driver: compilation.plugin_driver.clone(),
             compiler_options: &compilation.options,
+            old_cache: &compilation.old_cache,
+            profile: None,
           },
           Some(compilation),
         )
@@ -0,0 +1,339 @@
+use std::fmt;
+
+use json::{number::Number, object::Object, JsonValue};
+use rspack_core::ModuleType;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+/// The data formats parsed into json modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFormat {
+  Json,
+  Json5,
+  Yaml,
+  Toml,
+}
+
+impl JsonFormat {
+  pub const ALL: [JsonFormat; 4] = [Self::Json, Self::Json5, Self::Yaml, Self::Toml];
+
+  pub fn module_type(&self) -> ModuleType {
+    match self {
+      Self::Json => ModuleType::Json,
+      Self::Json5 => ModuleType::Json5,
+      Self::Yaml => ModuleType::Yaml,
+      Self::Toml => ModuleType::Toml,
+    }
+  }
+
+  pub fn error_title(&self) -> &'static str {
+    match self {
+      Self::Json => "Json parsing error",
+      Self::Json5 => "Json5 parsing error",
+      Self::Yaml => "Yaml parsing error",
+      Self::Toml => "Toml parsing error",
+    }
+  }
+}
+
+/// A parse error with the byte span it is reported at.
+#[derive(Debug)]
+pub struct ParseError {
+  pub start: usize,
+  pub end: usize,
+  pub message: String,
+}
+
+/// Json modules are rendered as JSON, which has no representation of the non-finite numbers.
+fn non_finite_message(value: f64) -> String {
+  let value = if value.is_nan() {
+    "NaN"
+  } else if value.is_sign_negative() {
+    "-Infinity"
+  } else {
+    "Infinity"
+  };
+  format!("{value} is not supported, json modules can only contain finite numbers")
+}
+
+fn finite_number(value: f64) -> Result<Number, String> {
+  if value.is_finite() {
+    Ok(Number::from(value))
+  } else {
+    Err(non_finite_message(value))
+  }
+}
+
+pub fn parse_json5(source: &str) -> Result<JsonValue, ParseError> {
+  json5::from_str::<Json5Value>(source)
+    .map(|value| value.0)
+    .map_err(|json5::Error::Message { msg, location }| {
+      let start = location.map_or(0, |location| {
+        let rope = ropey::Rope::from_str(source);
+        rope
+          .try_line_to_char(location.line - 1)
+          .map_or(0, |line| rope.char_to_byte(line + location.column - 1))
+      });
+      let end = source[start..]
+        .chars()
+        .next()
+        .map_or(start, |ch| start + ch.len_utf8());
+      // Syntax errors embed a code frame of their own, keep the summary line only.
+      let message = msg.lines().last().unwrap_or_default();
+      ParseError {
+        start,
+        end,
+        message: message.trim_start().trim_start_matches("= ").to_string(),
+      }
+    })
+}
+
+struct Json5Value(JsonValue);
+
+impl<'de> Deserialize<'de> for Json5Value {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(Json5Visitor).map(Json5Value)
+  }
+}
+
+struct Json5Visitor;
+
+impl<'de> Visitor<'de> for Json5Visitor {
+  type Value = JsonValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a json5 value")
+  }
+
+  fn visit_unit<E: de::Error>(self) -> Result<JsonValue, E> {
+    Ok(JsonValue::Null)
+  }
+
+  fn visit_bool<E: de::Error>(self, v: bool) -> Result<JsonValue, E> {
+    Ok(JsonValue::Boolean(v))
+  }
+
+  fn visit_i64<E: de::Error>(self, v: i64) -> Result<JsonValue, E> {
+    Ok(JsonValue::Number(Number::from(v)))
+  }
+
+  fn visit_u64<E: de::Error>(self, v: u64) -> Result<JsonValue, E> {
+    Ok(JsonValue::Number(Number::from(v)))
+  }
+
+  fn visit_f64<E: de::Error>(self, v: f64) -> Result<JsonValue, E> {
+    finite_number(v).map(JsonValue::Number).map_err(E::custom)
+  }
+
+  fn visit_str<E: de::Error>(self, v: &str) -> Result<JsonValue, E> {
+    Ok(JsonValue::String(v.to_string()))
+  }
+
+  fn visit_string<E: de::Error>(self, v: String) -> Result<JsonValue, E> {
+    Ok(JsonValue::String(v))
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+    let mut array = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+    while let Some(Json5Value(value)) = seq.next_element()? {
+      array.push(value);
+    }
+    Ok(JsonValue::Array(array))
+  }
+
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+    let mut object = Object::with_capacity(map.size_hint().unwrap_or_default());
+    while let Some((key, Json5Value(value))) = map.next_entry::<String, _>()? {
+      object.insert(&key, value);
+    }
+    Ok(JsonValue::Object(object))
+  }
+}
+
+pub fn parse_yaml(source: &str) -> Result<JsonValue, ParseError> {
+  let value = serde_yaml::from_str::<serde_yaml::Value>(source).map_err(|e| {
+    let start = e.location().map_or(0, |location| location.index());
+    ParseError {
+      start,
+      end: (start + 1).min(source.len()),
+      message: e.to_string(),
+    }
+  })?;
+  yaml_to_json(value).map_err(|message| ParseError {
+    start: 0,
+    end: 0,
+    message,
+  })
+}
+
+fn yaml_to_json(value: serde_yaml::Value) -> Result<JsonValue, String> {
+  use serde_yaml::Value;
+
+  Ok(match value {
+    Value::Null => JsonValue::Null,
+    Value::Bool(b) => JsonValue::Boolean(b),
+    Value::Number(n) => JsonValue::Number(if let Some(n) = n.as_i64() {
+      Number::from(n)
+    } else if let Some(n) = n.as_u64() {
+      Number::from(n)
+    } else {
+      finite_number(n.as_f64().unwrap_or(f64::NAN))?
+    }),
+    Value::String(s) => JsonValue::String(s),
+    Value::Sequence(seq) => JsonValue::Array(
+      seq
+        .into_iter()
+        .map(yaml_to_json)
+        .collect::<Result<Vec<_>, _>>()?,
+    ),
+    Value::Mapping(mapping) => {
+      let mut object = Object::with_capacity(mapping.len());
+      for (key, value) in mapping {
+        let key = match key {
+          Value::String(s) => s,
+          Value::Number(n) => n.to_string(),
+          Value::Bool(b) => b.to_string(),
+          Value::Null => "null".to_string(),
+          _ => return Err("Only scalar mapping keys are supported".to_string()),
+        };
+        object.insert(&key, yaml_to_json(value)?);
+      }
+      JsonValue::Object(object)
+    }
+    Value::Tagged(tagged) => yaml_to_json(tagged.value)?,
+  })
+}
+
+pub fn parse_toml(source: &str) -> Result<JsonValue, ParseError> {
+  let table = toml::from_str::<toml::Table>(source).map_err(|e| {
+    let span = e.span().unwrap_or(0..0);
+    ParseError {
+      start: span.start,
+      end: span.end,
+      message: e.message().to_string(),
+    }
+  })?;
+  toml_to_json(toml::Value::Table(table)).map_err(|message| ParseError {
+    start: 0,
+    end: 0,
+    message,
+  })
+}
+
+fn toml_to_json(value: toml::Value) -> Result<JsonValue, String> {
+  use toml::Value;
+
+  Ok(match value {
+    Value::String(s) => JsonValue::String(s),
+    Value::Integer(n) => JsonValue::Number(Number::from(n)),
+    Value::Float(n) => JsonValue::Number(finite_number(n)?),
+    Value::Boolean(b) => JsonValue::Boolean(b),
+    Value::Datetime(datetime) => JsonValue::String(datetime.to_string()),
+    Value::Array(array) => JsonValue::Array(
+      array
+        .into_iter()
+        .map(toml_to_json)
+        .collect::<Result<Vec<_>, _>>()?,
+    ),
+    Value::Table(table) => {
+      let mut object = Object::with_capacity(table.len());
+      for (key, value) in table {
+        object.insert(&key, toml_to_json(value)?);
+      }
+      JsonValue::Object(object)
+    }
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use json::stringify;
+
+  use super::*;
+
+  #[test]
+  fn parse_yaml_and_toml() {
+    let yaml = "title: hello\nlist:\n  - 1\n  - two\n1: one\n";
+    assert_eq!(
+      stringify(parse_yaml(yaml).expect("should parse")),
+      r#"{"title":"hello","list":[1,"two"],"1":"one"}"#
+    );
+
+    let toml = "title = \"hello\"\n[server]\nport = 8080\nhosts = [\"a\", \"b\"]\n";
+    assert_eq!(
+      stringify(parse_toml(toml).expect("should parse")),
+      r#"{"server":{"hosts":["a","b"],"port":8080},"title":"hello"}"#
+    );
+  }
+
+  #[test]
+  fn parse_json5_source() {
+    let source = r#"
+      // comment
+      {
+        unquoted: 'single',
+        "quoted": "line \
+continued",
+        hex: 0xFF, leading: .5, trailing: 5., plus: +1,
+        /* block */ nested: { list: [1, 2, ], },
+        escapes: '\x41B\'\uD83D\uDE00',
+      }
+    "#;
+    assert_eq!(
+      stringify(parse_json5(source).expect("should parse")),
+      r#"{"unquoted":"single","quoted":"line continued","hex":255,"leading":0.5,"trailing":5,"plus":1,"nested":{"list":[1,2]},"escapes":"AB'😀"}"#
+    );
+  }
+
+  #[test]
+  fn report_json5_error_span() {
+    let source = "{ a: 1, b: 2 c: 3 }";
+    let error = parse_json5(source).expect_err("should fail");
+    assert_eq!(&source[error.start..error.end], "2");
+    assert!(!error.message.contains('\n'));
+
+    let source = r#"["\uD800\u0041"]"#;
+    let error = parse_json5(source).expect_err("should fail");
+    assert_eq!(error.message, "expecting unicode trail surrogate");
+  }
+
+  #[test]
+  fn report_non_finite_numbers() {
+    for (source, value) in [
+      ("{ a: Infinity }", "Infinity"),
+      ("{\n  a: -Infinity }", "-Infinity"),
+      ("[1, NaN]", "NaN"),
+    ] {
+      let error = parse_json5(source).expect_err("should fail");
+      assert_eq!(&source[error.start..error.end], &value[..1]);
+      assert_eq!(
+        error.message,
+        format!("{value} is not supported, json modules can only contain finite numbers")
+      );
+    }
+
+    for (error, value) in [
+      (parse_yaml("a: .inf\n"), "Infinity"),
+      (parse_yaml("a: [-.inf]\n"), "-Infinity"),
+      (parse_yaml("a: .nan\n"), "NaN"),
+      (parse_toml("a = inf\n"), "Infinity"),
+      (parse_toml("a = [-inf]\n"), "-Infinity"),
+      (parse_toml("a = nan\n"), "NaN"),
+    ] {
+      assert_eq!(
+        error.expect_err("should fail").message,
+        format!("{value} is not supported, json modules can only contain finite numbers")
+      );
+    }
+  }
+
+  #[test]
+  fn report_error_span() {
+    let toml = "a = 1\nb = \n";
+    let error = parse_toml(toml).expect_err("should fail");
+    assert!(error.start >= 6 && error.end <= toml.len());
+
+    let yaml = "a: 1\nb: [1, 2\n";
+    let error = parse_yaml(yaml).expect_err("should fail");
+    assert!(error.start <= yaml.len());
+  }
+}
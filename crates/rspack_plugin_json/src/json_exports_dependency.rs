@@ -8,12 +8,14 @@ use rspack_core::{
 pub struct JsonExportsDependency {
   id: DependencyId,
   data: JsonValue,
+  exports_depth: Option<u32>,
 }
 
 impl JsonExportsDependency {
-  pub fn new(data: JsonValue) -> Self {
+  pub fn new(data: JsonValue, exports_depth: Option<u32>) -> Self {
     Self {
       data,
+      exports_depth,
       id: DependencyId::new(),
     }
   }
@@ -30,7 +32,8 @@ impl Dependency for JsonExportsDependency {
 
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
     Some(ExportsSpec {
-      exports: get_exports_from_data(&self.data).unwrap_or(ExportsOfExportsSpec::Null),
+      exports: get_exports_from_data(&self.data, self.exports_depth, 1)
+        .unwrap_or(ExportsOfExportsSpec::Null),
       ..Default::default()
     })
   }
@@ -52,7 +55,14 @@ impl DependencyTemplate for JsonExportsDependency {
   }
 }
 
-fn get_exports_from_data(data: &JsonValue) -> Option<ExportsOfExportsSpec> {
+fn get_exports_from_data(
+  data: &JsonValue,
+  exports_depth: Option<u32>,
+  cur_depth: u32,
+) -> Option<ExportsOfExportsSpec> {
+  if exports_depth.is_some_and(|depth| cur_depth > depth) {
+    return None;
+  }
   let ret = match data {
     JsonValue::Null
     | JsonValue::Short(_)
@@ -68,11 +78,13 @@ fn get_exports_from_data(data: &JsonValue) -> Option<ExportsOfExportsSpec> {
           ExportNameOrSpec::ExportSpec(ExportSpec {
             name: k.into(),
             can_mangle: Some(true),
-            exports: get_exports_from_data(v).map(|item| match item {
-              ExportsOfExportsSpec::True => unreachable!(),
-              ExportsOfExportsSpec::Null => unreachable!(),
-              ExportsOfExportsSpec::Array(arr) => arr,
-            }),
+            exports: get_exports_from_data(v, exports_depth, cur_depth + 1).map(
+              |item| match item {
+                ExportsOfExportsSpec::True => unreachable!(),
+                ExportsOfExportsSpec::Null => unreachable!(),
+                ExportsOfExportsSpec::Array(arr) => arr,
+              },
+            ),
             ..Default::default()
           })
         })
@@ -90,9 +102,11 @@ fn get_exports_from_data(data: &JsonValue) -> Option<ExportsOfExportsSpec> {
             ExportNameOrSpec::ExportSpec(ExportSpec {
               name: format!("{i}").into(),
               can_mangle: Some(true),
-              exports: get_exports_from_data(item).map(|item| match item {
-                ExportsOfExportsSpec::True | ExportsOfExportsSpec::Null => unreachable!(),
-                ExportsOfExportsSpec::Array(arr) => arr,
+              exports: get_exports_from_data(item, exports_depth, cur_depth + 1).map(|item| {
+                match item {
+                  ExportsOfExportsSpec::True | ExportsOfExportsSpec::Null => unreachable!(),
+                  ExportsOfExportsSpec::Array(arr) => arr,
+                }
               }),
               ..Default::default()
             })
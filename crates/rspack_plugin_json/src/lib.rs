@@ -14,21 +14,26 @@ use rspack_core::{
   diagnostics::ModuleParseError,
   rspack_sources::{BoxSource, RawSource, Source, SourceExt},
   BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, CompilerOptions, ExportsInfo,
-  GenerateContext, Module, ModuleGraph, ParserAndGenerator, Plugin, RuntimeGlobals, RuntimeSpec,
-  SourceType, UsageState, NAMESPACE_OBJECT_EXPORT,
+  GenerateContext, JsonParseFn, Module, ModuleGraph, ParserAndGenerator, Plugin, RuntimeGlobals,
+  RuntimeSpec, SourceType, UsageState, NAMESPACE_OBJECT_EXPORT,
 };
 use rspack_error::{
   miette::diagnostic, DiagnosticExt, DiagnosticKind, IntoTWithDiagnosticArray, Result,
   TWithDiagnosticArray, TraceableError,
 };
 
-use crate::json_exports_dependency::JsonExportsDependency;
+use crate::{format::JsonFormat, json_exports_dependency::JsonExportsDependency};
 
+mod format;
 mod json_exports_dependency;
 mod utils;
 
 #[derive(Debug)]
-struct JsonParserAndGenerator;
+struct JsonParserAndGenerator {
+  format: JsonFormat,
+  exports_depth: Option<u32>,
+  parse: Option<JsonParseFn>,
+}
 
 impl ParserAndGenerator for JsonParserAndGenerator {
   fn source_types(&self) -> &[SourceType] {
@@ -53,9 +58,23 @@ impl ParserAndGenerator for JsonParserAndGenerator {
     let source = box_source.source();
     let strip_bom_source = source.strip_prefix('\u{feff}');
     let need_strip_bom = strip_bom_source.is_some();
+    let content = strip_bom_source.unwrap_or(&source);
 
-    let parse_result = json::parse(strip_bom_source.unwrap_or(&source)).map_err(|e| {
-      match e {
+    let format_error = |e: format::ParseError| {
+      let bom_offset = source.len() - content.len();
+      TraceableError::from_file(
+        source.to_string(),
+        e.start + bom_offset,
+        e.end + bom_offset,
+        self.format.error_title().to_string(),
+        e.message,
+      )
+      .with_kind(DiagnosticKind::Json)
+      .boxed()
+    };
+    let parse_result = match (&self.parse, self.format) {
+      (Some(parse), _) => parse.call(content.to_string()).map_err(Into::into),
+      (None, JsonFormat::Json) => json::parse(content).map_err(|e| match e {
         UnexpectedCharacter { ch, line, column } => {
           let rope = ropey::Rope::from_str(&source);
           let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
@@ -69,7 +88,7 @@ impl ParserAndGenerator for JsonParserAndGenerator {
             start_offset
           };
           TraceableError::from_file(
-            source.into_owned(),
+            source.to_string(),
             // one character offset
             start_offset,
             start_offset + 1,
@@ -84,7 +103,7 @@ impl ParserAndGenerator for JsonParserAndGenerator {
           // End offset of json file
           let offset = source.len() - 1;
           TraceableError::from_file(
-            source.into_owned(),
+            source.to_string(),
             offset,
             offset,
             "Json parsing error".to_string(),
@@ -93,8 +112,11 @@ impl ParserAndGenerator for JsonParserAndGenerator {
           .with_kind(DiagnosticKind::Json)
           .boxed()
         }
-      }
-    });
+      }),
+      (None, JsonFormat::Json5) => format::parse_json5(content).map_err(format_error),
+      (None, JsonFormat::Yaml) => format::parse_yaml(content).map_err(format_error),
+      (None, JsonFormat::Toml) => format::parse_toml(content).map_err(format_error),
+    };
 
     let (diagnostics, data) = match parse_result {
       Ok(data) => (vec![], Some(data)),
@@ -113,7 +135,10 @@ impl ParserAndGenerator for JsonParserAndGenerator {
       rspack_core::ParseResult {
         presentational_dependencies: vec![],
         dependencies: if let Some(data) = data {
-          vec![Box::new(JsonExportsDependency::new(data))]
+          vec![Box::new(JsonExportsDependency::new(
+            data,
+            self.exports_depth,
+          ))]
         } else {
           vec![]
         },
@@ -218,10 +243,19 @@ impl Plugin for JsonPlugin {
     ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>,
     _options: &mut CompilerOptions,
   ) -> Result<()> {
-    ctx.context.register_parser_and_generator_builder(
-      rspack_core::ModuleType::Json,
-      Box::new(|_, _| Box::new(JsonParserAndGenerator {})),
-    );
+    for format in JsonFormat::ALL {
+      ctx.context.register_parser_and_generator_builder(
+        format.module_type(),
+        Box::new(move |parser_options, _| {
+          let parser_options = parser_options.and_then(|options| options.get_json());
+          Box::new(JsonParserAndGenerator {
+            format,
+            exports_depth: parser_options.and_then(|options| options.exports_depth),
+            parse: parser_options.and_then(|options| options.parse.clone()),
+          })
+        }),
+      );
+    }
 
     Ok(())
   }
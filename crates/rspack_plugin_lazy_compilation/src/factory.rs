@@ -43,6 +43,7 @@ impl ModuleFactory for LazyCompilationDependencyFactory {
       context_dependencies: proxy_data.context_dependencies.clone(),
       missing_dependencies: proxy_data.missing_dependencies.clone(),
       diagnostics: proxy_data.diagnostics.clone(),
+      resolve_duration: proxy_data.resolve_duration,
     };
 
     self.normal_module_factory.create(&mut create_data).await
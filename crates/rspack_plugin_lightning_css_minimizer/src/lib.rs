@@ -0,0 +1,178 @@
+use std::sync::{Arc, Mutex};
+
+use lightningcss::{
+  stylesheet::{MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, StyleSheet},
+  targets::{Browsers, Features, Targets},
+};
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use regex::Regex;
+use rspack_core::{
+  rspack_sources::{
+    MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource, SourceMapSourceOptions,
+  },
+  Compilation, CompilationProcessAssets, Plugin,
+};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+static CSS_ASSET_REGEXP: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\.css(\?.*)?$").expect("Invalid RegExp"));
+
+#[derive(Debug, Default)]
+pub struct LightningCssMinimizerOptions {
+  /// Ignores invalid rules and declarations and reports them as warnings.
+  pub error_recovery: bool,
+  /// Browserslist queries, the output is not lowered for any browsers when not set.
+  pub targets: Option<Vec<String>>,
+  /// Bits of `lightningcss::targets::Features` that are always compiled.
+  pub include: u32,
+  /// Bits of `lightningcss::targets::Features` that are never compiled.
+  pub exclude: u32,
+  /// Parses `@custom-media` rules.
+  pub draft_custom_media: bool,
+}
+
+impl LightningCssMinimizerOptions {
+  fn targets(&self) -> Result<Targets> {
+    let browsers = match &self.targets {
+      Some(queries) => Browsers::from_browserslist(queries)
+        .map_err(|e| error!("Failed to resolve browserslist targets {queries:?}: {e}"))?,
+      None => None,
+    };
+    Ok(Targets {
+      browsers,
+      include: Features::from_bits_truncate(self.include),
+      exclude: Features::from_bits_truncate(self.exclude),
+    })
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct LightningCssMinimizerRspackPlugin {
+  options: LightningCssMinimizerOptions,
+}
+
+impl LightningCssMinimizerRspackPlugin {
+  pub fn new(options: LightningCssMinimizerOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for LightningCssMinimizerRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let options = &self.options;
+  let targets = options.targets()?;
+  let mut flags = ParserFlags::NESTING;
+  if options.draft_custom_media {
+    flags |= ParserFlags::CUSTOM_MEDIA;
+  }
+  let diagnostics = Mutex::new(vec![]);
+
+  compilation
+    .assets_mut()
+    .par_iter_mut()
+    .filter(|(filename, _)| CSS_ASSET_REGEXP.is_match(filename))
+    .try_for_each(|(filename, original)| -> Result<()> {
+      if original.get_info().minimized {
+        return Ok(());
+      }
+
+      if let Some(original_source) = original.get_source() {
+        let input = original_source.source().to_string();
+        let input_source_map = original_source.map(&MapOptions::default());
+
+        let warnings = options.error_recovery.then(|| Arc::new(Default::default()));
+        let mut stylesheet = StyleSheet::parse(
+          &input,
+          ParserOptions {
+            filename: filename.to_string(),
+            error_recovery: options.error_recovery,
+            warnings: warnings.clone(),
+            flags: flags.clone(),
+            ..Default::default()
+          },
+        )
+        .map_err(|e| error!("{filename}: {e}"))?;
+        stylesheet
+          .minify(MinifyOptions {
+            targets,
+            unused_symbols: Default::default(),
+          })
+          .map_err(|e| error!("{filename}: {e}"))?;
+
+        let mut source_map = input_source_map.as_ref().map(|_| {
+          let mut source_map = parcel_sourcemap::SourceMap::new("/");
+          source_map.add_source(filename);
+          source_map
+        });
+        let result = stylesheet
+          .to_css(PrinterOptions {
+            minify: true,
+            source_map: source_map.as_mut(),
+            targets,
+            ..Default::default()
+          })
+          .map_err(|e| error!("{filename}: {e}"))?;
+
+        if let Some(warnings) = warnings {
+          diagnostics.lock().expect("should lock diagnostics").extend(
+            warnings
+              .read()
+              .expect("should lock warnings")
+              .iter()
+              .map(|warning| {
+                Diagnostic::warn(
+                  "LightningCssMinimizerRspackPlugin".to_string(),
+                  format!("{filename}: {warning}"),
+                )
+              }),
+          );
+        }
+
+        let minimized_source = match source_map {
+          Some(mut source_map) => {
+            let source_map = source_map
+              .to_json(None)
+              .map_err(|e| error!(e.to_string()))?;
+            SourceMapSource::new(SourceMapSourceOptions {
+              value: result.code,
+              name: filename,
+              source_map: SourceMap::from_json(&source_map).map_err(|e| error!(e.to_string()))?,
+              original_source: Some(input.clone()),
+              inner_source_map: input_source_map,
+              remove_original_source: true,
+            })
+            .boxed()
+          }
+          None => RawSource::from(result.code).boxed(),
+        };
+        original.set_source(Some(minimized_source));
+      }
+      original.get_info_mut().minimized = true;
+      Ok(())
+    })?;
+
+  compilation.extend_diagnostics(diagnostics.into_inner().expect("should lock diagnostics"));
+  Ok(())
+}
+
+impl Plugin for LightningCssMinimizerRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.LightningCssMinimizerRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
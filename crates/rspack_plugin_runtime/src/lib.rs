@@ -5,7 +5,11 @@ mod common_js_chunk_format;
 pub use common_js_chunk_format::CommonJsChunkFormatPlugin;
 mod runtime_plugin;
 use rspack_core::{BoxPlugin, ChunkLoading, ChunkLoadingType, PluginExt};
-pub use runtime_plugin::RuntimePlugin;
+pub use runtime_plugin::{
+  CreateLinkData, CreateScriptData, RuntimePlugin, RuntimePluginCreateLink,
+  RuntimePluginCreateLinkHook, RuntimePluginCreateScript, RuntimePluginCreateScriptHook,
+  RuntimePluginHooks,
+};
 mod array_push_callback_chunk_format;
 pub use array_push_callback_chunk_format::ArrayPushCallbackChunkFormatPlugin;
 mod common_js_chunk_loading;
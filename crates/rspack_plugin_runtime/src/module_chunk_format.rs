@@ -4,7 +4,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
 use rspack_core::{
-  ApplyContext, ChunkKind, ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements,
+  ApplyContext, ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements,
   CompilationParams, CompilerCompilation, CompilerOptions, Plugin, PluginContext, RuntimeGlobals,
 };
 use rspack_error::Result;
@@ -56,10 +56,6 @@ impl JavascriptModulesPluginPlugin for ModuleChunkFormatJavascriptModulesPluginP
     let drive = JsPlugin::get_compilation_drives(compilation);
     let chunk = args.chunk();
     let base_chunk_output_name = get_chunk_output_name(chunk, compilation)?;
-    if matches!(chunk.kind, ChunkKind::HotUpdate) {
-      unreachable!("HMR is not implemented for module chunk format yet");
-    }
-
     let mut sources = ConcatSource::default();
     sources.add(RawSource::from(format!(
       "export const ids = ['{}'];\n",
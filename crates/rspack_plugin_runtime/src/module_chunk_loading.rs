@@ -31,6 +31,23 @@ fn runtime_requirements_in_tree(
         has_chunk_loading = true;
         runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
       }
+      RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS if is_enabled_for_chunk => {
+        has_chunk_loading = true;
+        runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME);
+        runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+        runtime_requirements_mut.insert(RuntimeGlobals::HMR_MODULE_DATA);
+      }
+      RuntimeGlobals::HMR_DOWNLOAD_MANIFEST if is_enabled_for_chunk => {
+        has_chunk_loading = true;
+        runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+        runtime_requirements_mut.insert(RuntimeGlobals::GET_UPDATE_MANIFEST_FILENAME);
+      }
+      RuntimeGlobals::PREFETCH_CHUNK_HANDLERS | RuntimeGlobals::PRELOAD_CHUNK_HANDLERS
+        if is_enabled_for_chunk =>
+      {
+        has_chunk_loading = true;
+        runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+      }
       RuntimeGlobals::EXTERNAL_INSTALL_CHUNK if is_enabled_for_chunk => {
         has_chunk_loading = true;
         compilation
@@ -1,17 +1,20 @@
 use rspack_core::{
   impl_runtime_module,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
+  ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 use rspack_util::source_map::SourceMapKind;
 
+use crate::{CreateScriptData, RuntimePlugin};
+
 #[impl_runtime_module]
 #[derive(Debug, Eq)]
 pub struct LoadScriptRuntimeModule {
   id: Identifier,
   unique_name: String,
   with_create_script_url: bool,
+  chunk: Option<ChunkUkey>,
 }
 
 impl LoadScriptRuntimeModule {
@@ -20,6 +23,7 @@ impl LoadScriptRuntimeModule {
       id: Identifier::from("webpack/runtime/load_script"),
       unique_name,
       with_create_script_url,
+      chunk: None,
       source_map_kind: SourceMapKind::empty(),
       custom_source: None,
     }
@@ -54,6 +58,14 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       }
     };
 
+    let mut create_script = CreateScriptData {
+      code: cross_origin_loading,
+      chunk: self.chunk.expect("should attach chunk for load_script"),
+    };
+    RuntimePlugin::get_compilation_hooks(compilation)
+      .create_script
+      .call(compilation, &mut create_script)?;
+
     let script_type = if compilation.options.output.script_type.eq("false") {
       String::new()
     } else {
@@ -76,7 +88,7 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       include_str!("runtime/load_script.js")
         .replace(
           "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-          &cross_origin_loading,
+          &create_script.code,
         )
         .replace("$URL$", &url)
         .replace("$SCRIPT_TYPE$", &script_type)
@@ -101,4 +113,8 @@ impl RuntimeModule for LoadScriptRuntimeModule {
     )
     .boxed())
   }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
 }
@@ -1,12 +1,16 @@
 use rspack_core::{
   compile_boolean_matcher, impl_runtime_module,
   rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
-  BooleanMatcher, Chunk, ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
+  BooleanMatcher, Chunk, ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
+  RuntimeModuleStage,
 };
 use rspack_identifier::Identifier;
 use rspack_util::source_map::SourceMapKind;
 
-use super::utils::{chunk_has_js, get_output_dir};
+use super::{
+  generate_javascript_hmr_runtime,
+  utils::{chunk_has_js, get_output_dir},
+};
 use crate::{
   get_chunk_runtime_requirements,
   runtime_module::utils::{get_initial_chunk_ids, stringify_chunks},
@@ -68,6 +72,11 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
     let with_loading = runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
     let with_on_chunk_load = runtime_requirements.contains(RuntimeGlobals::ON_CHUNKS_LOADED);
     let with_hmr = runtime_requirements.contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
+    let with_hmr_manifest = runtime_requirements.contains(RuntimeGlobals::HMR_DOWNLOAD_MANIFEST);
+    let with_prefetch = runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+    let with_preload = runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+    let import_function_name = &compilation.options.output.import_function_name;
+    let cross_origin_loading = &compilation.options.output.cross_origin_loading;
 
     let condition_map =
       compilation
@@ -119,10 +128,7 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
       } else {
         include_str!("runtime/module_chunk_loading_with_loading.js")
           .replace("$JS_MATCHER$", &has_js_matcher.render("chunkId"))
-          .replace(
-            "$IMPORT_FUNCTION_NAME$",
-            &compilation.options.output.import_function_name,
-          )
+          .replace("$IMPORT_FUNCTION_NAME$", import_function_name)
           .replace("$OUTPUT_DIR$", &root_output_dir)
           .replace(
             "$MATCH_FALLBACK$",
@@ -144,6 +150,47 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
       )));
     }
 
+    if with_prefetch && !matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
+      let cross_origin = match cross_origin_loading {
+        CrossOriginLoading::Disable => "".to_string(),
+        CrossOriginLoading::Enable(_) => {
+          format!("link.crossOrigin = {}", cross_origin_loading)
+        }
+      };
+      source.add(RawSource::from(
+        include_str!("runtime/module_chunk_loading_with_prefetch.js")
+          .replace("$JS_MATCHER$", &has_js_matcher.render("chunkId"))
+          .replace("$CROSS_ORIGIN$", cross_origin.as_str())
+          .replace("$OUTPUT_DIR$", &root_output_dir),
+      ));
+    }
+
+    if with_preload && !matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
+      let cross_origin = match cross_origin_loading {
+        CrossOriginLoading::Disable => "".to_string(),
+        CrossOriginLoading::Enable(cross_origin_value) => {
+          if cross_origin_value.eq("use-credentials") {
+            "link.crossOrigin = \"use-credentials\";".to_string()
+          } else {
+            format!(
+              r#"
+              if (link.href.indexOf(window.location.origin + '/') !== 0) {{
+                link.crossOrigin = {}
+              }}
+              "#,
+              cross_origin_loading
+            )
+          }
+        }
+      };
+      source.add(RawSource::from(
+        include_str!("runtime/module_chunk_loading_with_preload.js")
+          .replace("$JS_MATCHER$", &has_js_matcher.render("chunkId"))
+          .replace("$CROSS_ORIGIN$", cross_origin.as_str())
+          .replace("$OUTPUT_DIR$", &root_output_dir),
+      ));
+    }
+
     if with_external_install_chunk {
       source.add(RawSource::from(format!(
         r#"
@@ -164,6 +211,21 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
       )));
     }
 
+    if with_hmr {
+      source.add(RawSource::from(
+        include_str!("runtime/module_chunk_loading_with_hmr.js")
+          .replace("$IMPORT_FUNCTION_NAME$", import_function_name)
+          .replace("$OUTPUT_DIR$", &root_output_dir),
+      ));
+      source.add(RawSource::from(generate_javascript_hmr_runtime("module")));
+    }
+
+    if with_hmr_manifest {
+      source.add(RawSource::from(include_str!(
+        "runtime/module_chunk_loading_with_hmr_manifest.js"
+      )));
+    }
+
     Ok(source.boxed())
   }
 
@@ -0,0 +1,14 @@
+__webpack_require__.F.j = function (chunkId) {
+  if ((!__webpack_require__.o(installedChunks, chunkId) || installedChunks[chunkId] === undefined) && $JS_MATCHER$) {
+    installedChunks[chunkId] = null;
+    var link = document.createElement('link');
+    $CROSS_ORIGIN$
+    if (__webpack_require__.nc) {
+      link.setAttribute("nonce", __webpack_require__.nc);
+    }
+    link.rel = "prefetch";
+    link.as = "script";
+    link.href = new URL("$OUTPUT_DIR$" + __webpack_require__.u(chunkId), import.meta.url).href;
+    document.head.appendChild(link);
+  }
+};
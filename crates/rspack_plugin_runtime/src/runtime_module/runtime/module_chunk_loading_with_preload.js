@@ -0,0 +1,14 @@
+__webpack_require__.H.j = function (chunkId) {
+  if ((!__webpack_require__.o(installedChunks, chunkId) || installedChunks[chunkId] === undefined) && $JS_MATCHER$) {
+    installedChunks[chunkId] = null;
+    var link = document.createElement('link');
+    link.charset = 'utf-8';
+    if (__webpack_require__.nc) {
+      link.setAttribute("nonce", __webpack_require__.nc);
+    }
+    link.rel = "modulepreload";
+    link.href = new URL("$OUTPUT_DIR$" + __webpack_require__.u(chunkId), import.meta.url).href;
+    $CROSS_ORIGIN$
+    document.head.appendChild(link);
+  }
+};
@@ -1,19 +1,23 @@
-use std::{hash::Hash, sync::Arc};
+use std::{
+  hash::Hash,
+  sync::{Arc, Mutex},
+};
 
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use rspack_core::{
   get_css_chunk_filename_template, get_js_chunk_filename_template, has_hash_placeholder,
-  ApplyContext, ChunkLoading, ChunkUkey, Compilation, CompilationParams,
+  ApplyContext, ChunkLoading, ChunkUkey, Compilation, CompilationId, CompilationParams,
   CompilationRuntimeRequirementInModule, CompilationRuntimeRequirementInTree, CompilerCompilation,
   CompilerOptions, ModuleIdentifier, Plugin, PluginContext, PublicPath, RuntimeGlobals,
   RuntimeModuleExt, SourceType,
 };
 use rspack_error::Result;
-use rspack_hook::{plugin, plugin_hook};
+use rspack_hook::{define_hook, plugin, plugin_hook};
 use rspack_plugin_javascript::{
   JavascriptModulesPluginPlugin, JsChunkHashArgs, JsPlugin, PluginJsChunkHashHookOutput,
 };
+use rspack_util::fx_hash::{BuildFxHasher, FxDashMap};
 
 use crate::runtime_module::{
   chunk_has_css, is_enabled_for_chunk, AsyncRuntimeModule, AutoPublicPathRuntimeModule,
@@ -53,8 +57,9 @@ static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
     RuntimeGlobals::SCRIPT_NONCE,
     // RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
     RuntimeGlobals::ASYNC_MODULE,
-    // RuntimeGlobals::WASM_INSTANCES,
+    RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
+    RuntimeGlobals::COMPILE_WASM,
     RuntimeGlobals::SHARE_SCOPE_MAP,
     RuntimeGlobals::INITIALIZE_SHARING,
     RuntimeGlobals::LOAD_SCRIPT,
@@ -161,10 +166,59 @@ impl JavascriptModulesPluginPlugin for RuntimeJavascriptModulesPluginPlugin {
   }
 }
 
+define_hook!(RuntimePluginCreateScript: SyncSeries(compilation: &Compilation, data: &mut CreateScriptData));
+define_hook!(RuntimePluginCreateLink: SyncSeries(compilation: &Compilation, data: &mut CreateLinkData));
+
+/// Code appended after the script element of a chunk is created by `__webpack_require__.l`,
+/// `script` and `chunkId` are in scope.
+#[derive(Debug)]
+pub struct CreateScriptData {
+  pub code: String,
+  pub chunk: ChunkUkey,
+}
+
+/// Code appended after the link element of a css chunk is created, `link` and `chunkId` are in
+/// scope.
+#[derive(Debug)]
+pub struct CreateLinkData {
+  pub code: String,
+  pub chunk: ChunkUkey,
+}
+
+#[derive(Debug, Default)]
+pub struct RuntimePluginHooks {
+  pub create_script: RuntimePluginCreateScriptHook,
+  pub create_link: RuntimePluginCreateLinkHook,
+}
+
+static COMPILATION_HOOKS_MAP: Lazy<FxDashMap<CompilationId, RuntimePluginHooks>> =
+  Lazy::new(Default::default);
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct RuntimePlugin {
   js_plugin: Arc<RuntimeJavascriptModulesPluginPlugin>,
+  last_compilation_id: Mutex<Option<CompilationId>>,
+}
+
+impl RuntimePlugin {
+  pub fn get_compilation_hooks(
+    compilation: &Compilation,
+  ) -> dashmap::mapref::one::Ref<'_, CompilationId, RuntimePluginHooks, BuildFxHasher> {
+    let id = compilation.id();
+    if !COMPILATION_HOOKS_MAP.contains_key(&id) {
+      COMPILATION_HOOKS_MAP.insert(id, Default::default());
+    }
+    COMPILATION_HOOKS_MAP
+      .get(&id)
+      .expect("should have runtime plugin hooks")
+  }
+
+  pub fn get_compilation_hooks_mut(
+    compilation: &Compilation,
+  ) -> dashmap::mapref::one::RefMut<'_, CompilationId, RuntimePluginHooks, BuildFxHasher> {
+    COMPILATION_HOOKS_MAP.entry(compilation.id()).or_default()
+  }
 }
 
 #[plugin_hook(CompilerCompilation for RuntimePlugin)]
@@ -175,6 +229,16 @@ async fn compilation(
 ) -> Result<()> {
   let mut drive = JsPlugin::get_compilation_drives_mut(compilation);
   drive.add_plugin(self.js_plugin.clone());
+
+  // the hooks of the previous compilation are not used anymore after a rebuild
+  let last_compilation_id = self
+    .last_compilation_id
+    .lock()
+    .expect("should lock last compilation id")
+    .replace(compilation.id());
+  if let Some(last_compilation_id) = last_compilation_id.filter(|id| *id != compilation.id()) {
+    COMPILATION_HOOKS_MAP.remove(&last_compilation_id);
+  }
   Ok(())
 }
 
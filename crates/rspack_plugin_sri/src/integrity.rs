@@ -0,0 +1,132 @@
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_error::error;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubresourceIntegrityHashFunction {
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
+impl TryFrom<&str> for SubresourceIntegrityHashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
+    match value {
+      "sha256" => Ok(Self::Sha256),
+      "sha384" => Ok(Self::Sha384),
+      "sha512" => Ok(Self::Sha512),
+      _ => Err(error!(
+        "Unsupported integrity hash function \"{value}\", expected one of \"sha256\", \"sha384\" or \"sha512\""
+      )),
+    }
+  }
+}
+
+impl SubresourceIntegrityHashFunction {
+  fn digest(&self, content: &[u8]) -> String {
+    let (prefix, digest) = match self {
+      Self::Sha256 => ("sha256", Sha256::digest(content).to_vec()),
+      Self::Sha384 => ("sha384", Sha384::digest(content).to_vec()),
+      Self::Sha512 => ("sha512", Sha512::digest(content).to_vec()),
+    };
+    format!("{prefix}-{}", rspack_base64::encode_to_string(digest))
+  }
+}
+
+/// Space separated integrity values of the content, one for each hash function.
+pub fn compute_integrity(
+  hash_funcs: &[SubresourceIntegrityHashFunction],
+  content: &[u8],
+) -> String {
+  hash_funcs
+    .iter()
+    .map(|hash_func| hash_func.digest(content))
+    .join(" ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntegrityKind {
+  Js,
+  Css,
+}
+
+impl IntegrityKind {
+  pub fn from_filename(filename: &str) -> Option<Self> {
+    let path = filename.split(['?', '#']).next().unwrap_or(filename);
+    if path.ends_with(".css") {
+      Some(Self::Css)
+    } else if path.ends_with(".js") || path.ends_with(".mjs") || path.ends_with(".cjs") {
+      Some(Self::Js)
+    } else {
+      None
+    }
+  }
+
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::Js => "CHUNK",
+      Self::Css => "CSS",
+    }
+  }
+}
+
+pub static PLACEHOLDER_REGEX: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"\*-\*-\*-((?:CHUNK|CSS)-SRI-HASH-.*?)-\*-\*-\*").expect("should be valid regex")
+});
+
+/// The key captured by [PLACEHOLDER_REGEX], the chunk id is escaped the same as in the rendered
+/// JSON string of the runtime.
+pub fn placeholder_key(kind: IntegrityKind, chunk_id: &str) -> String {
+  let escaped = serde_json::to_string(chunk_id).expect("should serialize chunk id");
+  format!(
+    "{}-SRI-HASH-{}",
+    kind.as_str(),
+    &escaped[1..escaped.len() - 1]
+  )
+}
+
+/// Rendered in the runtime before the chunks are hashed, and replaced with the integrity after
+/// `RealContentHashPlugin`.
+pub fn placeholder(kind: IntegrityKind, chunk_id: &str) -> String {
+  format!("*-*-*-{}-SRI-HASH-{chunk_id}-*-*-*", kind.as_str())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn integrity() {
+    assert_eq!(
+      compute_integrity(
+        &[SubresourceIntegrityHashFunction::Sha256],
+        b"alert('Hello, world.');"
+      ),
+      "sha256-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng="
+    );
+    assert_eq!(
+      compute_integrity(
+        &[
+          SubresourceIntegrityHashFunction::Sha256,
+          SubresourceIntegrityHashFunction::Sha384
+        ],
+        b""
+      ),
+      "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU= sha384-OLBgp1GsljhM2TJ+sbHjaiH9txEUvgdDTAzHv2P24donTt6/529l+9Ua0vFImLlb"
+    );
+  }
+
+  #[test]
+  fn placeholder_roundtrip() {
+    let rendered = serde_json::to_string(&placeholder(IntegrityKind::Css, "src_a\"b")).unwrap();
+    let key = PLACEHOLDER_REGEX
+      .captures(&rendered)
+      .and_then(|captures| captures.get(1))
+      .map(|key| key.as_str().to_string());
+    assert_eq!(key, Some(placeholder_key(IntegrityKind::Css, "src_a\"b")));
+  }
+}
@@ -0,0 +1,313 @@
+#![feature(let_chains)]
+
+mod integrity;
+mod runtime;
+
+use std::collections::BTreeMap;
+
+pub use integrity::SubresourceIntegrityHashFunction;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{
+  rspack_sources::{RawSource, ReplaceSource, SourceExt},
+  ApplyContext, AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams,
+  CompilationProcessAssets, CompilationRuntimeRequirementInTree, CompilerCompilation,
+  CompilerOptions, CrossOriginLoading, Plugin, PluginContext, RuntimeGlobals,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_runtime::{
+  CreateLinkData, CreateScriptData, RuntimePlugin, RuntimePluginCreateLink,
+  RuntimePluginCreateScript,
+};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::integrity::{compute_integrity, placeholder_key, IntegrityKind, PLACEHOLDER_REGEX};
+use crate::runtime::SriHashesRuntimeModule;
+
+static HTML_TAG_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"<(?:script|link)\b[^>]*>").expect("should be valid regex"));
+static HTML_URL_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r#"\b(?:src|href)="([^"]*)""#).expect("should be valid regex"));
+static HTML_INTEGRITY_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r#"\bintegrity="[^"]*""#).expect("should be valid regex"));
+
+#[derive(Debug)]
+pub struct SubresourceIntegrityRspackPluginOptions {
+  pub hash_func_names: Vec<SubresourceIntegrityHashFunction>,
+  /// Filename of the manifest mapping each JS/CSS asset to its integrity
+  pub manifest: Option<String>,
+}
+
+/// Computes the integrity of every emitted JS/CSS chunk after `RealContentHashPlugin`, and
+/// embeds them into the chunk loading runtime so the lazily loaded chunks are checked as well.
+#[plugin]
+#[derive(Debug)]
+pub struct SubresourceIntegrityRspackPlugin {
+  options: SubresourceIntegrityRspackPluginOptions,
+}
+
+impl SubresourceIntegrityRspackPlugin {
+  pub fn new(options: SubresourceIntegrityRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+fn cross_origin(compilation: &Compilation) -> String {
+  match &compilation.options.output.cross_origin_loading {
+    CrossOriginLoading::Enable(cross_origin) => cross_origin.clone(),
+    // integrity checks of cross-origin resources require CORS
+    CrossOriginLoading::Disable => "anonymous".to_string(),
+  }
+}
+
+#[plugin_hook(CompilerCompilation for SubresourceIntegrityRspackPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let mut hooks = RuntimePlugin::get_compilation_hooks_mut(compilation);
+  hooks.create_script.tap(create_script::new(self));
+  hooks.create_link.tap(create_link::new(self));
+  Ok(())
+}
+
+#[plugin_hook(RuntimePluginCreateScript for SubresourceIntegrityRspackPlugin)]
+fn create_script(&self, compilation: &Compilation, data: &mut CreateScriptData) -> Result<()> {
+  data.code.push_str(&format!(
+    r#"
+    if (__webpack_require__.sriHashes && __webpack_require__.sriHashes[chunkId]) {{
+      script.integrity = __webpack_require__.sriHashes[chunkId];
+      script.crossOrigin = {};
+    }}
+    "#,
+    serde_json::to_string(&cross_origin(compilation)).expect("should serialize")
+  ));
+  Ok(())
+}
+
+#[plugin_hook(RuntimePluginCreateLink for SubresourceIntegrityRspackPlugin)]
+fn create_link(&self, compilation: &Compilation, data: &mut CreateLinkData) -> Result<()> {
+  data.code.push_str(&format!(
+    r#"
+    if (__webpack_require__.sriCssHashes && __webpack_require__.sriCssHashes[chunkId]) {{
+      link.integrity = __webpack_require__.sriCssHashes[chunkId];
+      link.crossOrigin = {};
+    }}
+    "#,
+    serde_json::to_string(&cross_origin(compilation)).expect("should serialize")
+  ));
+  Ok(())
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for SubresourceIntegrityRspackPlugin)]
+fn runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  _runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
+    compilation.add_runtime_module(chunk_ukey, Box::<SriHashesRuntimeModule>::default())?;
+  }
+  Ok(None)
+}
+
+#[plugin_hook(CompilationProcessAssets for SubresourceIntegrityRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_HASH + 1)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut placeholders: HashMap<String, String> = HashMap::default();
+  let mut files: Vec<String> = vec![];
+  let mut file_chunks: HashMap<String, String> = HashMap::default();
+  for chunk in compilation.chunk_by_ukey.values() {
+    for file in chunk.files.iter().sorted() {
+      let Some(kind) = IntegrityKind::from_filename(file) else {
+        continue;
+      };
+      if let Some(id) = &chunk.id {
+        placeholders.insert(placeholder_key(kind, id), file.clone());
+      }
+      if let Some(name) = chunk.name.as_ref().or(chunk.id.as_ref()) {
+        file_chunks.insert(file.clone(), name.clone());
+      }
+      files.push(file.clone());
+    }
+  }
+  files.sort_unstable();
+  files.dedup();
+
+  // the runtime chunks embed the integrity of the async chunks, so they are hashed after the
+  // chunks they reference
+  let mut integrities: HashMap<String, String> = HashMap::default();
+  let mut pending = files;
+  let mut resolve_all = false;
+  while !pending.is_empty() {
+    let pending_len = pending.len();
+    let mut deferred = vec![];
+    for file in pending {
+      let Some(source) = compilation
+        .assets()
+        .get(&file)
+        .and_then(|asset| asset.get_source())
+        .cloned()
+      else {
+        continue;
+      };
+      let content = source.source();
+      let is_ready = resolve_all
+        || PLACEHOLDER_REGEX.captures_iter(&content).all(|captures| {
+          placeholders.get(&captures[1]).map_or(true, |target| {
+            target == &file || integrities.contains_key(target)
+          })
+        });
+      if !is_ready {
+        deferred.push(file);
+        continue;
+      }
+      // replace the placeholders in place so the source map of the chunk is kept
+      let replacements = PLACEHOLDER_REGEX
+        .captures_iter(&content)
+        .filter_map(|captures| {
+          let placeholder = captures.get(0)?;
+          let integrity = placeholders
+            .get(&captures[1])
+            .and_then(|target| integrities.get(target))
+            .cloned()
+            .unwrap_or_default();
+          Some((placeholder.start(), placeholder.end(), integrity))
+        })
+        .collect::<Vec<_>>();
+      let integrity = if replacements.is_empty() {
+        compute_integrity(&self.options.hash_func_names, &source.buffer())
+      } else {
+        let mut replace_source = ReplaceSource::new(source.clone());
+        for (start, end, integrity) in replacements {
+          replace_source.replace(start as u32, end as u32, &integrity, None);
+        }
+        let replaced = replace_source.boxed();
+        let integrity = compute_integrity(&self.options.hash_func_names, &replaced.buffer());
+        compilation.update_asset(&file, |_, info| Ok((replaced, info)))?;
+        integrity
+      };
+      integrities.insert(file, integrity);
+    }
+    // the runtime chunks referencing each other can't be ordered, the integrity of the chunks in
+    // the cycle are left empty
+    if deferred.len() == pending_len && !resolve_all {
+      let chunks = deferred
+        .iter()
+        .map(|file| format!("\"{}\"", file_chunks.get(file).unwrap_or(file)))
+        .unique()
+        .join(", ");
+      compilation.push_diagnostic(Diagnostic::warn(
+        "SubresourceIntegrityRspackPlugin".to_string(),
+        format!(
+          "Chunks {chunks} load each other, their integrity can't be embedded into the runtime and is left empty, the chunks will be loaded without integrity checks"
+        ),
+      ));
+    }
+    resolve_all = deferred.len() == pending_len;
+    pending = deferred;
+  }
+
+  update_html_integrities(compilation, &integrities)?;
+
+  if let Some(manifest) = &self.options.manifest {
+    let manifest_content: BTreeMap<&String, &String> = integrities.iter().collect();
+    compilation.emit_asset(
+      manifest.clone(),
+      CompilationAsset::new(
+        Some(
+          RawSource::from(
+            serde_json::to_string_pretty(&manifest_content).expect("should serialize manifest"),
+          )
+          .boxed(),
+        ),
+        AssetInfo::default(),
+      ),
+    );
+  }
+  Ok(())
+}
+
+/// `HtmlRspackPlugin` computes the integrity of the injected tags before the assets are hashed,
+/// update them with the final values.
+fn update_html_integrities(
+  compilation: &mut Compilation,
+  integrities: &HashMap<String, String>,
+) -> Result<()> {
+  let html_files = compilation
+    .assets()
+    .iter()
+    .filter(|(name, asset)| name.ends_with(".html") && asset.get_source().is_some())
+    .map(|(name, _)| name.clone())
+    .collect::<HashSet<_>>();
+  for html_file in html_files.into_iter().sorted() {
+    compilation.update_asset(&html_file, |source, info| {
+      let content = source.source();
+      let replacements = HTML_TAG_REGEX
+        .find_iter(&content)
+        .filter_map(|tag| {
+          let integrity = HTML_URL_REGEX
+            .captures(tag.as_str())
+            .and_then(|url| {
+              let url = url[1].split(['?', '#']).next().unwrap_or_default();
+              integrities.iter().find(|(file, _)| {
+                url == file.as_str()
+                  || url
+                    .strip_suffix(file.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('/'))
+              })
+            })
+            .map(|(_, integrity)| integrity)?;
+          let attribute = HTML_INTEGRITY_REGEX.find(tag.as_str())?;
+          Some((
+            tag.start() + attribute.start(),
+            tag.start() + attribute.end(),
+            format!("integrity=\"{integrity}\""),
+          ))
+        })
+        .collect::<Vec<_>>();
+      if replacements.is_empty() {
+        return Ok((source, info));
+      }
+      let mut replace_source = ReplaceSource::new(source.clone());
+      for (start, end, attribute) in replacements {
+        replace_source.replace(start as u32, end as u32, &attribute, None);
+      }
+      Ok((replace_source.boxed(), info))
+    })?;
+  }
+  Ok(())
+}
+
+impl Plugin for SubresourceIntegrityRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SubresourceIntegrityRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirements_in_tree::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
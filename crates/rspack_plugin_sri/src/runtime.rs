@@ -0,0 +1,74 @@
+use itertools::Itertools;
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkUkey, Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_plugin_runtime::{chunk_has_css, chunk_has_js};
+use rspack_util::source_map::SourceMapKind;
+
+use crate::integrity::{placeholder, IntegrityKind};
+
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct SriHashesRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+}
+
+impl Default for SriHashesRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/sri_hashes"),
+      chunk: None,
+      source_map_kind: SourceMapKind::empty(),
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for SriHashesRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk_ukey = self.chunk.expect("should attach chunk for sri_hashes");
+    let chunk = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+    let async_chunks = chunk
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .into_iter()
+      .filter_map(|chunk| {
+        compilation
+          .chunk_by_ukey
+          .expect_get(&chunk)
+          .id
+          .clone()
+          .map(|id| (id, chunk))
+      })
+      .sorted_unstable_by(|a, b| a.0.cmp(&b.0))
+      .collect_vec();
+
+    let render = |kind: IntegrityKind, has_kind: fn(&ChunkUkey, &Compilation) -> bool| {
+      let hashes: serde_json::Map<String, serde_json::Value> = async_chunks
+        .iter()
+        .filter(|(_, chunk)| has_kind(chunk, compilation))
+        .map(|(id, _)| (id.clone(), placeholder(kind, id).into()))
+        .collect();
+      serde_json::Value::Object(hashes).to_string()
+    };
+    Ok(
+      RawSource::from(format!(
+        "__webpack_require__.sriHashes = {};\n__webpack_require__.sriCssHashes = {};\n",
+        render(IntegrityKind::Js, chunk_has_js),
+        render(IntegrityKind::Css, chunk_has_css)
+      ))
+      .boxed(),
+    )
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+}
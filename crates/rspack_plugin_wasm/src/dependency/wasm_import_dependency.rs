@@ -1,8 +1,11 @@
 use rspack_core::{
   AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
-  DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
+  DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, ModuleType,
+  RuntimeSpec,
 };
+use rspack_error::Diagnostic;
 use swc_core::ecma::atoms::Atom;
+use wasmparser::TypeRef;
 
 use crate::WasmNode;
 
@@ -11,7 +14,9 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: Atom,
   request: String,
-  // only_direct_import: bool,
+  /// The kind of the import which can't be wrapped by javascript, so `webassembly/sync`
+  /// modules can only import it from another `webassembly/sync` module.
+  only_direct_import: Option<&'static str>,
   /// the WASM AST node
   pub desc: WasmNode,
 
@@ -20,18 +25,28 @@ pub struct WasmImportDependency {
 
 impl WasmImportDependency {
   pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+    let only_direct_import = match desc {
+      TypeRef::Func(_) => None,
+      TypeRef::Table(_) => Some("Table"),
+      TypeRef::Memory(_) => Some("Memory"),
+      TypeRef::Global(_) => Some("Global"),
+      TypeRef::Tag(_) => Some("Tag"),
+    };
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      only_direct_import,
       span: None,
     }
   }
   pub fn name(&self) -> &str {
     &self.name
   }
+  pub fn only_direct_import(&self) -> Option<&str> {
+    self.only_direct_import
+  }
 }
 
 impl Dependency for WasmImportDependency {
@@ -54,6 +69,26 @@ impl Dependency for WasmImportDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "WasmImportDependency"
   }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let kind = self.only_direct_import?;
+    let parent = module_graph.get_parent_module(&self.id)?;
+    let parent = module_graph.module_by_identifier(parent)?;
+    let module = module_graph.get_module_by_dependency_id(&self.id)?;
+    if *parent.module_type() != ModuleType::WasmSync
+      || *module.module_type() == ModuleType::WasmSync
+    {
+      return None;
+    }
+    Some(vec![Diagnostic::error(
+      "UnsupportedWebAssemblyFeatureError".into(),
+      format!(
+        "Import \"{}\" from \"{}\" with {kind} can only be used for direct wasm to wasm dependencies",
+        self.name, self.request
+      ),
+    )
+    .with_module_identifier(Some(parent.identifier()))])
+  }
 }
 
 impl ModuleDependency for WasmImportDependency {
@@ -1,17 +1,161 @@
 use rspack_core::{
-  BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginContext,
-  PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoadingType,
+  BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, ModuleType, Plugin,
+  PluginContext, PluginExt, RuntimeGlobals, RuntimeModuleExt, SourceType, WasmLoadingType,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{
+  AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule, WasmSourceLoadingRuntimeModule,
+};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> Vec<BoxPlugin> {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => vec![
+      FetchCompileWasmPlugin::default().boxed(),
+      FetchCompileAsyncWasmPlugin::default().boxed(),
+    ],
+    WasmLoadingType::AsyncNode => vec![
+      ReadFileCompileWasmPlugin::new(false).boxed(),
+      ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
+    ],
+    WasmLoadingType::AsyncNodeModule => vec![
+      ReadFileCompileWasmPlugin::new(true).boxed(),
+      ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    ],
+  }
+}
+
+fn has_sync_wasm_module_in_graph(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> bool {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let module_graph = compilation.get_module_graph();
+  chunk
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .iter()
+    .chain(std::iter::once(chunk_ukey))
+    .any(|chunk_ukey| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules_by_source_type(chunk_ukey, SourceType::Wasm, &module_graph)
+        .iter()
+        .any(|m| *m.module_type() == ModuleType::WasmSync)
+    })
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FetchCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for FetchCompileWasmPlugin)]
+fn fetch_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && has_sync_wasm_module_in_graph(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+        true,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(fetch_compile_wasm_plugin_runtime_requirements_in_tree::new(
+        self,
+      ));
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {
+  import: bool,
+}
+
+impl ReadFileCompileWasmPlugin {
+  fn new(import: bool) -> Self {
+    Self::new_inner(import)
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileWasmPlugin)]
+fn read_file_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && has_sync_wasm_module_in_graph(compilation, chunk_ukey)
+  {
+    // Same as webpack, webassembly/sync modules are only loaded by `fetch` and `async-node`.
+    if self.import {
+      compilation.push_diagnostic(Diagnostic::error(
+        "UnsupportedWebAssemblyFeatureError".into(),
+        "webassembly/sync modules are not supported with wasmLoading \"async-node-module\", use \"async-node\" or experiments.asyncWebAssembly instead.".into(),
+      ));
+      return Ok(None);
+    }
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        include_str!("runtime/read_file_compile_async_wasm.js").to_string(),
+        false,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(read_file_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
   }
 }
 
@@ -39,6 +183,18 @@ fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
       .boxed(),
     )?;
   }
+  if runtime_requirements.contains(RuntimeGlobals::COMPILE_WASM) {
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmSourceLoadingRuntimeModule::new(
+        format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+        true,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
 
   Ok(None)
 }
@@ -72,6 +228,14 @@ impl ReadFileCompileAsyncWasmPlugin {
   fn new(import: bool) -> Self {
     Self::new_inner(import)
   }
+
+  fn read_file_code(&self) -> &'static str {
+    if self.import {
+      include_str!("runtime/read_file_compile_async_wasm_with_import.js")
+    } else {
+      include_str!("runtime/read_file_compile_async_wasm.js")
+    }
+  }
 }
 
 #[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileAsyncWasmPlugin)]
@@ -86,16 +250,15 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
-      AsyncWasmLoadingRuntimeModule::new(
-        if self.import {
-          include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
-        } else {
-          include_str!("runtime/read_file_compile_async_wasm.js").to_string()
-        },
-        false,
-        *chunk_ukey,
-      )
-      .boxed(),
+      AsyncWasmLoadingRuntimeModule::new(self.read_file_code().to_string(), false, *chunk_ukey)
+        .boxed(),
+    )?;
+  }
+  if runtime_requirements.contains(RuntimeGlobals::COMPILE_WASM) {
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmSourceLoadingRuntimeModule::new(self.read_file_code().to_string(), false, *chunk_ukey)
+        .boxed(),
     )?;
   }
 
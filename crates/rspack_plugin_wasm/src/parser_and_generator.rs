@@ -1,17 +1,20 @@
 use std::collections::hash_map::DefaultHasher;
+use std::fmt::Write;
 use std::hash::{Hash, Hasher};
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
 use rspack_core::DependencyType::WasmImport;
 use rspack_core::{
-  AssetInfo, BoxDependency, BuildMetaExportsType, Compilation, FilenameTemplate, GenerateContext,
-  Module, ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult,
-  ParserAndGenerator, PathData, RuntimeGlobals, SourceType, StaticExportsDependency,
-  StaticExportsSpec, UsedName,
+  to_identifier, AssetInfo, BoxDependency, BuildMetaExportsType, ChunkInitFragments,
+  ChunkRenderContext, Compilation, FilenameTemplate, GenerateContext, InitFragment,
+  InitFragmentContents, InitFragmentExt, InitFragmentKey, InitFragmentStage, Module,
+  ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult, ParserAndGenerator,
+  PathData, RuntimeGlobals, SourceType, StaticExportsDependency, StaticExportsSpec, UsedName,
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
+use rspack_plugin_runtime::get_relative_path;
 use rspack_util::infallible::ResultInfallibleExt as _;
 use swc_core::atoms::Atom;
 use wasmparser::{Import, Parser, Payload};
@@ -22,6 +25,14 @@ use crate::ModuleIdToFileName;
 #[derive(Debug)]
 pub struct AsyncWasmParserAndGenerator {
   pub(crate) module_id_to_filename: ModuleIdToFileName,
+  /// Instantiate from a static `import source` of the wasm file, only works with `output.module`.
+  pub(crate) esm_integration: bool,
+}
+
+impl AsyncWasmParserAndGenerator {
+  fn use_esm_integration(&self, compilation: &Compilation) -> bool {
+    self.esm_integration && compilation.options.output.module
+  }
 }
 
 pub(crate) static WASM_SOURCE_TYPE: &[SourceType; 2] = &[SourceType::Wasm, SourceType::JavaScript];
@@ -32,88 +43,12 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 
   fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
-    parse_context.build_info.strict = true;
     parse_context.build_meta.has_top_level_await = true;
-    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
-
-    let source = parse_context.source;
-
-    let mut exports = Vec::with_capacity(1);
-    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
-    let mut diagnostic = Vec::with_capacity(1);
-
-    for payload in Parser::new(0).parse_all(&source.buffer()) {
-      match payload {
-        Ok(payload) => match payload {
-          Payload::ExportSection(s) => {
-            for export in s {
-              match export {
-                Ok(export) => exports.push(export.name.to_string()),
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Export Parse Error".into(),
-                  err.to_string(),
-                )),
-              };
-            }
-          }
-          Payload::ImportSection(s) => {
-            for import in s {
-              match import {
-                Ok(Import { module, name, ty }) => {
-                  dependencies.push(Box::new(WasmImportDependency::new(
-                    module.into(),
-                    name.into(),
-                    ty,
-                  )));
-                }
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Import Parse Error".into(),
-                  err.to_string(),
-                )),
-              }
-            }
-          }
-          _ => {}
-        },
-        Err(err) => {
-          diagnostic.push(Diagnostic::error(
-            "Wasm Parse Error".into(),
-            err.to_string(),
-          ));
-        }
-      }
-    }
-
-    dependencies.push(Box::new(StaticExportsDependency::new(
-      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
-      false,
-    )));
-
-    Ok(
-      ParseResult {
-        dependencies,
-        blocks: vec![],
-        presentational_dependencies: vec![],
-        code_generation_dependencies: vec![],
-        source,
-        analyze_result: Default::default(),
-        side_effects_bailout: None,
-      }
-      .with_diagnostic(diagnostic),
-    )
+    parse_wasm(parse_context)
   }
 
   fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
-    match source_type {
-      SourceType::JavaScript => {
-        40.0
-          + module
-            .get_presentational_dependencies()
-            .map_or(0.0, |i| i.len() as f64 * 10.0)
-      }
-      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
-      _ => 0.0,
-    }
+    wasm_size(module, source_type)
   }
 
   #[allow(clippy::unwrap_in_result)]
@@ -139,6 +74,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
     self
       .module_id_to_filename
       .insert(module.identifier(), wasm_path_with_info.clone());
+    let esm_integration = self.use_esm_integration(compilation);
 
     match generate_context.requested_source_type {
       SourceType::JavaScript => {
@@ -146,7 +82,9 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
         runtime_requirements.insert(RuntimeGlobals::MODULE);
         runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
         runtime_requirements.insert(RuntimeGlobals::EXPORTS);
-        runtime_requirements.insert(RuntimeGlobals::INSTANTIATE_WASM);
+        if !esm_integration {
+          runtime_requirements.insert(RuntimeGlobals::INSTANTIATE_WASM);
+        }
 
         let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
         let mut wasm_deps_by_request = IndexMap::<&str, Vec<(Identifier, String, String)>>::new();
@@ -240,12 +178,33 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
           None
         };
 
-        let instantiate_call = format!(
-          "{}(exports, module.id, {} {})",
-          RuntimeGlobals::INSTANTIATE_WASM,
-          serde_json::to_string(&hash).expect("should be ok"),
-          imports_obj.unwrap_or_default()
-        );
+        let instantiate_call = if esm_integration {
+          let module_id = chunk_graph
+            .get_module_id(module.identifier())
+            .as_deref()
+            .expect("should have module id");
+          let source_var = format!("__webpack_wasm_source_{}__", to_identifier(module_id));
+          let chunk_init_fragments: ChunkInitFragments = vec![WasmSourceImportInitFragment {
+            source_var: source_var.clone(),
+            key: InitFragmentKey::WasmSourceImport(source_var.clone()),
+            wasm_filename: wasm_path_with_info.0.clone(),
+          }
+          .boxed()];
+          generate_context.data.insert(chunk_init_fragments);
+          // instantiate asynchronously, browsers refuse to compile large modules synchronously
+          // on the main thread
+          format!(
+            "WebAssembly.instantiate({source_var}{}).then(function (instance) {{ return Object.assign(exports, instance.exports); }})",
+            imports_obj.unwrap_or_default()
+          )
+        } else {
+          format!(
+            "{}(exports, module.id, {} {})",
+            RuntimeGlobals::INSTANTIATE_WASM,
+            serde_json::to_string(&hash).expect("should be ok"),
+            imports_obj.unwrap_or_default()
+          )
+        };
 
         let source = if !promises.is_empty() {
           generate_context
@@ -296,7 +255,206 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_wasm(parse_context)
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    wasm_size(module, source_type)
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    self.module_id_to_filename.insert(
+      module.identifier(),
+      render_wasm_name(
+        compilation,
+        normal_module,
+        &compilation.options.output.webassembly_module_filename,
+        &hash_for_source(source),
+      ),
+    );
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+        runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+
+        // The wasm module is instantiated while its chunk loads, the imported modules are
+        // executed here to keep the order of esm.
+        let module_graph = compilation.get_module_graph();
+        let mut imported_modules = IndexSet::<ModuleIdentifier>::default();
+        for dep in module
+          .get_dependencies()
+          .iter()
+          .filter_map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+        {
+          if let Some(mgm) = module_graph.module_graph_module_by_dependency_id(dep.id()) {
+            imported_modules.insert(mgm.module_identifier);
+          }
+        }
+        if !imported_modules.is_empty() {
+          runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        }
+        let imports_code =
+          imported_modules
+            .iter()
+            .fold(String::new(), |mut imports_code, identifier| {
+              let module_id = compilation
+                .chunk_graph
+                .get_module_id(*identifier)
+                .as_deref()
+                .expect("should have module id");
+              writeln!(
+                imports_code,
+                "{}({});",
+                RuntimeGlobals::REQUIRE,
+                serde_json::to_string(module_id).expect("should be ok")
+              )
+              .expect("should write to string");
+              imports_code
+            });
+
+        Ok(
+          RawSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {wasm_instances}[module.id];
+{make_namespace_object}(exports);
+// export exports from WebAssembly module
+for(var name in wasmExports) if(name) exports[name] = wasmExports[name];
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+            make_namespace_object = RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<String> {
+    Some(String::from(
+      "Module Concatenation is not implemented for SyncWasmParserAndGenerator",
+    ))
+  }
+}
+
+fn parse_wasm(parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+  parse_context.build_info.strict = true;
+  parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+  let source = parse_context.source;
+
+  let mut exports = Vec::with_capacity(1);
+  let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+  let mut diagnostic = Vec::with_capacity(1);
+
+  for payload in Parser::new(0).parse_all(&source.buffer()) {
+    match payload {
+      Ok(payload) => match payload {
+        Payload::ExportSection(s) => {
+          for export in s {
+            match export {
+              Ok(export) => exports.push(export.name.to_string()),
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Export Parse Error".into(),
+                err.to_string(),
+              )),
+            };
+          }
+        }
+        Payload::ImportSection(s) => {
+          for import in s {
+            match import {
+              Ok(Import { module, name, ty }) => {
+                dependencies.push(Box::new(WasmImportDependency::new(
+                  module.into(),
+                  name.into(),
+                  ty,
+                )));
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Import Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        _ => {}
+      },
+      Err(err) => {
+        diagnostic.push(Diagnostic::error(
+          "Wasm Parse Error".into(),
+          err.to_string(),
+        ));
+      }
+    }
+  }
+
+  dependencies.push(Box::new(StaticExportsDependency::new(
+    StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
+    false,
+  )));
+
+  Ok(
+    ParseResult {
+      dependencies,
+      blocks: vec![],
+      presentational_dependencies: vec![],
+      code_generation_dependencies: vec![],
+      source,
+      analyze_result: Default::default(),
+      side_effects_bailout: None,
+    }
+    .with_diagnostic(diagnostic),
+  )
+}
+
+fn wasm_size(module: &dyn Module, source_type: &SourceType) -> f64 {
+  match source_type {
+    SourceType::JavaScript => {
+      40.0
+        + module
+          .get_presentational_dependencies()
+          .map_or(0.0, |i| i.len() as f64 * 10.0)
+    }
+    SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+    _ => 0.0,
+  }
+}
+
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &FilenameTemplate,
@@ -313,12 +471,47 @@ fn render_wasm_name(
     .always_ok()
 }
 
+/// `import source` declaration of a wasm file, the specifier is relative to the chunk it is
+/// rendered into.
+#[derive(Debug, Clone, Hash)]
+struct WasmSourceImportInitFragment {
+  source_var: String,
+  key: InitFragmentKey,
+  wasm_filename: String,
+}
+
+impl InitFragment<ChunkRenderContext> for WasmSourceImportInitFragment {
+  fn contents(self: Box<Self>, context: &mut ChunkRenderContext) -> Result<InitFragmentContents> {
+    let request = get_relative_path(&context.output_path, &self.wasm_filename);
+    Ok(InitFragmentContents {
+      start: format!(
+        "import source {} from {};\n",
+        self.source_var,
+        serde_json::to_string(&request).expect("should be ok")
+      ),
+      end: None,
+    })
+  }
+
+  fn stage(&self) -> InitFragmentStage {
+    InitFragmentStage::StageHarmonyImports
+  }
+
+  fn position(&self) -> i32 {
+    0
+  }
+
+  fn key(&self) -> &InitFragmentKey {
+    &self.key
+  }
+}
+
 fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
@@ -1,12 +1,17 @@
+use indexmap::{IndexMap, IndexSet};
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, Dependency,
+  Module, ModuleDependency, ModuleIdentifier, ModuleType, NormalModule, NormalModuleSource,
+  PathData, RuntimeGlobals, RuntimeModule, RuntimeModuleStage, RuntimeSpec, SourceType, UsedName,
 };
 use rspack_identifier::Identifier;
 use rspack_util::infallible::ResultInfallibleExt as _;
 use rspack_util::source_map::SourceMapKind;
 
+use crate::dependency::WasmImportDependency;
+use crate::{hash_for_source, render_wasm_name};
+
 #[impl_runtime_module]
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -126,3 +131,428 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     )
   }
 }
+
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      supports_streaming,
+      chunk,
+      source_map_kind: SourceMapKind::empty(),
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let module_graph = compilation.get_module_graph();
+    let with_hmr = compilation
+      .chunk_graph
+      .get_chunk_graph_chunk(&self.chunk)
+      .runtime_requirements
+      .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
+
+    let mut wasm_module_map = IndexMap::<String, Vec<String>>::new();
+    let mut wasm_module_files = IndexMap::<String, String>::new();
+    let mut wasm_modules = IndexSet::<ModuleIdentifier>::default();
+    let mut chunks = chunk
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .into_iter()
+      .collect::<Vec<_>>();
+    chunks.push(self.chunk);
+    for chunk_ukey in chunks {
+      let c = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+      let mut module_ids = compilation
+        .chunk_graph
+        .get_chunk_modules_by_source_type(&chunk_ukey, SourceType::Wasm, &module_graph)
+        .into_iter()
+        .filter(|m| *m.module_type() == ModuleType::WasmSync)
+        .map(|m| {
+          wasm_modules.insert(m.identifier());
+          get_module_id(compilation, m.identifier()).to_string()
+        })
+        .collect::<Vec<_>>();
+      if module_ids.is_empty() {
+        continue;
+      }
+      module_ids.sort_unstable();
+      if let Some(chunk_id) = &c.id {
+        wasm_module_map.insert(chunk_id.clone(), module_ids);
+      }
+    }
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    for identifier in &wasm_modules {
+      let module = module_graph
+        .module_by_identifier(identifier)
+        .expect("should have module");
+      let normal_module = module
+        .as_normal_module()
+        .expect("webassembly/sync module should be a NormalModule");
+      if let Some(filename) = get_wasm_module_file(compilation, normal_module) {
+        wasm_module_files.insert(
+          get_module_id(compilation, *identifier).to_string(),
+          filename,
+        );
+      }
+      import_objects.push(generate_import_object(
+        compilation,
+        module.as_ref(),
+        &chunk.runtime,
+        &mut declarations,
+      ));
+    }
+
+    let state_expression = if with_hmr {
+      format!(
+        "{}_wasm = {}_wasm || ",
+        RuntimeGlobals::HMR_RUNTIME_STATE_PREFIX,
+        RuntimeGlobals::HMR_RUNTIME_STATE_PREFIX
+      )
+    } else {
+      String::new()
+    };
+    let req = self
+      .generate_load_binary_code
+      .replace("$PATH", "wasmModuleFiles[wasmModuleId]");
+    let instantiate = if self.supports_streaming {
+      r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+          promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else if(typeof WebAssembly.instantiateStreaming === 'function') {
+          promise = WebAssembly.instantiateStreaming(req, importObject);
+        } else {
+          var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });
+        }"#
+    } else {
+      r#"if(importObject && typeof importObject.then === 'function') {
+          var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+          promise = Promise.all([
+            bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }),
+            importObject
+          ]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else {
+          var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });
+        }"#
+    };
+
+    Ok(
+      RawSource::from(format!(
+        r#"// object to store loaded and loading wasm modules
+var installedWasmModules = {state_expression}{{}};
+function promiseResolve() {{ return Promise.resolve(); }}
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+var wasmModuleMap = {wasm_module_map};
+var wasmModuleFiles = {wasm_module_files};
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData)
+      promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var req = {req};
+      var promise;
+      {instantiate}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+        declarations = declarations.join("\n"),
+        import_objects = import_objects.join(",\n"),
+        wasm_module_map = serde_json::to_string(&wasm_module_map).expect("should be ok"),
+        wasm_module_files = serde_json::to_string(&wasm_module_files).expect("should be ok"),
+        wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+        ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+        req = req.trim().trim_end_matches(';'),
+      ))
+      .boxed(),
+    )
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
+
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct WasmSourceLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmSourceLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_source_loading"),
+      supports_streaming,
+      chunk,
+      source_map_kind: SourceMapKind::empty(),
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for WasmSourceLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let module_graph = compilation.get_module_graph();
+
+    let mut wasm_module_files = IndexMap::<String, String>::new();
+    for chunk_ukey in chunk.get_all_referenced_chunks(&compilation.chunk_group_by_ukey) {
+      for module in compilation.chunk_graph.get_chunk_modules_by_source_type(
+        &chunk_ukey,
+        SourceType::Wasm,
+        &module_graph,
+      ) {
+        if let Some(normal_module) = module.as_normal_module()
+          && let Some(filename) = get_wasm_module_file(compilation, normal_module)
+        {
+          wasm_module_files.insert(
+            get_module_id(compilation, module.identifier()).to_string(),
+            filename,
+          );
+        }
+      }
+    }
+    wasm_module_files.sort_unstable_keys();
+
+    let req = self
+      .generate_load_binary_code
+      .replace("$PATH", "wasmModuleFiles[wasmModuleId]");
+    let compile = if self.supports_streaming {
+      r#"req.then(function(res) {
+    var fallback = function() {
+      return req.then(function(x) { return x.arrayBuffer(); }).then(function(bytes) { return WebAssembly.compile(bytes); });
+    };
+    if(typeof WebAssembly.compileStreaming === 'function') {
+      return WebAssembly.compileStreaming(res).catch(function(e) {
+        if(res.headers.get("Content-Type") !== "application/wasm") {
+          console.warn("`WebAssembly.compileStreaming` failed because your server does not serve wasm with `application/wasm` MIME type. Falling back to `WebAssembly.compile` which is slower. Original error:\n", e);
+          return fallback();
+        }
+        throw e;
+      });
+    }
+    return fallback();
+  })"#
+    } else {
+      r#"req.then(function(x) { return x.arrayBuffer(); }).then(function(bytes) { return WebAssembly.compile(bytes); })"#
+    };
+
+    Ok(
+      RawSource::from(format!(
+        r#"var wasmModuleFiles = {wasm_module_files};
+// object to store compiled and compiling wasm modules of source phase imports
+var compiledWasmModules = {{}};
+{compile_wasm} = function(wasmModuleId) {{
+  if(compiledWasmModules[wasmModuleId]) return compiledWasmModules[wasmModuleId];
+  var req = {req};
+  return compiledWasmModules[wasmModuleId] = {compile};
+}};
+"#,
+        wasm_module_files = serde_json::to_string(&wasm_module_files).expect("should be ok"),
+        compile_wasm = RuntimeGlobals::COMPILE_WASM,
+        req = req.trim().trim_end_matches(';'),
+      ))
+      .boxed(),
+    )
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
+
+/// The emitted filename of a wasm module, same as the one rendered by its generator.
+fn get_wasm_module_file(compilation: &Compilation, normal_module: &NormalModule) -> Option<String> {
+  let NormalModuleSource::BuiltSucceed(source) = normal_module.source() else {
+    return None;
+  };
+  let (filename, _) = render_wasm_name(
+    compilation,
+    normal_module,
+    &compilation.options.output.webassembly_module_filename,
+    &hash_for_source(source),
+  );
+  Some(filename)
+}
+
+fn get_module_id(compilation: &Compilation, identifier: ModuleIdentifier) -> &str {
+  compilation
+    .chunk_graph
+    .get_module_id(identifier)
+    .as_deref()
+    .expect("should have module id")
+}
+
+/// Generates the function which creates the import object of a `webassembly/sync` module.
+/// Direct wasm to wasm imports wait for the instance of the imported wasm module, other
+/// imports are wrapped lazily since the imported javascript module isn't executed yet.
+fn generate_import_object(
+  compilation: &Compilation,
+  module: &dyn Module,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = compilation.get_module_graph();
+  let mut wait_for_instances = IndexMap::<String, &str>::new();
+  let mut properties_by_request = IndexMap::<&str, Vec<String>>::new();
+
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+  {
+    let Some(imported_module) = module_graph.get_module_by_dependency_id(dep.id()) else {
+      continue;
+    };
+    let Some(UsedName::Str(used_name)) = module_graph
+      .get_exports_info(&imported_module.identifier())
+      .id
+      .get_used_name(
+        &module_graph,
+        Some(runtime),
+        UsedName::Str(dep.name().into()),
+      )
+    else {
+      continue;
+    };
+    let used_name = serde_json::to_string(&used_name).expect("should be ok");
+    let imported_module_id = get_module_id(compilation, imported_module.identifier());
+    let value = if dep.only_direct_import().is_some() {
+      let instance_var = format!("m{}", wait_for_instances.len());
+      let value = format!("{instance_var}[{used_name}]");
+      wait_for_instances.insert(instance_var, imported_module_id);
+      value
+    } else {
+      let module = format!(
+        "{}[{}]",
+        RuntimeGlobals::MODULE_CACHE,
+        serde_json::to_string(imported_module_id).expect("should be ok")
+      );
+      let cache = format!("wasmImportedFuncCache{}", declarations.len());
+      declarations.push(format!("var {cache};"));
+      let mod_code = if imported_module.module_type().is_wasm_like() {
+        format!("{module} ? {module}.exports[{used_name}] : ")
+      } else {
+        String::new()
+      };
+      format!(
+        r#"{mod_code}function() {{
+        if({cache} === undefined) {cache} = {module}.exports;
+        return {cache}[{used_name}].apply({cache}, arguments);
+      }}"#
+      )
+    };
+    properties_by_request
+      .entry(dep.request())
+      .or_default()
+      .push(format!(
+        "{}: {value}",
+        serde_json::to_string(dep.name()).expect("should be ok")
+      ));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties_by_request
+      .into_iter()
+      .map(|(request, properties)| format!(
+        "  {}: {{\n      {}\n    }}",
+        serde_json::to_string(request).expect("should be ok"),
+        properties.join(",\n      ")
+      ))
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+  let module_id =
+    serde_json::to_string(get_module_id(compilation, module.identifier())).expect("should be ok");
+  let installed = |id: &str| {
+    format!(
+      "installedWasmModules[{}]",
+      serde_json::to_string(id).expect("should be ok")
+    )
+  };
+
+  match wait_for_instances.len() {
+    0 => format!("{module_id}: function() {{\n  {import_object}\n}}"),
+    1 => {
+      let (variable, id) = wait_for_instances.first().expect("should have instance");
+      format!(
+        "{module_id}: function() {{\n  return promiseResolve().then(function() {{ return {}; }}).then(function({variable}) {{\n    {import_object}\n  }});\n}}",
+        installed(id)
+      )
+    }
+    _ => {
+      let promises = wait_for_instances
+        .values()
+        .map(|id| installed(id))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let variables = wait_for_instances
+        .keys()
+        .enumerate()
+        .map(|(i, variable)| format!("{variable} = array[{i}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!(
+        "{module_id}: function() {{\n  return promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{\n    var {variables};\n    {import_object}\n  }});\n}}"
+      )
+    }
+  }
+}
@@ -3,21 +3,28 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 use rayon::prelude::*;
 use rspack_core::{
-  ApplyContext, ChunkUkey, Compilation, CompilationParams, CompilationRenderManifest,
-  CompilerCompilation, CompilerOptions, DependencyType, ModuleType, ParserAndGenerator, Plugin,
-  PluginContext, RenderManifestEntry, SourceType,
+  ApplyContext, ChunkUkey, Compilation, CompilationOptimizeChunks, CompilationParams,
+  CompilationRenderManifest, CompilerCompilation, CompilerOptions, DependencyType, ModuleType,
+  ParserAndGenerator, Plugin, PluginContext, RenderManifestEntry, SourceType,
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
 #[plugin]
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct AsyncWasmPlugin {
   pub module_id_to_filename_without_ext: ModuleIdToFileName,
+  esm_integration: bool,
+}
+
+impl AsyncWasmPlugin {
+  pub fn new(esm_integration: bool) -> Self {
+    Self::new_inner(Default::default(), esm_integration)
+  }
 }
 
 #[plugin_hook(CompilerCompilation for AsyncWasmPlugin)]
@@ -44,6 +51,63 @@ async fn render_manifest(
   chunk_ukey: &ChunkUkey,
   manifest: &mut Vec<RenderManifestEntry>,
   _diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  render_wasm_manifest(
+    compilation,
+    chunk_ukey,
+    manifest,
+    ModuleType::WasmAsync,
+    &self.module_id_to_filename_without_ext,
+  )
+}
+
+#[async_trait]
+impl Plugin for AsyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.AsyncWebAssemblyModulesPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .render_manifest
+      .tap(render_manifest::new(self));
+
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+    let esm_integration = self.esm_integration;
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::WasmAsync,
+      Box::new(move |_, _| {
+        Box::new({
+          AsyncWasmParserAndGenerator {
+            module_id_to_filename: module_id_to_filename_without_ext.clone(),
+            esm_integration,
+          }
+        }) as Box<dyn ParserAndGenerator>
+      }),
+    );
+
+    Ok(())
+  }
+}
+
+fn render_wasm_manifest(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  module_type: ModuleType,
+  module_id_to_filename: &ModuleIdToFileName,
 ) -> Result<()> {
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
   let module_graph = &compilation.get_module_graph();
@@ -54,15 +118,14 @@ async fn render_manifest(
 
   let files = ordered_modules
     .par_iter()
-    .filter(|m| *m.module_type() == ModuleType::WasmAsync)
+    .filter(|m| *m.module_type() == module_type)
     .map(|m| {
       let code_gen_result = compilation
         .code_generation_results
         .get(&m.identifier(), Some(&chunk.runtime));
 
       let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-        let (output_path, asset_info) = self
-          .module_id_to_filename_without_ext
+        let (output_path, asset_info) = module_id_to_filename
           .get(&m.identifier())
           .map(|s| s.clone())
           .expect("should have wasm_filename");
@@ -80,10 +143,79 @@ async fn render_manifest(
   Ok(())
 }
 
+#[plugin]
+#[derive(Debug, Default)]
+pub struct WasmPlugin {
+  pub module_id_to_filename: ModuleIdToFileName,
+}
+
+#[plugin_hook(CompilerCompilation for WasmPlugin)]
+async fn sync_compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::WasmImport,
+    params.normal_module_factory.clone(),
+  );
+  compilation.set_dependency_factory(
+    DependencyType::WasmExportImported,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilationOptimizeChunks for WasmPlugin)]
+fn sync_optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<bool>> {
+  let module_graph = compilation.get_module_graph();
+  let mut initial_wasm_modules = compilation
+    .chunk_by_ukey
+    .values()
+    .filter(|chunk| chunk.can_be_initial(&compilation.chunk_group_by_ukey))
+    .flat_map(|chunk| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules(&chunk.ukey, &module_graph)
+    })
+    .filter(|m| *m.module_type() == ModuleType::WasmSync)
+    .map(|m| m.identifier())
+    .collect::<Vec<_>>();
+  initial_wasm_modules.sort_unstable();
+  initial_wasm_modules.dedup();
+  for module in initial_wasm_modules {
+    compilation.push_diagnostic(
+      Diagnostic::error(
+        "WebAssemblyInInitialChunkError".into(),
+        "WebAssembly module is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module.".into(),
+      )
+      .with_module_identifier(Some(module)),
+    );
+  }
+  Ok(None)
+}
+
+#[plugin_hook(CompilationRenderManifest for WasmPlugin)]
+async fn sync_render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  _diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  render_wasm_manifest(
+    compilation,
+    chunk_ukey,
+    manifest,
+    ModuleType::WasmSync,
+    &self.module_id_to_filename,
+  )
+}
+
 #[async_trait]
-impl Plugin for AsyncWasmPlugin {
+impl Plugin for WasmPlugin {
   fn name(&self) -> &'static str {
-    "rspack.AsyncWebAssemblyModulesPlugin"
+    "rspack.WebAssemblyModulesPlugin"
   }
 
   fn apply(
@@ -95,21 +227,26 @@ impl Plugin for AsyncWasmPlugin {
       .context
       .compiler_hooks
       .compilation
-      .tap(compilation::new(self));
+      .tap(sync_compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .optimize_chunks
+      .tap(sync_optimize_chunks::new(self));
     ctx
       .context
       .compilation_hooks
       .render_manifest
-      .tap(render_manifest::new(self));
+      .tap(sync_render_manifest::new(self));
 
-    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+    let module_id_to_filename = self.module_id_to_filename.clone();
 
     ctx.context.register_parser_and_generator_builder(
-      ModuleType::WasmAsync,
+      ModuleType::WasmSync,
       Box::new(move |_, _| {
         Box::new({
-          AsyncWasmParserAndGenerator {
-            module_id_to_filename: module_id_to_filename_without_ext.clone(),
+          SyncWasmParserAndGenerator {
+            module_id_to_filename: module_id_to_filename.clone(),
           }
         }) as Box<dyn ParserAndGenerator>
       }),
@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::Range;
 
 use regex_syntax::hir::literal::ExtractKind;
 use regex_syntax::hir::{Hir, HirKind, Look};
@@ -97,6 +98,17 @@ impl Algo {
     }
   }
 
+  /// The fast path doesn't track capture groups, so the whole match is returned for it.
+  pub(crate) fn find_group(&self, str: &str, group: usize) -> Option<Range<usize>> {
+    match self {
+      Algo::Regress(regex) => regex.find(str)?.group(group),
+      Algo::EndWith { pats } => pats
+        .iter()
+        .find(|pat| str.ends_with(pat.as_str()))
+        .map(|pat| str.len() - pat.len()..str.len()),
+    }
+  }
+
   pub(crate) fn global(&self) -> bool {
     match self {
       Algo::Regress(reg) => reg.flags.contains('g'),
@@ -1,6 +1,7 @@
 #![feature(let_chains)]
 
 use std::fmt::Debug;
+use std::ops::Range;
 
 use rspack_error::Error;
 use swc_core::ecma::ast::Regex as SwcRegex;
@@ -30,6 +31,11 @@ impl RspackRegex {
     self.algo.test(text)
   }
 
+  /// Return the range of a capture group in the first match, group `0` is the whole match.
+  pub fn find_group(&self, text: &str, group: usize) -> Option<Range<usize>> {
+    self.algo.find_group(text, group)
+  }
+
   pub fn global(&self) -> bool {
     self.algo.global()
   }
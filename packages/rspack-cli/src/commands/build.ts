@@ -109,7 +109,14 @@ export class BuildCommand implements RspackCommand {
 				if (cli.isWatch(compiler)) {
 					return;
 				} else {
-					compiler.run(errorHandler);
+					compiler.run((error, stats) => {
+						compiler.close(closeError => {
+							if (closeError) {
+								logger.error(closeError);
+							}
+							errorHandler(error, stats);
+						});
+					});
 				}
 			}
 		);
@@ -62,15 +62,15 @@ export class HotProcessor<T extends ECompilerType> extends BasicProcessor<T> {
 			this._hotOptions.target === "webworker"
 		) {
 			for (const file of info.entrypoints!.main.assets!) {
-				if (file.name.endsWith(".js")) {
+				if (file.name.endsWith(".js") || file.name.endsWith(".mjs")) {
 					files.push(file.name);
 				} else {
 					prefiles.push(file.name);
 				}
 			}
 		} else {
-			const assets = info.entrypoints!.main.assets!.filter(s =>
-				s.name.endsWith(".js")
+			const assets = info.entrypoints!.main.assets!.filter(
+				s => s.name.endsWith(".js") || s.name.endsWith(".mjs")
 			);
 			files.push(assets[assets.length - 1].name);
 		}
@@ -9,11 +9,11 @@ import urlToRelativePath from "../../../helper/legacy/urlToRelativePath";
 import { ECompilerType } from "../../../type";
 import { TBasicRunnerFile, TRunnerRequirer } from "../../type";
 import { IBasicRunnerOptions } from "../basic";
-import { CommonJsRunner } from "../cjs";
+import { EsmRunner } from "../esm";
 
 export class FakeDocumentWebRunner<
 	T extends ECompilerType = ECompilerType.Rspack
-> extends CommonJsRunner<T> {
+> extends EsmRunner<T> {
 	private document: FakeDocument;
 	private oldCurrentScript: CurrentScript | null = null;
 	constructor(protected _webOptions: IBasicRunnerOptions<T>) {
@@ -29,7 +29,7 @@ export class FakeDocumentWebRunner<
 	}
 
 	run(file: string) {
-		if (!file.endsWith(".js")) {
+		if (!file.endsWith(".js") && !file.endsWith(".mjs")) {
 			const cssElement = this.document.createElement("link");
 			cssElement.href = file;
 			cssElement.rel = "stylesheet";
@@ -57,6 +57,11 @@ export class FakeDocumentWebRunner<
 						err ? reject(err) : resolve(b)
 					)
 				);
+				if (url.endsWith(".wasm")) {
+					return new Response(buffer, {
+						headers: { "Content-Type": "application/wasm" }
+					});
+				}
 				return {
 					status: 200,
 					ok: true,
@@ -142,7 +147,6 @@ export class FakeDocumentWebRunner<
 
 	protected createRunner() {
 		super.createRunner();
-		this.requirers.set("cjs", this.getRequire());
 		this.requirers.set("json", this.createJsonRequirer());
 		this.requirers.set("entry", (_, modulePath, context) => {
 			if (Array.isArray(modulePath)) {
@@ -154,6 +158,15 @@ export class FakeDocumentWebRunner<
 					modulePath,
 					context
 				);
+			} else if (
+				modulePath.endsWith(".mjs") &&
+				this._options.compilerOptions.experiments?.outputModule
+			) {
+				return this.requirers.get("esm")!(
+					this._options.dist,
+					modulePath,
+					context
+				);
 			} else {
 				return this.requirers.get("cjs")!(
 					this._options.dist,
@@ -117,6 +117,11 @@ export class JSDOMWebRunner<
 						err ? reject(err) : resolve(b)
 					)
 				);
+				if (url.endsWith(".wasm")) {
+					return new Response(buffer, {
+						headers: { "Content-Type": "application/wasm" }
+					});
+				}
 				return {
 					status: 200,
 					ok: true,
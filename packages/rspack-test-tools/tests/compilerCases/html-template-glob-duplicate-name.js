@@ -0,0 +1,37 @@
+const { rspack } = require("@rspack/core");
+
+let error;
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description:
+		"should fail when templates matched by templateGlob have the same name",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./a",
+			output: {
+				path: context.getDist()
+			},
+			plugins: [
+				new rspack.HtmlRspackPlugin({
+					templateGlob: "./html-template-glob/*/index.html"
+				})
+			]
+		};
+	},
+	async build(_, compiler) {
+		await new Promise(resolve => {
+			compiler.run(err => {
+				error = err;
+				resolve();
+			});
+		});
+	},
+	async check() {
+		expect(error).toBeTruthy();
+		expect(error.message).toContain(
+			"matches multiple templates named `index`: `html-template-glob/a/index.html` and `html-template-glob/b/index.html`"
+		);
+	}
+};
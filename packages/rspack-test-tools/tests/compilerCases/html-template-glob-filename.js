@@ -0,0 +1,38 @@
+const { rspack } = require("@rspack/core");
+
+let error;
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description:
+		"should fail when templateGlob matches multiple templates but filename has no [name]",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./a",
+			output: {
+				path: context.getDist()
+			},
+			plugins: [
+				new rspack.HtmlRspackPlugin({
+					templateGlob: "./html-template-glob/pages/*.html",
+					filename: "index.html"
+				})
+			]
+		};
+	},
+	async build(_, compiler) {
+		await new Promise(resolve => {
+			compiler.run(err => {
+				error = err;
+				resolve();
+			});
+		});
+	},
+	async check() {
+		expect(error).toBeTruthy();
+		expect(error.message).toContain(
+			"filename `index.html` must contain `[name]` as templateGlob `./html-template-glob/pages/*.html` matches multiple templates"
+		);
+	}
+};
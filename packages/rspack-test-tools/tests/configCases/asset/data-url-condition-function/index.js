@@ -0,0 +1,16 @@
+import png from "./file.png";
+import jpg from "./file.jpg";
+
+it("should decide whether to inline by calling `dataUrlCondition`", () => {
+	expect(png.startsWith("data:image/png;base64,")).toBeTruthy();
+	expect(jpg.endsWith(".jpg")).toBeTruthy();
+});
+
+it("should pass the source, filename and issuer to `dataUrlCondition`", () => {
+	const calls = __non_webpack_require__("./calls.json");
+	expect(calls).toHaveLength(2);
+	for (const call of calls) {
+		expect(call.isBuffer).toBe(true);
+		expect(call.issuer.endsWith("index.js")).toBe(true);
+	}
+});
@@ -0,0 +1,37 @@
+const fs = require("fs");
+const path = require("path");
+
+const calls = [];
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.(png|jpg)$/,
+				type: "asset"
+			}
+		],
+		parser: {
+			asset: {
+				dataUrlCondition: (source, { filename, issuer }) => {
+					calls.push({ isBuffer: Buffer.isBuffer(source), issuer });
+					return filename.endsWith(".png");
+				}
+			}
+		}
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("Test", stats => {
+					fs.writeFileSync(
+						path.join(stats.compilation.outputOptions.path, "calls.json"),
+						JSON.stringify(calls)
+					);
+				});
+			}
+		}
+	]
+};
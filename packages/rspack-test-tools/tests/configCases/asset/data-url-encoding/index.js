@@ -0,0 +1,19 @@
+import svg from "./logo.svg";
+import text from "./text.txt";
+import transformed from "./logo.svg?transform";
+
+it("should encode the svg like mini-svg-data-uri", () => {
+	expect(svg).toBe(
+		"data:image/svg+xml,%3csvg%20xmlns='http://www.w3.org/2000/svg'%20viewBox='0%200%201%201'%3e%20%3crect%20width='1'%20height='1'%20fill='%230f0'/%3e%20%3c/svg%3e"
+	);
+});
+
+it("should encode the text as utf8", () => {
+	expect(text).toBe("data:text/plain;charset=utf-8,hello%20world%20%231%0A");
+});
+
+it("should transform the content before encoding", () => {
+	expect(transformed).toBe(
+		"data:image/svg+xml,%3csvg%20xmlns='http://www.w3.org/2000/svg'/%3e"
+	);
+});
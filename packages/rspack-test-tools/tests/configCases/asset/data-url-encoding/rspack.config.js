@@ -0,0 +1,30 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.svg$/,
+				type: "asset/inline",
+				generator: {
+					dataUrl: { encoding: "svg" }
+				},
+				oneOf: [
+					{
+						resourceQuery: /transform/,
+						generator: {
+							transform: () => '<svg xmlns="http://www.w3.org/2000/svg"/>'
+						}
+					},
+					{}
+				]
+			},
+			{
+				test: /\.txt$/,
+				type: "asset/inline",
+				generator: {
+					dataUrl: { encoding: "utf8" }
+				}
+			}
+		]
+	}
+};
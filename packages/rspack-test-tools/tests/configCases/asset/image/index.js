@@ -0,0 +1,38 @@
+import fs from "fs";
+import path from "path";
+import optimized from "./photo.png";
+import responsive from "./photo.png?width=16,32,128&format=webp";
+import inlined from "./photo.png?format=webp&inline";
+
+const readAsset = url =>
+	fs.readFileSync(path.join(__STATS__.outputPath, /images\/.*$/.exec(url)[0]));
+
+it("should optimize images losslessly", () => {
+	expect(optimized).toMatch(/images\/photo\.png$/);
+	expect(readAsset(optimized).length).toBeLessThan(
+		fs.readFileSync(path.join(__dirname, "photo.png")).length
+	);
+});
+
+it("should export resized variants for srcset", () => {
+	expect(responsive.width).toBe(16);
+	expect(responsive.height).toBe(8);
+	expect(responsive.src).toMatch(/images\/photo-16w\.webp$/);
+	expect(`${responsive}`).toBe(responsive.src);
+	// widths larger than the image are not upscaled
+	expect(responsive.images.map(image => [image.width, image.height])).toEqual([
+		[16, 8],
+		[32, 16],
+		[64, 32]
+	]);
+	expect(responsive.srcSet).toBe(
+		responsive.images.map(image => `${image.src} ${image.width}w`).join(", ")
+	);
+	for (const image of responsive.images) {
+		expect(readAsset(image.src).toString("latin1", 8, 12)).toBe("WEBP");
+	}
+});
+
+it("should convert inlined images", () => {
+	expect(inlined.startsWith("data:image/webp;base64,")).toBe(true);
+});
@@ -0,0 +1,29 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	output: {
+		assetModuleFilename: "images/[name][ext]"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				oneOf: [
+					{
+						resourceQuery: /inline/,
+						type: "asset/inline",
+						generator: {
+							image: {}
+						}
+					},
+					{
+						type: "asset/resource",
+						generator: {
+							image: {}
+						}
+					}
+				]
+			}
+		]
+	}
+};
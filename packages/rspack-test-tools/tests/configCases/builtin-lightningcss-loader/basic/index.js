@@ -0,0 +1,13 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should prefix and lower css with the builtin lightningcss loader", () => {
+	require("./index.css");
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toContain("-webkit-user-select: none");
+	expect(css).toContain("margin-inline-start: 4px");
+	expect(css).toMatch(/\.card \.title\s*\{/);
+	expect(css).toContain("color(display-p3");
+	expect(css).not.toContain("@custom-media");
+	expect(css).toContain("@media (max-width: 30em)");
+});
@@ -0,0 +1,28 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.css$/,
+				use: [
+					{
+						loader: "builtin:lightningcss-loader",
+						options: {
+							targets: "safari 12",
+							exclude: {
+								logicalProperties: true
+							},
+							draft: {
+								customMedia: true
+							}
+						}
+					}
+				],
+				type: "css",
+				generator: {
+					exportsOnly: false
+				}
+			}
+		]
+	}
+};
@@ -0,0 +1,11 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should leave css modules to rspack", () => {
+	const styles = require("./style.module.css");
+	expect(styles.base).toBe("base-local");
+	expect(styles.button).toBe("button-local base-local");
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toMatch(/\.button-local \.icon\s*\{/);
+	expect(css).not.toContain("composes");
+});
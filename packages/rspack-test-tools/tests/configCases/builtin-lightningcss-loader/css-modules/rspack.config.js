@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.module\.css$/,
+				use: [
+					{
+						loader: "builtin:lightningcss-loader",
+						options: {
+							targets: ["chrome 80"]
+						}
+					}
+				],
+				type: "css/module",
+				generator: {
+					localIdentName: "[local]-local",
+					exportsOnly: false
+				}
+			}
+		]
+	}
+};
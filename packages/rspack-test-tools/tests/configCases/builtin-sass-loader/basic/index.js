@@ -0,0 +1,11 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should compile scss with the builtin sass loader", () => {
+	require("./index.scss");
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toContain(".org-theme");
+	expect(css).toContain("color: #333");
+	expect(css).toContain("background: hotpink");
+	expect(css).toMatch(/\.foo \.nested\s*\{\s*border-radius: 4px;/);
+});
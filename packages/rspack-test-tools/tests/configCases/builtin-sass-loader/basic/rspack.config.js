@@ -0,0 +1,22 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.s[ac]ss$/i,
+				use: [
+					{
+						loader: "builtin:sass-loader",
+						options: {
+							additionalData: "$prepended: hotpink;"
+						}
+					}
+				],
+				type: "css",
+				generator: {
+					exportsOnly: false
+				}
+			}
+		]
+	}
+};
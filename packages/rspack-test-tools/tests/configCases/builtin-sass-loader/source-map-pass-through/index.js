@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should pass the source map of previous loaders through", () => {
+	require("./index.scss");
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "bundle0.css.map"), "utf-8")
+	);
+	expect(map.sources).toHaveLength(1);
+	expect(map.sources[0]).toMatch(/original\.scss$/);
+	expect(map.sourcesContent[0]).toContain("variables.$color");
+});
@@ -0,0 +1,10 @@
+module.exports = function (content) {
+	const map = {
+		version: 3,
+		sources: ["original.scss"],
+		sourcesContent: [content],
+		names: [],
+		mappings: "AAAA"
+	};
+	this.callback(null, content, map);
+};
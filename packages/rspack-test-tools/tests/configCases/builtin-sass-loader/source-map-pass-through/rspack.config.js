@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.s[ac]ss$/i,
+				use: [{ loader: "builtin:sass-loader" }, { loader: "./loader.js" }],
+				type: "css",
+				generator: {
+					exportsOnly: false
+				}
+			}
+		]
+	},
+	devtool: "source-map"
+};
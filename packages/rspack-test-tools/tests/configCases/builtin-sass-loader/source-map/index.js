@@ -0,0 +1,31 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should map the css back to the compiled sass without a loader source map", async () => {
+	require("./index.scss");
+	const { SourceMapConsumer } = require("source-map");
+	const source = fs.readFileSync(
+		path.resolve(__dirname, "bundle0.css.map"),
+		"utf-8"
+	);
+	const map = JSON.parse(source);
+	expect(map.sources).toEqual(["webpack:///./index.scss"]);
+	expect(map.file).toEqual("bundle0.css");
+	// grass doesn't track source positions, so the original source is the compiled css
+	const [original] = map.sourcesContent;
+	expect(original).toContain("color: red;");
+	expect(original).not.toContain("variables.$color");
+
+	const out = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	const consumer = await new SourceMapConsumer(source);
+	const positionOf = (content, search) => {
+		const lines = content.slice(0, content.indexOf(search)).split("\n");
+		return { line: lines.length, column: lines[lines.length - 1].length };
+	};
+	expect(
+		consumer.originalPositionFor(positionOf(out, "color: red"))
+	).toMatchObject({
+		source: map.sources[0],
+		...positionOf(original, "color: red")
+	});
+});
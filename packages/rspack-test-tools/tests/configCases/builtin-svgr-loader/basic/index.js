@@ -0,0 +1,42 @@
+import fs from "fs";
+import path from "path";
+import Icon, { url } from "./icon.svg";
+
+it("should transform svg to a react component", () => {
+	expect(Icon.$$typeof).toBe("memo");
+	expect(Icon.type.$$typeof).toBe("forward_ref");
+
+	const svg = Icon.type.render({ className: "icon", titleId: "star" }, "ref");
+	expect(svg.type).toBe("svg");
+	expect(svg.props.viewBox).toBe("0 0 24 24");
+	expect(svg.props.className).toBe("icon");
+	expect(svg.props.ref).toBe("ref");
+	expect(svg.props["aria-labelledby"]).toBe("star");
+	expect(svg.props.xmlnsSketch).toBeUndefined();
+
+	const [title, ...rest] = svg.props.children;
+	expect(title.type).toBe("title");
+	expect(title.props.id).toBe("star");
+	expect(title.props.children).toEqual(["Star"]);
+	expect(rest.map(child => child.type)).toEqual(["path"]);
+
+	const [icon] = rest;
+	expect(icon.props.fillRule).toBe("evenodd");
+	expect(icon.props.style).toEqual({
+		strokeWidth: "2",
+		WebkitTransform: "none"
+	});
+});
+
+it("should render the title prop", () => {
+	const svg = Icon.type.render({ title: "Custom" }, null);
+	expect(svg.props.children[0].props.children).toEqual(["Custom"]);
+	expect(Icon.type.render({ title: null }, null).props.children[0]).toBe(null);
+});
+
+it("should export the url of the svg asset", () => {
+	expect(url).toMatch(/\.svg$/);
+	const file = path.basename(new URL(url).pathname);
+	const content = fs.readFileSync(path.resolve(__dirname, file), "utf-8");
+	expect(content).toContain("<metadata>generated</metadata>");
+});
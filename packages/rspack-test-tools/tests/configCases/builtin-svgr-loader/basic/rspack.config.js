@@ -0,0 +1,39 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		alias: {
+			react: path.resolve(__dirname, "react.js")
+		}
+	},
+	module: {
+		rules: [
+			{
+				test: /\.svg$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "ecmascript",
+									jsx: true
+								}
+							}
+						}
+					},
+					{
+						loader: "builtin:svgr-loader",
+						options: {
+							ref: true,
+							title: true,
+							memo: true,
+							urlExport: "url"
+						}
+					}
+				]
+			}
+		]
+	}
+};
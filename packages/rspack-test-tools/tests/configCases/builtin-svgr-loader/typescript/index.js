@@ -0,0 +1,14 @@
+import * as icon from "./icon.svg";
+
+it("should transform svg to a typed react component", () => {
+	const svg = icon.ReactComponent({ className: "icon" });
+	expect(svg.type).toBe("svg");
+	expect(svg.props.width).toBe("1em");
+	expect(svg.props.height).toBe("1em");
+	expect(svg.props.role).toBe("img");
+	expect(svg.props.className).toBe("icon");
+
+	const types = svg.props.children.map(child => child.type);
+	expect(types).toEqual(["title", "metadata", "g", "path"]);
+	expect(svg.props.children[3].props.fill).toBe("currentColor");
+});
@@ -0,0 +1,6 @@
+exports.createElement = (type, props, ...children) => ({
+	type,
+	props: { ...props, children }
+});
+exports.forwardRef = render => ({ $$typeof: "forward_ref", render });
+exports.memo = type => ({ $$typeof: "memo", type });
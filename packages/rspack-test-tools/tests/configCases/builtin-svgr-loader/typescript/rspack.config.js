@@ -0,0 +1,45 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		alias: {
+			react: path.resolve(__dirname, "react.js")
+		}
+	},
+	module: {
+		rules: [
+			{
+				test: /\.svg$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "typescript",
+									tsx: true
+								}
+							}
+						}
+					},
+					{
+						loader: "builtin:svgr-loader",
+						options: {
+							typescript: true,
+							icon: true,
+							svgo: false,
+							exportType: "named",
+							replaceAttrValues: {
+								"#063855": "currentColor"
+							},
+							svgProps: {
+								role: "img"
+							}
+						}
+					}
+				]
+			}
+		]
+	}
+};
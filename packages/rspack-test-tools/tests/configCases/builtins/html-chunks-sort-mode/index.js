@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+require("./shared");
+
+it("html chunks sort mode dependency", () => {
+	const html = fs.readFileSync(path.join(__dirname, "./dependency.html"), "utf-8");
+	const runtimeIndex = html.search("runtime.js");
+	const sharedIndex = html.search("shared.js");
+	expect(runtimeIndex).toBeGreaterThan(-1);
+	expect(runtimeIndex < sharedIndex).toBe(true);
+	expect(sharedIndex < html.search("main.js")).toBe(true);
+	expect(sharedIndex < html.search("polyfill.js")).toBe(true);
+	// shared chunks are only injected once
+	expect(html.split("shared.js").length).toBe(2);
+});
+
+it("html chunks sort mode manual", () => {
+	const html = fs.readFileSync(path.join(__dirname, "./manual.html"), "utf-8");
+	expect(html.search("polyfill.js") < html.search("main.js")).toBe(true);
+});
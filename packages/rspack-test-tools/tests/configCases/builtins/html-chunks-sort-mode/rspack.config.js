@@ -0,0 +1,37 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		polyfill: "./polyfill.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		runtimeChunk: "single",
+		splitChunks: {
+			minSize: 0,
+			cacheGroups: {
+				shared: {
+					test: /shared/,
+					name: "shared",
+					chunks: "all",
+					enforce: true
+				}
+			}
+		}
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			filename: "dependency.html",
+			chunksSortMode: "dependency"
+		}),
+		new rspack.HtmlRspackPlugin({
+			filename: "manual.html",
+			chunks: ["polyfill", "main"],
+			chunksSortMode: "manual"
+		})
+	]
+};
@@ -0,0 +1,2 @@
+import(/* webpackPrefetch: true */ "./prefetched");
+import(/* webpackPreload: true */ "./preloaded");
@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should inject modulepreload links for initial esm chunks", () => {
+	const html = fs.readFileSync(path.join(__dirname, "index.html"), "utf-8");
+	expect(html).toMatch(/<link href="[^"]*runtime\.mjs" rel="modulepreload">/);
+	expect(html).toMatch(/<script src="[^"]*main\.mjs" type="module"><\/script>/);
+	expect(html).not.toMatch(/<script src="[^"]*runtime\.mjs"/);
+});
+
+it("should not inject modulepreload links for non-module scripts", () => {
+	const html = fs.readFileSync(path.join(__dirname, "defer.html"), "utf-8");
+	expect(html).not.toContain("modulepreload");
+	expect(html).toMatch(/<script src="[^"]*defer-runtime\.mjs" defer><\/script>/);
+	expect(html).toMatch(/<script src="[^"]*defer-main\.mjs" defer><\/script>/);
+});
+
+it("should prefetch and preload chunks in esm chunk loading", () => {
+	const runtime = fs.readFileSync(path.join(__dirname, "runtime.mjs"), "utf-8");
+	expect(runtime).toContain("__webpack_require__.F.j = ");
+	expect(runtime).toContain('link.rel = "prefetch";');
+	expect(runtime).toContain("__webpack_require__.H.j = ");
+	expect(runtime).toContain('link.rel = "modulepreload";');
+});
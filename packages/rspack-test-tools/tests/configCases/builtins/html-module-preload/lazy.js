@@ -0,0 +1,2 @@
+export default () =>
+	import(/* webpackChunkName: "preloaded", webpackPreload: true */ "./preloaded");
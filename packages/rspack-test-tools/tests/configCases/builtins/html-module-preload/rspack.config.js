@@ -0,0 +1,73 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			main: "./app.js"
+		},
+		target: "web",
+		output: {
+			module: true,
+			filename: "[name].mjs",
+			chunkFilename: "[name].chunk.mjs"
+		},
+		experiments: {
+			outputModule: true
+		},
+		optimization: {
+			runtimeChunk: "single"
+		},
+		plugins: [
+			new rspack.HtmlRspackPlugin({
+				scriptLoading: "module"
+			})
+		]
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "bundle1.js"
+		}
+	},
+	{
+		entry: {
+			main: "./app.js"
+		},
+		target: "web",
+		output: {
+			module: true,
+			filename: "defer-[name].mjs",
+			chunkFilename: "defer-[name].chunk.mjs"
+		},
+		experiments: {
+			outputModule: true
+		},
+		optimization: {
+			runtimeChunk: "single"
+		},
+		plugins: [
+			new rspack.HtmlRspackPlugin({
+				filename: "defer.html"
+			})
+		]
+	},
+	{
+		entry: {
+			main: "./run.js"
+		},
+		target: "web",
+		output: {
+			module: true,
+			filename: "run-[name].mjs",
+			chunkFilename: "run-[name].chunk.mjs"
+		},
+		experiments: {
+			outputModule: true
+		},
+		optimization: {
+			runtimeChunk: "single",
+			chunkIds: "named"
+		}
+	}
+];
@@ -0,0 +1,41 @@
+const links = rel =>
+	document.head._children.filter(
+		element => element._type === "link" && element.rel === rel
+	);
+
+export const later = () =>
+	Promise.all([
+		import(/* webpackChunkName: "prefetched", webpackPrefetch: true */ "./prefetched"),
+		import(/* webpackChunkName: "lazy" */ "./lazy")
+	]);
+
+it("should prefetch chunks at startup", () => {
+	const prefetch = links("prefetch");
+	expect(prefetch).toHaveLength(1);
+	expect(prefetch[0].href).toMatch(/run-prefetched\.chunk\.mjs$/);
+	expect(prefetch[0].as).toBe("script");
+
+	// the chunk is marked as prefetched and won't be prefetched again
+	__webpack_require__.F.j("prefetched");
+	expect(links("prefetch")).toHaveLength(1);
+});
+
+it("should preload chunks with modulepreload links", async () => {
+	__webpack_require__.H.j("preloaded");
+	__webpack_require__.H.j("preloaded");
+	const preload = links("modulepreload");
+	expect(preload).toHaveLength(1);
+	expect(preload[0].href).toMatch(/run-preloaded\.chunk\.mjs$/);
+
+	// preloaded chunks are still loaded when they are imported
+	const { default: value } = await import(
+		/* webpackChunkName: "preloaded" */ "./preloaded"
+	);
+	expect(value).toBe("preloaded");
+
+	// installed chunks are neither prefetched nor preloaded
+	__webpack_require__.F.j("preloaded");
+	__webpack_require__.H.j("preloaded");
+	expect(links("prefetch")).toHaveLength(1);
+	expect(links("modulepreload")).toHaveLength(1);
+});
@@ -0,0 +1,8 @@
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: function (i) {
+		if (i === 1) return ["./bundle1.js"];
+		if (i === 3) return ["./run-main.mjs"];
+		return [];
+	}
+};
@@ -0,0 +1,15 @@
+const fs = require("fs");
+const path = require("path");
+
+it("html multi page", () => {
+	const home = fs.readFileSync(path.join(__dirname, "./home.html"), "utf-8");
+	expect(home.includes("<title>Site</title>")).toBe(true);
+	expect(home.includes("home.js")).toBe(true);
+	expect(home.includes("about.js")).toBe(false);
+	expect(home.includes("main.js")).toBe(false);
+
+	const about = fs.readFileSync(path.join(__dirname, "./about.html"), "utf-8");
+	expect(about.includes("<title>About us</title>")).toBe(true);
+	expect(about.includes("about.js")).toBe(true);
+	expect(about.includes("home.js")).toBe(false);
+});
@@ -0,0 +1,28 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		home: "./home.js",
+		about: "./about.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			templateGlob: "./pages/*.html",
+			templateParameters: {
+				title: "Site"
+			},
+			pages: {
+				about: {
+					templateParameters: {
+						title: "About us"
+					}
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,13 @@
+const fs = require("fs");
+const path = require("path");
+
+it("html template context", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent.includes('<html lang="en">')).toBe(true);
+	// placed by the template even though inject is false
+	expect(htmlContent.split("/static/main.js").length).toBe(3);
+	expect(htmlContent.includes('<div id="public-path">/static/</div>')).toBe(true);
+	expect(htmlContent.includes('<div class="js-file">/static/main.js</div>')).toBe(true);
+	expect(htmlContent.includes('<div id="inject">false</div>')).toBe(true);
+});
@@ -0,0 +1,18 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		publicPath: "/static/"
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			template: "./index.html",
+			inject: false,
+			templateParameters: {
+				lang: "en"
+			}
+		})
+	]
+};
@@ -0,0 +1 @@
+module.exports = [/failed to render `template_content.html`: Function "unknown" is not registered/];
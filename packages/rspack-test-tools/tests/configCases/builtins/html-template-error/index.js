@@ -0,0 +1,6 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should report template errors instead of aborting", () => {
+	expect(fs.existsSync(path.join(__dirname, "./index.html"))).toBe(false);
+});
@@ -0,0 +1,14 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			templateContent:
+				"<!DOCTYPE html><html><body>\n<div><%= env %></div>\n<div><%= unknown(env) %></div>\n</body></html>",
+			templateParameters: {
+				env: "production"
+			}
+		})
+	]
+};
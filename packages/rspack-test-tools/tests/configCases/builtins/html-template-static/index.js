@@ -0,0 +1,8 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit templates without templateParameters as is", () => {
+	const html = fs.readFileSync(path.join(__dirname, "index.html"), "utf-8");
+	expect(html).toMatch(/Use (<|&lt;)%= name %(>|&gt;) in templates/);
+	expect(html).toContain("<script");
+});
@@ -0,0 +1,11 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			templateContent:
+				"<!DOCTYPE html><html><body><div>Use <%= name %> in templates</div></body></html>"
+		})
+	]
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[/Json5 parsing error/, /expected/],
+	[/Yaml parsing error/],
+	[/Toml parsing error/]
+];
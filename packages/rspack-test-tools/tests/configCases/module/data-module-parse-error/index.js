@@ -0,0 +1,5 @@
+it("should report parse errors of data modules", () => {
+	expect(() => require("./broken.json5")).toThrow();
+	expect(() => require("./broken.yaml")).toThrow();
+	expect(() => require("./broken.toml")).toThrow();
+});
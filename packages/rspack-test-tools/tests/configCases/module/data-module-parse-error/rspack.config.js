@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.json5$/,
+				type: "json5"
+			},
+			{
+				test: /\.yaml$/,
+				type: "yaml"
+			},
+			{
+				test: /\.toml$/,
+				type: "toml"
+			}
+		]
+	}
+};
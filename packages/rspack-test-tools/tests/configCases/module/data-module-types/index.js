@@ -0,0 +1,20 @@
+import { name, port, features } from "./config.json5";
+import { greeting, nested } from "./messages.yaml";
+import * as settings from "./settings.toml";
+
+it("should parse json5, yaml and toml modules", () => {
+	expect(name).toBe("app");
+	expect(port).toBe(8080);
+	expect(features).toEqual(["a", "b"]);
+	expect(greeting).toBe("Hello");
+	expect(nested.farewell).toBe("Bye");
+	expect(settings.title).toBe("settings");
+	expect(settings.server.port).toBe(8080);
+});
+
+it("should tree shake unused data", () => {
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("UNUSED_" + "JSON5_VALUE");
+	expect(content).not.toContain("UNUSED_" + "YAML_VALUE");
+	expect(content).not.toContain("UNUSED_" + "TOML_VALUE");
+});
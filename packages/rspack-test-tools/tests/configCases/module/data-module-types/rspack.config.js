@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	optimization: {
+		minimize: false
+	},
+	module: {
+		rules: [
+			{
+				test: /\.json5$/,
+				type: "json5"
+			},
+			{
+				test: /\.ya?ml$/,
+				type: "yaml"
+			},
+			{
+				test: /\.toml$/,
+				type: "toml"
+			}
+		]
+	}
+};
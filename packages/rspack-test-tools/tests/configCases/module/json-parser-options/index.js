@@ -0,0 +1,13 @@
+import { a } from "./data.json";
+import custom from "./custom.json";
+
+it("should transform the data with custom parse", () => {
+	expect(custom).toEqual({ original: 1, injected: true });
+});
+
+it("should only track exports to exportsDepth", () => {
+	expect(a.used).toBe(1);
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).toContain("NESTED_" + "KEPT");
+	expect(content).not.toContain("TOP_" + "UNUSED");
+});
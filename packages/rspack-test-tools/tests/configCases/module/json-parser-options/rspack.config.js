@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	optimization: {
+		minimize: false
+	},
+	module: {
+		parser: {
+			json: {
+				exportsDepth: 1
+			}
+		},
+		rules: [
+			{
+				test: /custom\.json$/,
+				type: "json",
+				parser: {
+					parse: source => ({ ...JSON.parse(source), injected: true })
+				}
+			}
+		]
+	}
+};
@@ -0,0 +1,10 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should minify css assets with lightningcss", () => {
+	require("./index.css");
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toContain("-webkit-user-select:none");
+	expect(css).toContain("color:red");
+	expect(css).not.toContain("\n\t");
+});
@@ -0,0 +1,25 @@
+const { LightningCssMinimizerRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	module: {
+		rules: [
+			{
+				test: /\.css$/,
+				type: "css",
+				generator: {
+					exportsOnly: false
+				}
+			}
+		]
+	},
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new LightningCssMinimizerRspackPlugin({
+				targets: ["safari 12"]
+			})
+		]
+	}
+};
@@ -0,0 +1,14 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should download hot updates with import() in esm output", async () => {
+	const source = await fs.promises.readFile(
+		path.resolve(__dirname, "module.mjs"),
+		"utf-8"
+	);
+	expect(source).toContain("__webpack_require__.hmrC.module = ");
+	expect(source).toContain('import("./" + __webpack_require__.hu(chunkId))');
+	expect(source).toContain(
+		"fetch(__webpack_require__.p + __webpack_require__.hmrF())"
+	);
+});
@@ -0,0 +1,24 @@
+const { HotModuleReplacementPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: "./module.js",
+		output: {
+			module: true,
+			filename: "module.mjs",
+			chunkFilename: "[name].mjs"
+		},
+		experiments: {
+			outputModule: true
+		},
+		plugins: [new HotModuleReplacementPlugin()],
+		target: "web"
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "index.js"
+		}
+	}
+];
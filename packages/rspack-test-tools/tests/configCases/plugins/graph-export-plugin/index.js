@@ -0,0 +1,87 @@
+import { a } from "./a";
+
+const fs = require("fs");
+const path = require("path");
+
+const nodesOf = dot => {
+	const nodes = {};
+	for (const [, id, label, kind] of dot.matchAll(
+		/^ {2}"(\w+)" \[label="([^"]*)", shape=\w+, kind="(\w+)"/gm
+	)) {
+		nodes[`${kind}:${label}`] = id;
+	}
+	return nodes;
+};
+
+const hasEdge = (dot, source, target, attributes) =>
+	dot
+		.split("\n")
+		.some(
+			line =>
+				line.startsWith(`  "${source}" -> "${target}" [`) &&
+				attributes.every(attribute => line.includes(attribute))
+		);
+
+it("should export the module graph and the chunk graph as dot", async () => {
+	expect(a).toBe("a");
+	const { default: value } = await import(
+		/* webpackChunkName: "async" */ "./async"
+	);
+	expect(value).toBe("async");
+
+	const dot = fs.readFileSync(path.join(__dirname, "main.graph.dot"), "utf-8");
+	expect(dot.startsWith('digraph "main" {')).toBe(true);
+
+	const nodes = nodesOf(dot);
+	const index = nodes["module:./index.js"];
+	const block = nodes["block:async"];
+	const main = nodes["chunk_group:main"];
+	const asyncGroup = nodes["chunk_group:async"];
+	expect(index).toBeDefined();
+	expect(block).toBeDefined();
+	expect(main).toBeDefined();
+	expect(asyncGroup).toBeDefined();
+
+	expect(
+		hasEdge(dot, index, nodes["module:./a.js"], [
+			'kind="dependency"',
+			'dependency_type="esm import"',
+			'active="true"'
+		])
+	).toBe(true);
+	expect(hasEdge(dot, index, block, ['kind="block"'])).toBe(true);
+	expect(
+		hasEdge(dot, block, nodes["module:./async.js"], [
+			'kind="dependency"',
+			'dependency_type="import()"'
+		])
+	).toBe(true);
+
+	expect(hasEdge(dot, main, asyncGroup, ['kind="child_chunk_group"'])).toBe(
+		true
+	);
+	expect(hasEdge(dot, block, asyncGroup, ['kind="block_chunk_group"'])).toBe(
+		true
+	);
+	expect(dot).toMatch(
+		/"chunk\d+" \[label="main", shape=folder, kind="chunk", runtime="main", initial="true", files="main\.js"\]/
+	);
+});
+
+it("should only export the filtered modules as graphml", () => {
+	const graphml = fs.readFileSync(
+		path.join(__dirname, "main.filtered.graphml"),
+		"utf-8"
+	);
+	expect(graphml).toContain('<graph id="main" edgedefault="directed">');
+	const labels = [
+		...graphml.matchAll(
+			/<data key="node_kind">module<\/data>\s*<data key="node_label">([^<]*)</g
+		)
+	].map(([, label]) => label);
+	expect(labels).toEqual(["./async.js"]);
+	// the importer is filtered out, only the chunk membership is left
+	expect(graphml).toContain('<data key="edge_kind">chunk_module</data>');
+	expect(graphml).not.toContain('<data key="edge_kind">dependency</data>');
+	expect(graphml).not.toContain("./a.js");
+});
@@ -0,0 +1,19 @@
+const { GraphExportRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new GraphExportRspackPlugin(),
+		new GraphExportRspackPlugin({
+			format: "graphml",
+			filename: "[name].filtered.graphml",
+			moduleFilter: /async\.js$/
+		})
+	]
+};
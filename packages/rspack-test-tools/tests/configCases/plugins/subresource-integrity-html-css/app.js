@@ -0,0 +1,3 @@
+import "./entry.css";
+
+import(/* webpackChunkName: "style" */ "./style.css");
@@ -0,0 +1,30 @@
+const fs = require("fs");
+const path = require("path");
+const crypto = require("crypto");
+
+const read = file => fs.readFileSync(path.join(__dirname, file), "utf-8");
+const integrityOf = file =>
+	`sha384-${crypto
+		.createHash("sha384")
+		.update(fs.readFileSync(path.join(__dirname, file)))
+		.digest("base64")}`;
+
+it("should embed the integrity of css chunks into the runtime", () => {
+	const main = read("main.js");
+	const hashes = main.match(/sriCssHashes = (\{[^}]*\})/);
+	expect(hashes).toBeTruthy();
+	expect(JSON.parse(hashes[1])).toEqual({ style: integrityOf("style.css") });
+	expect(main).toContain(
+		"link.integrity = __webpack_require__.sriCssHashes[chunkId];"
+	);
+});
+
+it("should rewrite the integrity of the tags injected by HtmlRspackPlugin", () => {
+	const html = read("index.html");
+	const script = html.match(/<script [^>]*src="[^"]*main\.js"[^>]*>/);
+	expect(script).toBeTruthy();
+	expect(script[0]).toContain(`integrity="${integrityOf("main.js")}"`);
+	const link = html.match(/<link [^>]*href="[^"]*main\.css"[^>]*>/);
+	expect(link).toBeTruthy();
+	expect(link[0]).toContain(`integrity="${integrityOf("main.css")}"`);
+});
@@ -0,0 +1,28 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			main: "./app.js"
+		},
+		target: "web",
+		output: {
+			filename: "[name].js",
+			chunkFilename: "[name].js"
+		},
+		optimization: {
+			chunkIds: "named"
+		},
+		plugins: [
+			new rspack.HtmlRspackPlugin({ sri: "sha256" }),
+			new rspack.SubresourceIntegrityRspackPlugin()
+		]
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "bundle1.js"
+		}
+	}
+];
@@ -0,0 +1,39 @@
+const fs = require("fs");
+const path = require("path");
+const crypto = require("crypto");
+
+const integrityOf = file =>
+	`sha384-${crypto
+		.createHash("sha384")
+		.update(fs.readFileSync(path.join(__dirname, file)))
+		.digest("base64")}`;
+
+it("should embed the integrity of async chunks into the runtime", async () => {
+	const { default: value } = await import(
+		/* webpackChunkName: "async" */ "./async"
+	);
+	expect(value).toBe("async");
+
+	const main = fs.readFileSync(path.join(__dirname, "main.js"), "utf-8");
+	const hashes = main.match(/sriHashes = (\{"[^}]*\})/);
+	expect(hashes).toBeTruthy();
+	expect(JSON.parse(hashes[1])).toEqual({ async: integrityOf("async.js") });
+});
+
+it("should keep the source map of the chunks", () => {
+	const main = fs.readFileSync(path.join(__dirname, "main.js"), "utf-8");
+	expect(main).toContain("//# sourceMappingURL=main.js.map");
+	const map = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "main.js.map"), "utf-8")
+	);
+	expect(map.sources.some(source => source.endsWith("index.js"))).toBe(true);
+});
+
+it("should emit the integrity manifest", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "integrity-manifest.json"), "utf-8")
+	);
+	expect(Object.keys(manifest).sort()).toEqual(["async.js", "main.js"]);
+	expect(manifest["async.js"]).toBe(integrityOf("async.js"));
+	expect(manifest["main.js"]).toBe(integrityOf("main.js"));
+});
@@ -0,0 +1,17 @@
+const { SubresourceIntegrityRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js"
+	},
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[name].js"
+	},
+	devtool: "source-map",
+	optimization: {
+		chunkIds: "named"
+	},
+	plugins: [new SubresourceIntegrityRspackPlugin({ manifest: true })]
+};
@@ -0,0 +1,34 @@
+import value from "./value";
+
+const fs = require("fs");
+const path = require("path");
+
+const readRecords = file =>
+	JSON.parse(fs.readFileSync(path.join(__dirname, file), "utf-8"));
+
+it("should write the hmr state to records", () => {
+	const records = readRecords("hmr-records.json");
+	expect(records.hotIndex).toBe(0);
+	expect(records.hmr.modules["./hmr/value.js"].id).toBe("./hmr/value.js");
+	expect(records.hmr.chunks.main).toEqual(["main"]);
+});
+
+it("should emit hot updates against the records of the previous build", () => {
+	expect(value).toBe(2);
+	const { hash } = readRecords("hmr-records.json");
+	const manifestFile = fs
+		.readdirSync(__dirname)
+		.find(file => /^second-main\..+\.hot-update\.json$/.test(file));
+	expect(hash.startsWith(manifestFile.split(".")[1])).toBe(true);
+	const manifest = readRecords(manifestFile);
+	expect(manifest.c).toEqual(["main"]);
+	const update = fs.readFileSync(
+		path.join(__dirname, manifestFile.replace(/\.json$/, ".js")),
+		"utf-8"
+	);
+	expect(update).toContain("./hmr/value.js");
+});
+
+it("should continue the hot index of the previous build", () => {
+	expect(readRecords("hmr-records-second.json").hotIndex).toBe(1);
+});
@@ -0,0 +1,33 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should revive chunk ids from records", async () => {
+	const { default: value } = await import(
+		/* webpackChunkName: "async" */ "./async"
+	);
+	expect(value).toBe(42);
+	expect(fs.existsSync(path.join(__dirname, "100.js"))).toBe(true);
+});
+
+it("should revive ids of unnamed chunks by their root modules", async () => {
+	const { default: value } = await import("./unnamed");
+	expect(value).toBe("unnamed");
+	expect(fs.existsSync(path.join(__dirname, "200.js"))).toBe(true);
+});
+
+it("should revive module ids from records", () => {
+	expect(String(require("./revived"))).toBe("900");
+});
+
+it("should write records of the compilation", () => {
+	const records = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "records.json"), "utf-8")
+	);
+	expect(records.chunks.byName.async).toBe("100");
+	expect(records.chunks.bySource["./unnamed.js"]).toBe("200");
+	expect(records.modules.byIdentifier["./revived.js"]).toBe("900");
+	expect(Object.values(records.modules.byIdentifier)).toContain(
+		String(module.id)
+	);
+	expect(typeof records.hash).toBe("string");
+});
@@ -0,0 +1,49 @@
+const path = require("path");
+const { DefinePlugin, HotModuleReplacementPlugin } = require("@rspack/core");
+
+const dist = path.resolve(__dirname, "../../../js/config/records/stable-ids");
+
+const hmr = value => ({
+	entry: "./hmr/index.js",
+	target: "node",
+	optimization: {
+		chunkIds: "named",
+		moduleIds: "named"
+	},
+	plugins: [
+		new HotModuleReplacementPlugin(),
+		new DefinePlugin({ VALUE: JSON.stringify(value) })
+	]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		target: "node",
+		recordsInputPath: path.resolve(__dirname, "records.json"),
+		recordsOutputPath: path.resolve(dist, "records.json"),
+		output: {
+			chunkFilename: "[id].js"
+		},
+		optimization: {
+			chunkIds: "natural",
+			moduleIds: "natural"
+		}
+	},
+	{
+		...hmr(1),
+		name: "hmr-first",
+		recordsOutputPath: path.resolve(dist, "hmr-records.json")
+	},
+	{
+		...hmr(2),
+		name: "hmr-second",
+		dependencies: ["hmr-first"],
+		recordsInputPath: path.resolve(dist, "hmr-records.json"),
+		recordsOutputPath: path.resolve(dist, "hmr-records-second.json"),
+		output: {
+			hotUpdateMainFilename: "second-[runtime].[fullhash].hot-update.json",
+			hotUpdateChunkFilename: "second-[id].[fullhash].hot-update.js"
+		}
+	}
+];
@@ -0,0 +1,6 @@
+module.exports = {
+	findBundle(i) {
+		// the first hmr build only writes the records read by the second one
+		return i === 1 ? undefined : `./bundle${i}.js`;
+	}
+};
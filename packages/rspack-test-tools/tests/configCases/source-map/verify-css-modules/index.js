@@ -0,0 +1,42 @@
+const fs = require("fs");
+const path = require("path");
+const styles = require("./style.module.css");
+
+it("verify css modules source map", async () => {
+	const { SourceMapConsumer } = require("source-map");
+	const source = fs.readFileSync(
+		path.resolve(__dirname, "bundle0.css.map"),
+		"utf-8"
+	);
+	const map = JSON.parse(source);
+	expect(map.sources).toHaveLength(1);
+	expect(map.sources[0]).toContain("style.module.css");
+	expect(map.sourcesContent[0]).not.toContain("banner");
+
+	const out = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	const consumer = await new SourceMapConsumer(source);
+	const positionOf = search => {
+		const lines = out.slice(0, out.indexOf(search)).split("\n");
+		return consumer.originalPositionFor({
+			line: lines.length,
+			column: lines[lines.length - 1].length
+		});
+	};
+
+	// renamed local idents map back to the original name through the loader map
+	expect(positionOf(styles.foo)).toMatchObject({
+		source: map.sources[0],
+		line: 1,
+		name: "foo"
+	});
+	expect(positionOf(styles.spin)).toMatchObject({
+		source: map.sources[0],
+		line: 5,
+		name: "spin"
+	});
+	expect(positionOf("color: blue")).toMatchObject({
+		source: map.sources[0],
+		line: 7,
+		column: 2
+	});
+});
@@ -0,0 +1,19 @@
+const { SourceMapGenerator } = require("source-map");
+
+/** @type {import("@rspack/core").LoaderDefinition} */
+module.exports = function (content) {
+	const source = this.utils.contextify(this.context, this.resourcePath);
+	const generator = new SourceMapGenerator({ file: source });
+	generator.setSourceContent(source, content);
+	// every character is shifted by the banner line
+	content.split("\n").forEach((line, index) => {
+		for (let column = 0; column < line.length; column++) {
+			generator.addMapping({
+				source,
+				original: { line: index + 1, column },
+				generated: { line: index + 2, column }
+			});
+		}
+	});
+	this.callback(null, `/* banner */\n${content}`, generator.toJSON());
+};
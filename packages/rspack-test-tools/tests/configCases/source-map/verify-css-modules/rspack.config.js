@@ -0,0 +1,15 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.module\.css$/,
+				type: "css/module",
+				use: "./loader.js"
+			}
+		]
+	},
+	devtool: "source-map",
+	externals: ["source-map"],
+	externalsType: "commonjs"
+};
@@ -0,0 +1,29 @@
+const fs = require("fs");
+const path = require("path");
+const { execFileSync } = require("child_process");
+
+const bundle = path.join(__dirname, "js/esm.mjs");
+
+it("should instantiate async wasm from a source phase import with esm integration", () => {
+	const source = fs.readFileSync(bundle, "utf-8");
+	expect(source).toMatch(
+		/import source __webpack_wasm_source_\w+__ from "\.\.\/[0-9a-f]+\.module\.wasm";/
+	);
+	expect(source).toContain("WebAssembly.instantiate(__webpack_wasm_source_");
+	expect(source).not.toContain("new WebAssembly.Instance(");
+	expect(source).not.toContain("__webpack_require__.v(");
+});
+
+// source phase imports of wasm are supported since node 24 with `--experimental-wasm-modules`
+const nodeMajor = Number(process.versions.node.split(".")[0]);
+
+if (nodeMajor >= 24) {
+	it("should run the bundle with esm integration", () => {
+		const output = execFileSync(
+			process.execPath,
+			["--experimental-wasm-modules", bundle],
+			{ encoding: "utf-8" }
+		);
+		expect(output.trim()).toBe("42");
+	});
+}
@@ -0,0 +1,25 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: "./wasm.js",
+		output: {
+			module: true,
+			filename: "js/esm.mjs"
+		},
+		experiments: {
+			outputModule: true,
+			asyncWebAssembly: true,
+			wasmEsmIntegration: true
+		}
+	},
+	{
+		entry: "./index.js",
+		target: "node",
+		output: {
+			filename: "bundle1.js"
+		},
+		node: {
+			__dirname: false
+		}
+	}
+];
@@ -0,0 +1,3 @@
+import { memory } from "./memory.wasm";
+
+console.log(new Int32Array(memory.buffer)[0]);
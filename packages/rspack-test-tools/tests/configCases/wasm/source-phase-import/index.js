@@ -0,0 +1,8 @@
+it("should compile the wasm module of a source phase import", () =>
+	import("./module").then(({ memoryModule }) => {
+		expect(
+			WebAssembly.Module.exports(memoryModule).map(({ name }) => name)
+		).toEqual(["memory"]);
+		const { exports } = new WebAssembly.Instance(memoryModule);
+		expect(new Int32Array(exports.memory.buffer)[0]).toBe(42);
+	}));
@@ -0,0 +1,3 @@
+import source memoryModule from "./memory.wasm";
+
+export { memoryModule };
@@ -0,0 +1,3 @@
+module.exports = [
+	[/webassembly\/sync modules are not supported with wasmLoading "async-node-module"/]
+];
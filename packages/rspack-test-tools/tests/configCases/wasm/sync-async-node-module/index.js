@@ -0,0 +1,7 @@
+it("should report webassembly/sync modules with async-node-module", () => {
+	expect(typeof load).toBe("function");
+});
+
+export function load() {
+	return import("./wasm-user");
+}
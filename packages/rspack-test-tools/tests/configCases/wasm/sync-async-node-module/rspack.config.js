@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "async-node",
+	output: {
+		wasmLoading: "async-node-module"
+	},
+	experiments: {
+		syncWebAssembly: true
+	}
+};
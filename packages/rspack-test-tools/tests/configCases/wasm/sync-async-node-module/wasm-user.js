@@ -0,0 +1,3 @@
+import { getResult } from "./wasm.wasm";
+
+export var result = getResult(1);
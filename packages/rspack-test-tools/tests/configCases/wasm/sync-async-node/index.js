@@ -0,0 +1,5 @@
+it("should instantiate webassembly/sync modules while loading the chunk", () =>
+	import("./module").then(({ result, run }) => {
+		expect(result).toBe(42);
+		expect(run()).toBe(43);
+	}));
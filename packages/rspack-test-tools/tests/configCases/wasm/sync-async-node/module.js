@@ -0,0 +1,9 @@
+import { getResult } from "./imports-multiple.wasm";
+
+export { run } from "./imports-memory.wasm";
+
+export var result = getResult(1);
+
+export function getNumber() {
+	return 20;
+}
@@ -0,0 +1,7 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "async-node",
+	experiments: {
+		syncWebAssembly: true
+	}
+};
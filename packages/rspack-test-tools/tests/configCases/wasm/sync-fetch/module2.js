@@ -0,0 +1,5 @@
+import { getNumber as getN } from "./imports-multiple.wasm";
+
+export function getNumber() {
+	return getN();
+}
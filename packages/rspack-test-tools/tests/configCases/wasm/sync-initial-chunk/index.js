@@ -0,0 +1,3 @@
+import { getNumber } from "./wasm.wasm";
+
+getNumber();
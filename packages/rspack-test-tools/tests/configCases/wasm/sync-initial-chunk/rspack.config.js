@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	experiments: {
+		syncWebAssembly: true
+	}
+};
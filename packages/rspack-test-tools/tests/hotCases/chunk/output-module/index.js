@@ -0,0 +1,20 @@
+import { value } from "./file";
+
+it("should apply hot updates to esm output", done => {
+	expect(value).toBe(1);
+	import("./chunk")
+		.then(chunk => {
+			expect(chunk.value).toBe(1);
+			module.hot.accept(["./file", "./chunk"], () => {
+				expect(value).toBe(2);
+				import("./chunk")
+					.then(chunk => {
+						expect(chunk.value).toBe(2);
+						done();
+					})
+					.catch(done);
+			});
+			NEXT(require("../../update")(done));
+		})
+		.catch(done);
+});
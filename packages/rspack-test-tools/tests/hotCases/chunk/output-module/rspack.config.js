@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		module: true,
+		filename: "bundle.mjs",
+		chunkFilename: "[name].chunk.[fullhash].mjs",
+		library: { type: "module" }
+	},
+	experiments: {
+		outputModule: true
+	}
+};
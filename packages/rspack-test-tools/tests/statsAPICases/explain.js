@@ -0,0 +1,44 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should explain why a module and its exports are included",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				main: "./fixtures/esm/abc"
+			},
+			optimization: {
+				usedExports: true,
+				providedExports: true
+			}
+		};
+	},
+	async check(stats) {
+		const explanation = stats?.explain("./fixtures/esm/a.js");
+		expect(explanation.name).toBe("./fixtures/esm/a.js");
+		expect(
+			explanation.connectionChain.map(connection => [
+				connection.entry,
+				connection.moduleName
+			])
+		).toEqual([
+			["main", "./fixtures/esm/abc.js"],
+			[null, "./fixtures/esm/a.js"]
+		]);
+		expect(explanation.chunks).toHaveLength(1);
+		expect(explanation.chunks[0].path).toEqual([
+			{ name: "main", kind: "entrypoint", block: null }
+		]);
+
+		const a = stats?.explain("./fixtures/esm/a.js", "a");
+		expect(a.usageState).toBe("used");
+		expect(a.usedBy.map(connection => connection.originName)).toEqual([
+			"./fixtures/esm/abc.js"
+		]);
+		const unused = stats?.explain("./fixtures/esm/a.js", "default");
+		expect(unused.usageState).toBe("unused");
+		expect(unused.usedBy).toEqual([]);
+
+		expect(stats?.explain("./fixtures/esm/not-exist.js")).toBeNull();
+	}
+};
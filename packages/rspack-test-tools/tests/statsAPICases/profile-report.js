@@ -0,0 +1,70 @@
+const path = require("path");
+
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description:
+		"should break module profile down and write profile report when profileReport is set",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/abc",
+			profile: true,
+			profileReport: "profile.json",
+			module: {
+				rules: [
+					{
+						test: /a\.js$/,
+						use: [{ loader: context.getSource("./fixtures/count-loader.js") }]
+					}
+				]
+			}
+		};
+	},
+	async check(stats, compiler) {
+		const { modules, profileSummary } = stats.toJson({
+			all: false,
+			modules: true
+		});
+		for (const { profile } of modules) {
+			expect(typeof profile.factory).toBe("number");
+			expect(typeof profile.resolving).toBe("number");
+			expect(typeof profile.parsing).toBe("number");
+			expect(typeof profile.codeGeneration).toBe("number");
+			expect(Array.isArray(profile.loaders)).toBe(true);
+		}
+		const a = modules.find(m => m.name === "./fixtures/a.js");
+		expect(a.profile.loaders).toHaveLength(1);
+		expect(a.profile.loaders[0].loader).toContain("count-loader.js");
+		expect(typeof a.profile.loaders[0].time).toBe("number");
+
+		expect(profileSummary.slowestModules).toHaveLength(4);
+		expect(profileSummary.slowestLoaders).toEqual([
+			{
+				loader: expect.stringContaining("count-loader.js"),
+				time: expect.any(Number),
+				count: 1
+			}
+		]);
+		expect(Array.isArray(profileSummary.slowestResolves)).toBe(true);
+
+		const report = JSON.parse(
+			compiler.outputFileSystem.readFileSync(
+				path.join(compiler.options.output.path, "profile.json"),
+				"utf-8"
+			)
+		);
+		expect(report.modules.map(m => m.name).sort()).toEqual([
+			"./fixtures/a.js",
+			"./fixtures/abc.js",
+			"./fixtures/b.js",
+			"./fixtures/c.js"
+		]);
+		expect(Object.keys(report.summary)).toEqual([
+			"slowestModules",
+			"slowestLoaders",
+			"slowestResolves"
+		]);
+		expect(report.summary.slowestLoaders).toHaveLength(1);
+		expect(report.summary.slowestLoaders[0].count).toBe(1);
+	}
+};
@@ -10,16 +10,19 @@ module.exports = {
 	},
 	async check(stats) {
 		expect(
-			stats?.toString({ all: false, modules: true }).replace(/\d+ ms/g, "X ms")
+			stats
+				?.toString({ all: false, modules: true })
+				.replace(/\d+ ms/g, "X ms")
+				.replace(/, waiting: X ms/g, "")
 		).toMatchInlineSnapshot(`
 		"./fixtures/a.js
-		  X ms (resolving: X ms, integration: X ms, building: X ms)
+		  X ms (factory: X ms, resolving: X ms, integration: X ms, building: X ms, parsing: X ms, code generation: X ms)
 		./fixtures/b.js
-		  X ms (resolving: X ms, integration: X ms, building: X ms)
+		  X ms (factory: X ms, resolving: X ms, integration: X ms, building: X ms, parsing: X ms, code generation: X ms)
 		./fixtures/c.js
-		  X ms (resolving: X ms, integration: X ms, building: X ms)
+		  X ms (factory: X ms, resolving: X ms, integration: X ms, building: X ms, parsing: X ms, code generation: X ms)
 		./fixtures/abc.js
-		  X ms (resolving: X ms, integration: X ms, building: X ms)"
+		  X ms (factory: X ms, resolving: X ms, integration: X ms, building: X ms, parsing: X ms, code generation: X ms)"
 	`);
 	}
 };
@@ -0,0 +1,44 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should render webpack-compatible json for stats presets with toWebpackJson",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/abc"
+		};
+	},
+	async check(stats) {
+		const verbose = stats.toWebpackJson("verbose");
+		expect(verbose.version).toBeTruthy();
+		expect(verbose.rspackVersion).toBeTruthy();
+		expect(verbose.assetsByChunkName).toEqual({ main: ["main.js"] });
+		expect(verbose.assets.map(asset => asset.name)).toEqual(["main.js"]);
+		expect(verbose.chunks[0].modules.map(m => m.name).sort()).toEqual([
+			"./fixtures/a.js",
+			"./fixtures/abc.js",
+			"./fixtures/b.js",
+			"./fixtures/c.js"
+		]);
+		const abc = verbose.modules.find(m => m.name === "./fixtures/abc.js");
+		expect(abc.reasons[0].type).toBe("entry");
+		expect(typeof abc.source).toBe("string");
+		expect(Object.keys(verbose.entrypoints)).toEqual(["main"]);
+		expect(verbose.errorsCount).toBe(1);
+
+		const detailed = stats.toWebpackJson("detailed");
+		expect(detailed.modules).toHaveLength(4);
+		expect(detailed.chunks).toHaveLength(1);
+		expect(detailed.chunks[0].modules).toBeUndefined();
+
+		expect(stats.toWebpackJson("errors-only")).toEqual({
+			errors: verbose.errors.map(({ moduleId, ...error }) => error),
+			errorsCount: 1
+		});
+		expect(stats.toWebpackJson("none")).toEqual({});
+
+		// presets passed to `toJson` keep going through the stats factory
+		const json = stats.toJson("verbose");
+		expect(typeof json.time).toBe("number");
+		expect(typeof json.builtAt).toBe("number");
+	}
+};
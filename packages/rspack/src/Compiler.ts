@@ -631,7 +631,10 @@ class Compiler {
 		}
 		this.hooks.shutdown.callAsync(err => {
 			if (err) return callback(err);
-			this.cache.shutdown(callback);
+			this.cache.shutdown(err => {
+				if (err || !this.#instance) return callback(err);
+				this.#instance.close(err => callback(err));
+			});
 		});
 	}
 
@@ -22,6 +22,54 @@ export type {
 	StatsWarnings
 } from "./stats/statsFactoryUtils";
 
+export type StatsExplanationConnection = {
+	originModule: string | null;
+	originName: string | null;
+	module: string;
+	moduleName: string;
+	type: string;
+	userRequest: string | null;
+	entry: string | null;
+};
+
+export type StatsExportExplanation = {
+	name: string;
+	provided: string;
+	used: string;
+	usageState: "unused" | "onlyPropertiesUsed" | "noInfo" | "unknown" | "used";
+	usedBy: StatsExplanationConnection[];
+};
+
+export type StatsModuleExplanation = {
+	identifier: string;
+	name: string;
+	connectionChain: StatsExplanationConnection[];
+	chunks: {
+		id: string | null;
+		name: string | null;
+		files: string[];
+		path: {
+			name: string | null;
+			kind: "entrypoint" | "async entrypoint" | "async";
+			block: {
+				module: string;
+				moduleName: string;
+				requests: string[];
+			} | null;
+		}[];
+	}[];
+	sideEffects: {
+		sideEffectFree: boolean | null;
+		reasons: string[];
+	};
+	exports: StatsExportExplanation[];
+	optimizationBailout: string[];
+};
+
+type RustStatsPreset = NonNullable<
+	Parameters<binding.JsStats["getJsonString"]>[0]
+>;
+
 export class Stats {
 	#inner: binding.JsStats;
 	compilation: Compilation;
@@ -55,6 +103,20 @@ export class Stats {
 		return this.#inner.getWarnings().length > 0;
 	}
 
+	/**
+	 * Explains why the module is included, `module` is the identifier or the readable identifier
+	 * of the module.
+	 */
+	explain(module: string): StatsModuleExplanation | null;
+	/**
+	 * Explains how the export of the module is used.
+	 */
+	explain(module: string, exportName: string): StatsExportExplanation | null;
+	explain(module: string, exportName?: string) {
+		const report = this.#inner.getModuleExplanation(module, exportName);
+		return report ? JSON.parse(report) : null;
+	}
+
 	toJson(opts?: StatsValue, forToString?: boolean): StatsCompilation {
 		const options = this.compilation.createStatsOptions(opts, {
 			forToString
@@ -81,6 +143,20 @@ export class Stats {
 		return stats as StatsCompilation;
 	}
 
+	/**
+	 * Renders the webpack-compatible stats JSON of a preset in rust, which is faster than `toJson`
+	 * but skips the stats factory, so plugins can't customize it and fields like `time`, `builtAt`,
+	 * `children` and `logging` are not included.
+	 */
+	toWebpackJson(preset: RustStatsPreset = "normal"): StatsCompilation {
+		const json: StatsCompilation = JSON.parse(this.#inner.getJsonString(preset));
+		if (preset === "normal" || preset === "detailed" || preset === "verbose") {
+			const { version, webpackVersion } = require("../package.json");
+			return { version: webpackVersion, rspackVersion: version, ...json };
+		}
+		return json;
+	}
+
 	toString(opts?: StatsValue) {
 		const options = this.compilation.createStatsOptions(opts, {
 			forToString: true
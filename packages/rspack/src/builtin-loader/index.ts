@@ -1 +1,7 @@
 export * from "./swc";
+export type {
+	LightningcssFeatureOptions,
+	LightningcssLoaderOptions
+} from "./lightningcss";
+export { resolveTargets, toFeatures } from "./lightningcss";
+export type { SvgrLoaderOptions } from "./svgr";
@@ -0,0 +1,93 @@
+/**
+ * Features that can be always compiled or never compiled, the bit flags follow
+ * `lightningcss::targets::Features`.
+ */
+export type LightningcssFeatureOptions = {
+	nesting?: boolean;
+	notSelectorList?: boolean;
+	dirSelector?: boolean;
+	langSelectorList?: boolean;
+	isSelector?: boolean;
+	textDecorationThicknessPercent?: boolean;
+	mediaIntervalSyntax?: boolean;
+	mediaRangeSyntax?: boolean;
+	customMediaQueries?: boolean;
+	clampFunction?: boolean;
+	colorFunction?: boolean;
+	oklabColors?: boolean;
+	labColors?: boolean;
+	p3Colors?: boolean;
+	hexAlphaColors?: boolean;
+	spaceSeparatedColorNotation?: boolean;
+	fontFamilySystemUi?: boolean;
+	doublePositionGradients?: boolean;
+	vendorPrefixes?: boolean;
+	logicalProperties?: boolean;
+	selectors?: boolean;
+	mediaQueries?: boolean;
+	colors?: boolean;
+};
+
+export type LightningcssLoaderOptions = {
+	minify?: boolean;
+	errorRecovery?: boolean;
+	/**
+	 * Browserslist queries, defaults to the browserslist config of the project.
+	 */
+	targets?: string[] | string;
+	include?: LightningcssFeatureOptions;
+	exclude?: LightningcssFeatureOptions;
+	draft?: {
+		customMedia?: boolean;
+	};
+};
+
+const FEATURES: Record<keyof LightningcssFeatureOptions, number> = {
+	nesting: 1 << 0,
+	notSelectorList: 1 << 1,
+	dirSelector: 1 << 2,
+	langSelectorList: 1 << 3,
+	isSelector: 1 << 4,
+	textDecorationThicknessPercent: 1 << 5,
+	mediaIntervalSyntax: 1 << 6,
+	mediaRangeSyntax: 1 << 7,
+	customMediaQueries: 1 << 8,
+	clampFunction: 1 << 9,
+	colorFunction: 1 << 10,
+	oklabColors: 1 << 11,
+	labColors: 1 << 12,
+	p3Colors: 1 << 13,
+	hexAlphaColors: 1 << 14,
+	spaceSeparatedColorNotation: 1 << 15,
+	fontFamilySystemUi: 1 << 16,
+	doublePositionGradients: 1 << 17,
+	vendorPrefixes: 1 << 18,
+	logicalProperties: 1 << 19,
+	selectors: 0b11111,
+	mediaQueries: 0b111 << 6,
+	colors: 0b111111 << 10
+};
+
+export function toFeatures(options: LightningcssFeatureOptions): number {
+	let features = 0;
+	for (const [feature, enabled] of Object.entries(options)) {
+		if (enabled) {
+			features |= FEATURES[feature as keyof LightningcssFeatureOptions] ?? 0;
+		}
+	}
+	return features;
+}
+
+export function resolveTargets(
+	targets: string[] | string | undefined,
+	context: string
+): string[] | undefined {
+	if (typeof targets === "string") {
+		return [targets];
+	}
+	if (targets) {
+		return targets;
+	}
+	const browserslistTargetHandler = require("../../config/browserslistTargetHandler");
+	return browserslistTargetHandler.load(undefined, context);
+}
@@ -0,0 +1,34 @@
+export type SvgrLoaderOptions = {
+	/**
+	 * Forwards the ref to the root `<svg>` element.
+	 */
+	ref?: boolean;
+	/**
+	 * Adds `title` and `titleId` props rendered as the `<title>` of the SVG.
+	 */
+	title?: boolean;
+	/**
+	 * Wraps the component with `React.memo`.
+	 */
+	memo?: boolean;
+	/**
+	 * Emits a TSX module with typed props.
+	 */
+	typescript?: boolean;
+	expandProps?: boolean | "start" | "end";
+	dimensions?: boolean;
+	icon?: boolean;
+	svgProps?: Record<string, string>;
+	replaceAttrValues?: Record<string, string>;
+	/**
+	 * Runs the built-in optimizations, a subset of the default preset of svgo.
+	 */
+	svgo?: boolean;
+	jsxRuntime?: "classic" | "automatic";
+	exportType?: "default" | "named";
+	namedExport?: string;
+	/**
+	 * Name of an extra export holding the URL of the SVG emitted as an asset module.
+	 */
+	urlExport?: string;
+};
@@ -4,6 +4,6 @@ import { create } from "./base";
 
 export const AsyncWebAssemblyModulesPlugin = create(
 	BuiltinPluginName.AsyncWebAssemblyModulesPlugin,
-	() => {},
+	(esmIntegration: boolean = false) => esmIntegration,
 	"compilation"
 );
@@ -0,0 +1,35 @@
+import {
+	BuiltinPluginName,
+	RawGraphExportRspackPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type GraphExportRspackPluginOptions = {
+	/**
+	 * `[name]` is replaced with the entry name.
+	 * @default "[name].graph.dot" or "[name].graph.graphml"
+	 */
+	filename?: string;
+	/** @default "dot" */
+	format?: "dot" | "graphml";
+	/** Entries to export, all entries are exported by default. */
+	entries?: string[];
+	/** Only export the modules whose readable identifier matches. */
+	moduleFilter?: RegExp;
+};
+
+export const GraphExportRspackPlugin = create(
+	BuiltinPluginName.GraphExportRspackPlugin,
+	(
+		options: GraphExportRspackPluginOptions = {}
+	): RawGraphExportRspackPluginOptions => {
+		const format = options.format ?? "dot";
+		return {
+			filename: options.filename ?? `[name].graph.${format}`,
+			format,
+			entries: options.entries ?? [],
+			moduleFilter: options.moduleFilter
+		};
+	}
+);
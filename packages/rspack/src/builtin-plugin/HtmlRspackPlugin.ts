@@ -9,11 +9,21 @@ const htmlRspackPluginOptions = z.strictObject({
 	template: z.string().optional(),
 	templateContent: z.string().optional(),
 	templateParameters: z.record(z.string()).optional(),
+	templateGlob: z.string().optional(),
+	pages: z
+		.record(
+			z.strictObject({
+				entry: z.string().optional(),
+				templateParameters: z.record(z.string()).optional()
+			})
+		)
+		.optional(),
 	inject: z.enum(["head", "body"]).or(z.boolean()).optional(),
 	publicPath: z.string().optional(),
 	scriptLoading: z.enum(["blocking", "defer", "module"]).optional(),
 	chunks: z.string().array().optional(),
 	excludedChunks: z.string().array().optional(),
+	chunksSortMode: z.enum(["auto", "manual", "dependency"]).optional(),
 	sri: z.enum(["sha256", "sha384", "sha512"]).optional(),
 	minify: z.boolean().optional(),
 	title: z.string().optional(),
@@ -0,0 +1,41 @@
+import {
+	BuiltinPluginName,
+	RawLightningCssMinimizerRspackPluginOptions
+} from "@rspack/binding";
+
+import {
+	LightningcssFeatureOptions,
+	toFeatures
+} from "../builtin-loader/lightningcss";
+import { create } from "./base";
+
+export type LightningCssMinimizerRspackPluginOptions = {
+	errorRecovery?: boolean;
+	/**
+	 * Browserslist queries, the output is not lowered for any browsers when not set.
+	 */
+	targets?: string[] | string;
+	include?: LightningcssFeatureOptions;
+	exclude?: LightningcssFeatureOptions;
+	draft?: {
+		customMedia?: boolean;
+	};
+};
+
+export const LightningCssMinimizerRspackPlugin = create(
+	BuiltinPluginName.LightningCssMinimizerRspackPlugin,
+	(
+		options?: LightningCssMinimizerRspackPluginOptions
+	): RawLightningCssMinimizerRspackPluginOptions => {
+		const targets = options?.targets;
+		return {
+			errorRecovery: options?.errorRecovery ?? true,
+			targets: typeof targets === "string" ? [targets] : targets,
+			include: options?.include ? toFeatures(options.include) : undefined,
+			exclude: options?.exclude ? toFeatures(options.exclude) : undefined,
+			draft: options?.draft
+				? { customMedia: options.draft.customMedia ?? false }
+				: undefined
+		};
+	}
+);
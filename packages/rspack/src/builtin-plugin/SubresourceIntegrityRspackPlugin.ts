@@ -0,0 +1,35 @@
+import {
+	BuiltinPluginName,
+	RawSubresourceIntegrityRspackPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type SubresourceIntegrityRspackPluginOptions = {
+	/** @default ["sha384"] */
+	hashFuncNames?: ("sha256" | "sha384" | "sha512")[];
+	/**
+	 * Emit a JSON manifest mapping each JS/CSS asset to its integrity,
+	 * `true` uses "integrity-manifest.json".
+	 * @default false
+	 */
+	manifest?: boolean | string;
+};
+
+export const SubresourceIntegrityRspackPlugin = create(
+	BuiltinPluginName.SubresourceIntegrityRspackPlugin,
+	(
+		options: SubresourceIntegrityRspackPluginOptions = {}
+	): RawSubresourceIntegrityRspackPluginOptions => {
+		const { manifest } = options;
+		return {
+			hashFuncNames: options.hashFuncNames ?? ["sha384"],
+			manifest:
+				manifest === true
+					? "integrity-manifest.json"
+					: typeof manifest === "string"
+						? manifest
+						: undefined
+		};
+	}
+);
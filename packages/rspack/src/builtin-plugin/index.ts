@@ -2,6 +2,7 @@ export * from "./APIPlugin";
 export * from "./ArrayPushCallbackChunkFormatPlugin";
 export * from "./AssetModulesPlugin";
 export * from "./AsyncWebAssemblyModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./BannerPlugin";
 export { RspackBuiltinPlugin } from "./base";
 export * from "./BundlerInfoRspackPlugin";
@@ -27,6 +28,7 @@ export * from "./ExternalsPlugin";
 export * from "./FileUriPlugin";
 export * from "./FlagDependencyExportsPlugin";
 export * from "./FlagDependencyUsagePlugin";
+export * from "./GraphExportRspackPlugin";
 export * from "./HotModuleReplacementPlugin";
 export * from "./HtmlRspackPlugin";
 export * from "./HttpExternalsRspackPlugin";
@@ -49,6 +51,7 @@ export * from "./NodeTargetPlugin";
 export * from "./ProgressPlugin";
 export * from "./ProvidePlugin";
 export * from "./RealContentHashPlugin";
+export * from "./RecordIdsPlugin";
 export * from "./RemoveEmptyChunksPlugin";
 export * from "./RuntimeChunkPlugin";
 export * from "./RuntimePlugin";
@@ -56,7 +59,9 @@ export * from "./SideEffectsFlagPlugin";
 export * from "./SizeLimitsPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./SplitChunksPlugin";
+export * from "./SubresourceIntegrityRspackPlugin";
 export * from "./SwcCssMinimizerPlugin";
+export * from "./LightningCssMinimizerPlugin";
 export * from "./SwcJsMinimizerPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
 export * from "./WebWorkerTemplatePlugin";
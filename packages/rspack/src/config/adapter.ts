@@ -13,6 +13,7 @@ import type {
 	RawFuncUseCtx,
 	RawGeneratorOptions,
 	RawJavascriptParserOptions,
+	RawJsonParserOptions,
 	RawLibraryName,
 	RawLibraryOptions,
 	RawModuleRule,
@@ -25,6 +26,7 @@ import type {
 	RawRuleSetLogicalConditions
 } from "@rspack/binding";
 import assert from "assert";
+import path from "path";
 
 import { Compiler } from "../Compiler";
 import { normalizeStatsPreset } from "../Stats";
@@ -46,10 +48,12 @@ import {
 import {
 	AssetGeneratorDataUrl,
 	AssetGeneratorOptions,
+	AssetGeneratorTransform,
 	AssetInlineGeneratorOptions,
 	AssetParserDataUrl,
 	AssetParserOptions,
 	AssetResourceGeneratorOptions,
+	CacheOptions,
 	ChunkLoading,
 	CrossOriginLoading,
 	CssAutoGeneratorOptions,
@@ -62,6 +66,7 @@ import {
 	Environment,
 	GeneratorOptionsByModuleType,
 	JavascriptParserOptions,
+	JsonParserOptions,
 	LibraryName,
 	LibraryOptions,
 	Node,
@@ -108,27 +113,67 @@ export const getRawOptions = (
 		optimization: getRawOptimization(options.optimization),
 		stats: getRawStats(options.stats),
 		snapshot: getRawSnapshotOptions(options.snapshot),
-		cache: {
-			type: options.cache ? "memory" : "disable",
-			// TODO: implement below cache options
-			maxGenerations: 0,
-			maxAge: 0,
-			profile: false,
-			buildDependencies: [],
-			cacheDirectory: "",
-			cacheLocation: "",
-			name: "",
-			version: ""
-		},
+		cache: getRawCache(options.cache!, options.context!),
 		experiments,
 		node: getRawNode(options.node),
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		profile: options.profile!,
+		profileReport: options.profileReport,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!
 	};
 };
 
+function getRawCache(
+	cache: CacheOptions,
+	context: string
+): RawOptions["cache"] {
+	const raw: RawOptions["cache"] = {
+		type: "disable",
+		maxGenerations: 0,
+		maxAge: 0,
+		profile: false,
+		buildDependencies: [],
+		cacheDirectory: "",
+		cacheLocation: "",
+		hashAlgorithm: "xxhash64",
+		compression: "none",
+		idleTimeout: 60000,
+		idleTimeoutForInitialStore: 5000,
+		idleTimeoutAfterLargeChanges: 1000,
+		name: "",
+		version: ""
+	};
+	if (cache === false) {
+		return raw;
+	}
+	if (cache === true || cache.type === "memory") {
+		return { ...raw, type: "memory" };
+	}
+	return {
+		...raw,
+		type: "filesystem",
+		maxAge: cache.maxAge ?? raw.maxAge,
+		profile: cache.profile ?? raw.profile,
+		buildDependencies: Object.values(cache.buildDependencies ?? {})
+			.flat()
+			.map(dep => path.resolve(context, dep)),
+		cacheDirectory: cache.cacheDirectory ?? raw.cacheDirectory,
+		cacheLocation: cache.cacheLocation ?? raw.cacheLocation,
+		hashAlgorithm: cache.hashAlgorithm ?? raw.hashAlgorithm,
+		compression: cache.compression || raw.compression,
+		idleTimeout: cache.idleTimeout ?? raw.idleTimeout,
+		idleTimeoutForInitialStore:
+			cache.idleTimeoutForInitialStore ?? raw.idleTimeoutForInitialStore,
+		idleTimeoutAfterLargeChanges:
+			cache.idleTimeoutAfterLargeChanges ?? raw.idleTimeoutAfterLargeChanges,
+		name: cache.name ?? raw.name,
+		version: cache.version ?? raw.version
+	};
+}
+
 function getRawTarget(target: Target | undefined): RawOptions["target"] {
 	if (!target) {
 		return [];
@@ -612,6 +657,16 @@ function getRawParserOptions(
 			type: "css/module",
 			cssModule: getRawCssParserOptions(parser)
 		};
+	} else if (
+		type === "json" ||
+		type === "json5" ||
+		type === "yaml" ||
+		type === "toml"
+	) {
+		return {
+			type,
+			json: getRawJsonParserOptions(parser)
+		};
 	}
 	// FIXME: shouldn't depend on module type, for example: `rules: [{ test: /\.css/, generator: {..} }]` will error
 	throw new Error(`unreachable: unknow module type: ${type}`);
@@ -667,8 +722,14 @@ function getRawAssetParserDataUrl(
 			}
 		};
 	}
+	if (typeof dataUrlCondition === "function") {
+		return {
+			type: "function",
+			function: dataUrlCondition
+		};
+	}
 	throw new Error(
-		`unreachable: AssetParserDataUrl type should be one of "options", but got ${dataUrlCondition}`
+		`unreachable: AssetParserDataUrl type should be one of "options", "function", but got ${dataUrlCondition}`
 	);
 }
 
@@ -680,6 +741,18 @@ function getRawCssParserOptions(
 	};
 }
 
+function getRawJsonParserOptions(
+	parser: JsonParserOptions
+): RawJsonParserOptions {
+	const { parse } = parser;
+	return {
+		exportsDepth: parser.exportsDepth,
+		parse: parse
+			? (source: string) => JSON.stringify(parse(source)) ?? "null"
+			: undefined
+	};
+}
+
 function getRawGeneratorOptions(
 	generator: { [k: string]: any },
 	type: string
@@ -742,6 +815,10 @@ function getRawAssetInlineGeneratorOptions(
 	return {
 		dataUrl: options.dataUrl
 			? getRawAssetGeneratorDataUrl(options.dataUrl)
+			: undefined,
+		image: options.image,
+		transform: options.transform
+			? getRawAssetGeneratorTransform(options.transform)
 			: undefined
 	};
 }
@@ -752,7 +829,20 @@ function getRawAssetResourceGeneratorOptions(
 	return {
 		emit: options.emit,
 		filename: options.filename,
-		publicPath: options.publicPath
+		publicPath: options.publicPath,
+		image: options.image,
+		transform: options.transform
+			? getRawAssetGeneratorTransform(options.transform)
+			: undefined
+	};
+}
+
+function getRawAssetGeneratorTransform(
+	transform: AssetGeneratorTransform
+): NonNullable<RawAssetResourceGeneratorOptions["transform"]> {
+	return (content, context) => {
+		const result = transform(content, context);
+		return typeof result === "string" ? Buffer.from(result) : result;
 	};
 }
 
@@ -816,9 +906,17 @@ function getRawOptimization(
 }
 
 function getRawSnapshotOptions(
-	_snapshot: SnapshotOptions
+	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	return {};
+	const { managedPaths, immutablePaths, module, resolve, buildDependencies } =
+		snapshot;
+	return {
+		managedPaths,
+		immutablePaths,
+		module,
+		resolve,
+		buildDependencies
+	};
 }
 
 function getRawExperiments(
@@ -855,8 +953,42 @@ function getRawNode(node: Node): RawOptions["node"] {
 
 function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
+	const preset =
+		typeof stats === "boolean"
+			? stats
+				? "normal"
+				: "none"
+			: typeof stats === "string"
+				? stats
+				: stats?.preset;
 	return {
-		colors: statsOptions.colors ?? false
+		colors: statsOptions.colors ?? false,
+		preset,
+		all: statsOptions.all,
+		hash: statsOptions.hash,
+		publicPath: statsOptions.publicPath,
+		outputPath: statsOptions.outputPath,
+		assets: statsOptions.assets,
+		chunks: statsOptions.chunks,
+		chunkModules: statsOptions.chunkModules,
+		chunkRelations: statsOptions.chunkRelations,
+		chunkGroups: statsOptions.chunkGroups,
+		entrypoints: statsOptions.entrypoints,
+		modules: statsOptions.modules,
+		nestedModules: statsOptions.nestedModules,
+		orphanModules: statsOptions.orphanModules,
+		runtimeModules: statsOptions.runtimeModules,
+		moduleAssets: statsOptions.moduleAssets,
+		ids: statsOptions.ids,
+		reasons: statsOptions.reasons,
+		source: statsOptions.source,
+		usedExports: statsOptions.usedExports,
+		providedExports: statsOptions.providedExports,
+		optimizationBailout: statsOptions.optimizationBailout,
+		errors: statsOptions.errors,
+		errorsCount: statsOptions.errorsCount,
+		warnings: statsOptions.warnings,
+		warningsCount: statsOptions.warningsCount
 	};
 }
 
@@ -13,7 +13,9 @@ import {
 	resolveEmotion,
 	resolvePluginImport,
 	resolveReact,
-	resolveRelay
+	resolveRelay,
+	resolveTargets,
+	toFeatures
 } from "../builtin-loader";
 import { Compilation } from "../Compilation";
 import { parsePathQueryFragment } from "../loader-runner";
@@ -265,6 +267,23 @@ const getSwcLoaderOptions: GetLoaderOptions = (o, options) => {
 	return o;
 };
 
+const getLightningcssLoaderOptions: GetLoaderOptions = (o, options) => {
+	if (o && typeof o === "object") {
+		o.targets = resolveTargets(o.targets, options.context!);
+		if (o.include && typeof o.include === "object") {
+			o.include = toFeatures(o.include);
+		}
+		if (o.exclude && typeof o.exclude === "object") {
+			o.exclude = toFeatures(o.exclude);
+		}
+		return o;
+	}
+	if (isNil(o)) {
+		return { targets: resolveTargets(undefined, options.context!) };
+	}
+	return o;
+};
+
 function getBuiltinLoaderOptions(
 	identifier: string,
 	o: RuleSetLoaderWithOptions["options"],
@@ -273,6 +292,9 @@ function getBuiltinLoaderOptions(
 	if (identifier.startsWith(`${BUILTIN_LOADER_PREFIX}swc-loader`)) {
 		return getSwcLoaderOptions(o, options);
 	}
+	if (identifier.startsWith(`${BUILTIN_LOADER_PREFIX}lightningcss-loader`)) {
+		return getLightningcssLoaderOptions(o, options);
+	}
 
 	return o;
 }
@@ -84,13 +84,14 @@ export const applyRspackOptionsDefaults = (
 	F(options, "cache", () => development);
 
 	applyExperimentsDefaults(options.experiments, {
-		cache: options.cache!
+		cache: !!options.cache
 	});
 
 	applySnapshotDefaults(options.snapshot, { production });
 
 	applyModuleDefaults(options.module, {
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		css: options.experiments.css!,
 		targetProperties
 	});
@@ -187,6 +188,8 @@ const applyExperimentsDefaults = (
 ) => {
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
+	D(experiments, "wasmEsmIntegration", false);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
 
@@ -253,10 +256,12 @@ const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
 		asyncWebAssembly,
+		syncWebAssembly,
 		css,
 		targetProperties
 	}: {
 		asyncWebAssembly: boolean;
+		syncWebAssembly: boolean;
 		css: boolean;
 		targetProperties: any;
 	}
@@ -428,6 +433,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -64,7 +64,10 @@ import type {
 	Performance,
 	Plugins,
 	Profile,
+	ProfileReport,
 	PublicPath,
+	RecordsInputPath,
+	RecordsOutputPath,
 	Resolve,
 	RspackFutureOptions,
 	RspackOptions,
@@ -247,7 +250,13 @@ export const getNormalizedRspackOptions = (
 				}
 		),
 		loader: cloneObject(config.loader),
-		snapshot: nestedConfig(config.snapshot, _snapshot => ({})),
+		snapshot: nestedConfig(config.snapshot, snapshot => ({
+			managedPaths: snapshot.managedPaths,
+			immutablePaths: snapshot.immutablePaths,
+			module: snapshot.module,
+			resolve: snapshot.resolve,
+			buildDependencies: snapshot.buildDependencies
+		})),
 		cache: optionalNestedConfig(config.cache, cache => cache),
 		stats: nestedConfig(config.stats, stats => {
 			if (stats === false) {
@@ -301,6 +310,9 @@ export const getNormalizedRspackOptions = (
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
+		profileReport: config.profileReport,
+		recordsInputPath: config.recordsInputPath ?? config.recordsPath,
+		recordsOutputPath: config.recordsOutputPath ?? config.recordsPath,
 		bail: config.bail
 	};
 };
@@ -496,6 +508,8 @@ export interface ModuleOptionsNormalized {
 export interface ExperimentsNormalized {
 	lazyCompilation?: false | LazyCompilationOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
+	wasmEsmIntegration?: boolean;
 	outputModule?: boolean;
 	topLevelAwait?: boolean;
 	css?: boolean;
@@ -544,5 +558,8 @@ export interface RspackOptionsNormalized {
 	ignoreWarnings?: IgnoreWarningsNormalized;
 	performance?: Performance;
 	profile?: Profile;
+	profileReport?: ProfileReport;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 	bail?: Bail;
 }
@@ -289,7 +289,14 @@ export type HashDigest = z.infer<typeof hashDigest>;
 const hashDigestLength = z.number();
 export type HashDigestLength = z.infer<typeof hashDigestLength>;
 
-const hashFunction = z.enum(["md4", "xxhash64"]);
+const hashFunction = z.enum([
+	"md4",
+	"md5",
+	"xxhash64",
+	"xxhash128",
+	"sha256",
+	"sha512"
+]);
 export type HashFunction = z.infer<typeof hashFunction>;
 
 const hashSalt = z.string();
@@ -538,7 +545,23 @@ export type AssetParserDataUrlOptions = z.infer<
 	typeof assetParserDataUrlOptions
 >;
 
-const assetParserDataUrl = assetParserDataUrlOptions;
+const assetParserDataUrlFunction = z
+	.function()
+	.args(
+		z.instanceof(Buffer),
+		z.strictObject({
+			filename: z.string(),
+			issuer: z.string().optional()
+		})
+	)
+	.returns(z.boolean());
+export type AssetParserDataUrlFunction = z.infer<
+	typeof assetParserDataUrlFunction
+>;
+
+const assetParserDataUrl = assetParserDataUrlOptions.or(
+	assetParserDataUrlFunction
+);
 export type AssetParserDataUrl = z.infer<typeof assetParserDataUrl>;
 
 const assetParserOptions = z.strictObject({
@@ -593,6 +616,12 @@ const javascriptParserOptions = z.strictObject({
 });
 export type JavascriptParserOptions = z.infer<typeof javascriptParserOptions>;
 
+const jsonParserOptions = z.strictObject({
+	exportsDepth: z.number().int().nonnegative().optional(),
+	parse: z.function().args(z.string()).returns(z.any()).optional()
+});
+export type JsonParserOptions = z.infer<typeof jsonParserOptions>;
+
 const parserOptionsByModuleTypeKnown = z.strictObject({
 	asset: assetParserOptions.optional(),
 	css: cssParserOptions.optional(),
@@ -601,7 +630,11 @@ const parserOptionsByModuleTypeKnown = z.strictObject({
 	javascript: javascriptParserOptions.optional(),
 	"javascript/auto": javascriptParserOptions.optional(),
 	"javascript/dynamic": javascriptParserOptions.optional(),
-	"javascript/esm": javascriptParserOptions.optional()
+	"javascript/esm": javascriptParserOptions.optional(),
+	json: jsonParserOptions.optional(),
+	json5: jsonParserOptions.optional(),
+	yaml: jsonParserOptions.optional(),
+	toml: jsonParserOptions.optional()
 });
 
 export type ParserOptionsByModuleTypeKnown = z.infer<
@@ -621,7 +654,10 @@ export type ParserOptionsByModuleType = z.infer<
 >;
 
 const assetGeneratorDataUrlOptions = z.strictObject({
-	encoding: z.literal(false).or(z.literal("base64")).optional(),
+	encoding: z
+		.literal(false)
+		.or(z.enum(["base64", "utf8", "svg"]))
+		.optional(),
 	mimetype: z.string().optional()
 });
 export type AssetGeneratorDataUrlOptions = z.infer<
@@ -646,8 +682,29 @@ const assetGeneratorDataUrl = assetGeneratorDataUrlOptions.or(
 );
 export type AssetGeneratorDataUrl = z.infer<typeof assetGeneratorDataUrl>;
 
+const assetGeneratorImageOptions = z.strictObject({
+	optimize: z.boolean().optional(),
+	quality: z.number().int().min(1).max(100).optional()
+});
+export type AssetGeneratorImageOptions = z.infer<
+	typeof assetGeneratorImageOptions
+>;
+
+const assetGeneratorTransform = z
+	.function()
+	.args(
+		z.instanceof(Buffer),
+		z.strictObject({
+			filename: z.string()
+		})
+	)
+	.returns(z.instanceof(Buffer).or(z.string()));
+export type AssetGeneratorTransform = z.infer<typeof assetGeneratorTransform>;
+
 const assetInlineGeneratorOptions = z.strictObject({
-	dataUrl: assetGeneratorDataUrl.optional()
+	dataUrl: assetGeneratorDataUrl.optional(),
+	image: assetGeneratorImageOptions.optional(),
+	transform: assetGeneratorTransform.optional()
 });
 export type AssetInlineGeneratorOptions = z.infer<
 	typeof assetInlineGeneratorOptions
@@ -656,7 +713,9 @@ export type AssetInlineGeneratorOptions = z.infer<
 const assetResourceGeneratorOptions = z.strictObject({
 	emit: z.boolean().optional(),
 	filename: filenameTemplate.optional(),
-	publicPath: publicPath.optional()
+	publicPath: publicPath.optional(),
+	image: assetGeneratorImageOptions.optional(),
+	transform: assetGeneratorTransform.optional()
 });
 export type AssetResourceGeneratorOptions = z.infer<
 	typeof assetResourceGeneratorOptions
@@ -1002,12 +1061,50 @@ export type Loader = z.infer<typeof loader>;
 //#endregion
 
 //#region Snapshot
-const snapshotOptions = z.strictObject({});
+const snapshotStrategy = z.strictObject({
+	timestamp: z.boolean().optional(),
+	hash: z.boolean().optional()
+});
+export type SnapshotStrategy = z.infer<typeof snapshotStrategy>;
+
+const snapshotOptions = z.strictObject({
+	managedPaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+	immutablePaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+	module: snapshotStrategy.optional(),
+	resolve: snapshotStrategy.optional(),
+	buildDependencies: snapshotStrategy.optional()
+});
 export type SnapshotOptions = z.infer<typeof snapshotOptions>;
 //#endregion
 
 //#region Cache
-const cacheOptions = z.boolean();
+const memoryCacheOptions = z.strictObject({
+	type: z.literal("memory"),
+	maxGenerations: z.number().optional()
+});
+export type MemoryCacheOptions = z.infer<typeof memoryCacheOptions>;
+
+const fileSystemCacheOptions = z.strictObject({
+	type: z.literal("filesystem"),
+	buildDependencies: z.record(z.array(z.string())).optional(),
+	cacheDirectory: z.string().optional(),
+	cacheLocation: z.string().optional(),
+	compression: z.enum(["gzip", "brotli"]).or(z.literal(false)).optional(),
+	hashAlgorithm: hashFunction.optional(),
+	idleTimeout: z.number().optional(),
+	idleTimeoutAfterLargeChanges: z.number().optional(),
+	idleTimeoutForInitialStore: z.number().optional(),
+	maxAge: z.number().optional(),
+	name: z.string().optional(),
+	profile: z.boolean().optional(),
+	version: z.string().optional()
+});
+export type FileSystemCacheOptions = z.infer<typeof fileSystemCacheOptions>;
+
+const cacheOptions = z
+	.boolean()
+	.or(memoryCacheOptions)
+	.or(fileSystemCacheOptions);
 export type CacheOptions = z.infer<typeof cacheOptions>;
 //#endregion
 
@@ -1015,7 +1112,14 @@ export type CacheOptions = z.infer<typeof cacheOptions>;
 const statsOptions = z.strictObject({
 	all: z.boolean().optional(),
 	preset: z
-		.enum(["normal", "none", "verbose", "errors-only", "errors-warnings"])
+		.enum([
+			"normal",
+			"none",
+			"verbose",
+			"detailed",
+			"errors-only",
+			"errors-warnings"
+		])
 		.optional(),
 	assets: z.boolean().optional(),
 	chunks: z.boolean().optional(),
@@ -1057,7 +1161,14 @@ const statsOptions = z.strictObject({
 export type StatsOptions = z.infer<typeof statsOptions>;
 
 const statsValue = z
-	.enum(["none", "errors-only", "errors-warnings", "normal", "verbose"])
+	.enum([
+		"none",
+		"errors-only",
+		"errors-warnings",
+		"normal",
+		"detailed",
+		"verbose"
+	])
 	.or(z.boolean())
 	.or(statsOptions);
 export type StatsValue = z.infer<typeof statsValue>;
@@ -1224,6 +1335,8 @@ export type LazyCompilationOptions = z.infer<typeof lazyCompilationOptions>;
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional().or(lazyCompilationOptions),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
+	wasmEsmIntegration: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	css: z.boolean().optional(),
@@ -1275,6 +1388,20 @@ export type IgnoreWarnings = z.infer<typeof ignoreWarnings>;
 //#region Profile
 const profile = z.boolean();
 export type Profile = z.infer<typeof profile>;
+
+const profileReport = z.string();
+export type ProfileReport = z.infer<typeof profileReport>;
+//#endregion
+
+//#region Records
+const recordsPath = z.literal(false).or(z.string());
+export type RecordsPath = z.infer<typeof recordsPath>;
+
+const recordsInputPath = z.literal(false).or(z.string());
+export type RecordsInputPath = z.infer<typeof recordsInputPath>;
+
+const recordsOutputPath = z.literal(false).or(z.string());
+export type RecordsOutputPath = z.infer<typeof recordsOutputPath>;
 //#endregion
 
 //#region Bail
@@ -1323,6 +1450,10 @@ export const rspackOptions = z.strictObject({
 	devServer: devServer.optional(),
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
+	profileReport: profileReport.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsInputPath.optional(),
+	recordsOutputPath: recordsOutputPath.optional(),
 	bail: bail.optional(),
 	performance: performance.optional()
 });
@@ -28,7 +28,10 @@ export type {
 	StatsChunk,
 	StatsCompilation,
 	StatsError,
+	StatsExplanationConnection,
+	StatsExportExplanation,
 	StatsModule,
+	StatsModuleExplanation,
 	StatsWarnings
 } from "./Stats";
 export { Stats } from "./Stats";
@@ -220,6 +223,7 @@ export const sharing = {
 ///// Rspack Postfixed Internal Plugins /////
 export type { HtmlRspackPluginOptions } from "./builtin-plugin";
 export type { SwcJsMinimizerRspackPluginOptions } from "./builtin-plugin";
+export type { LightningCssMinimizerRspackPluginOptions } from "./builtin-plugin";
 export type { CopyRspackPluginOptions } from "./builtin-plugin";
 export type { SourceMapDevToolPluginOptions } from "./builtin-plugin";
 export type { EvalDevToolModulePluginOptions } from "./builtin-plugin";
@@ -227,14 +231,19 @@ export type {
 	CssExtractRspackLoaderOptions,
 	CssExtractRspackPluginOptions
 } from "./builtin-plugin";
+export type { GraphExportRspackPluginOptions } from "./builtin-plugin";
+export type { SubresourceIntegrityRspackPluginOptions } from "./builtin-plugin";
 export { HtmlRspackPlugin } from "./builtin-plugin";
 export { SwcJsMinimizerRspackPlugin } from "./builtin-plugin";
 export { SwcCssMinimizerRspackPlugin } from "./builtin-plugin";
+export { LightningCssMinimizerRspackPlugin } from "./builtin-plugin";
 export { CopyRspackPlugin } from "./builtin-plugin";
 export { SourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalSourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalDevToolModulePlugin } from "./builtin-plugin";
 export { CssExtractRspackPlugin } from "./builtin-plugin";
+export { GraphExportRspackPlugin } from "./builtin-plugin";
+export { SubresourceIntegrityRspackPlugin } from "./builtin-plugin";
 
 ///// Rspack Postfixed Internal Loaders /////
 export type {
@@ -247,3 +256,8 @@ export type {
 	SwcLoaderTransformConfig,
 	SwcLoaderTsParserConfig
 } from "./builtin-loader/swc/index";
+export type {
+	LightningcssFeatureOptions,
+	LightningcssLoaderOptions
+} from "./builtin-loader/lightningcss/index";
+export type { SvgrLoaderOptions } from "./builtin-loader/svgr/index";
@@ -56,6 +56,7 @@ import {
 	NaturalModuleIdsPlugin,
 	NodeTargetPlugin,
 	RealContentHashPlugin,
+	RecordIdsPlugin,
 	RemoveEmptyChunksPlugin,
 	RuntimeChunkPlugin,
 	RuntimePlugin,
@@ -64,6 +65,7 @@ import {
 	SourceMapDevToolPlugin,
 	SplitChunksPlugin,
 	WarnCaseSensitiveModulesPlugin,
+	WebAssemblyModulesPlugin,
 	WorkerPlugin
 } from "./builtin-plugin";
 import EntryOptionPlugin from "./lib/EntryOptionPlugin";
@@ -207,7 +209,12 @@ export class RspackOptionsApply {
 		new JsonModulesPlugin().apply(compiler);
 		new AssetModulesPlugin().apply(compiler);
 		if (options.experiments.asyncWebAssembly) {
-			new AsyncWebAssemblyModulesPlugin().apply(compiler);
+			new AsyncWebAssemblyModulesPlugin(
+				options.experiments.wasmEsmIntegration
+			).apply(compiler);
+		}
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
 		}
 		if (options.experiments.css) {
 			new CssModulesPlugin().apply(compiler);
@@ -303,6 +310,9 @@ export class RspackOptionsApply {
 		if (options.optimization.realContentHash) {
 			new RealContentHashPlugin().apply(compiler);
 		}
+		if (options.recordsInputPath || options.recordsOutputPath) {
+			new RecordIdsPlugin().apply(compiler);
+		}
 		const moduleIds = options.optimization.moduleIds;
 		if (moduleIds) {
 			switch (moduleIds) {
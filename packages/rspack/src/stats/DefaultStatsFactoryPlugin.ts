@@ -7,6 +7,7 @@
  * Copyright (c) JS Foundation and other contributors
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
+import type * as binding from "@rspack/binding";
 import * as util from "node:util";
 
 import type { Compiler } from "../Compiler";
@@ -42,6 +43,9 @@ import {
 	spaceLimited
 } from "./statsFactoryUtils";
 
+/** Number of items in each list of the profile summary */
+const PROFILE_SUMMARY_LIMIT = 20;
+
 const compareIds = _compareIds as <T>(a: T, b: T) => -1 | 0 | 1;
 const GROUP_EXTENSION_REGEXP = /(\.[^.]+?)(?:\?|(?: \+ \d+ modules?)?$)/;
 const GROUP_PATH_REGEXP = /(.+)[/\\][^/\\]+?(?:\?|(?: \+ \d+ modules?)?$)/;
@@ -628,6 +632,26 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			const limited = spaceLimited(groupedModules, options.modulesSpace!);
 			object.modules = limited.children;
 			object.filteredModules = limited.filteredChildren;
+			if (compilation.options.profile) {
+				const { slowestModules, slowestLoaders, slowestResolves } = context
+					.getInner(compilation)
+					.getProfileSummary(PROFILE_SUMMARY_LIMIT);
+				const resolveModules = (modules: binding.JsStatsProfileModule[]) =>
+					modules.map(({ identifier, name, time }) => ({
+						identifier,
+						name,
+						time: resolveStatsMillisecond(time)
+					}));
+				object.profileSummary = {
+					slowestModules: resolveModules(slowestModules),
+					slowestLoaders: slowestLoaders.map(({ loader, time, count }) => ({
+						loader,
+						time: resolveStatsMillisecond(time),
+						count
+					})),
+					slowestResolves: resolveModules(slowestResolves)
+				};
+			}
 		},
 		entrypoints: (
 			object,
@@ -823,11 +847,20 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			const factory = resolveStatsMillisecond(profile.factory);
 			const integration = resolveStatsMillisecond(profile.integration);
 			const building = resolveStatsMillisecond(profile.building);
+			const codeGeneration = resolveStatsMillisecond(profile.codeGeneration);
 			const statsProfile: StatsProfile = {
-				total: factory + integration + building,
-				resolving: factory,
+				total: factory + integration + building + codeGeneration,
+				factory,
+				resolving: resolveStatsMillisecond(profile.resolving),
 				integration,
-				building
+				waiting: resolveStatsMillisecond(profile.waiting),
+				building,
+				loaders: profile.loaders.map(({ loader, time }) => ({
+					loader,
+					time: resolveStatsMillisecond(time)
+				})),
+				parsing: resolveStatsMillisecond(profile.parsing),
+				codeGeneration
 			};
 			Object.assign(object, statsProfile);
 		}
@@ -476,14 +476,22 @@ const SIMPLE_PRINTERS: Record<
 			: undefined,
 
 	"module.profile.total": (value, { formatTime }) => formatTime(value),
+	"module.profile.factory": (value, { formatTime }) =>
+		`factory: ${formatTime(value)}`,
 	"module.profile.resolving": (value, { formatTime }) =>
 		`resolving: ${formatTime(value)}`,
 	"module.profile.restoring": (value, { formatTime }) =>
 		`restoring: ${formatTime(value)}`,
 	"module.profile.integration": (value, { formatTime }) =>
 		`integration: ${formatTime(value)}`,
+	"module.profile.waiting": (value, { formatTime }) =>
+		value ? `waiting: ${formatTime(value)}` : undefined,
 	"module.profile.building": (value, { formatTime }) =>
 		`building: ${formatTime(value)}`,
+	"module.profile.parsing": (value, { formatTime }) =>
+		`parsing: ${formatTime(value)}`,
+	"module.profile.codeGeneration": (value, { formatTime }) =>
+		`code generation: ${formatTime(value)}`,
 	"module.profile.storing": (value, { formatTime }) =>
 		`storing: ${formatTime(value)}`,
 	"module.profile.additionalResolving": (value, { formatTime }) =>
@@ -843,10 +851,14 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 	"module.profile": [
 		"total",
 		"separator!",
+		"factory",
 		"resolving",
 		"restoring",
 		"integration",
+		"waiting",
 		"building",
+		"parsing",
+		"codeGeneration",
 		"storing",
 		"additionalResolving",
 		"additionalIntegration"
@@ -35,9 +35,20 @@ export type StatsProfile = KnownStatsProfile & Record<string, any>;
 
 export type KnownStatsProfile = {
 	total: number;
+	factory: number;
 	resolving: number;
 	integration: number;
+	waiting: number;
 	building: number;
+	loaders: { loader: string; time: number }[];
+	parsing: number;
+	codeGeneration: number;
+};
+
+export type StatsProfileSummary = {
+	slowestModules: { identifier: string; name: string; time: number }[];
+	slowestLoaders: { loader: string; time: number; count: number }[];
+	slowestResolves: { identifier: string; name: string; time: number }[];
 };
 
 export type StatsModule = KnownStatsModule & Record<string, any>;
@@ -77,6 +88,7 @@ export type KnownStatsCompilation = {
 	warnings?: StatsWarnings[];
 	warningsCount?: number;
 	filteredModules?: number;
+	profileSummary?: StatsProfileSummary;
 	children?: StatsCompilation[];
 	logging?: Record<string, StatsLogging>;
 